@@ -0,0 +1,178 @@
+use pumpkin_data::{Block, BlockState};
+
+/// Looks up a block by its registry name, with or without the `minecraft:` namespace.
+pub fn parse_block(name: &str) -> Result<&'static Block, String> {
+    let key = name.strip_prefix("minecraft:").unwrap_or(name);
+    Block::from_registry_key(key).ok_or_else(|| format!("Unknown block '{name}'."))
+}
+
+/// Returns the block a state id belongs to.
+#[must_use]
+pub fn block_of(state_id: u16) -> &'static Block {
+    Block::from_state_id(state_id)
+}
+
+#[must_use]
+pub fn is_air(state_id: u16) -> bool {
+    let block = block_of(state_id);
+    block.id == Block::AIR.id || block.id == Block::CAVE_AIR.id || block.id == Block::VOID_AIR.id
+}
+
+/// Returns the value of the state property `name` (e.g. `waterlogged`), or `None` if
+/// the block has no such property.
+#[must_use]
+pub fn property(state_id: u16, name: &str) -> Option<String> {
+    let block = block_of(state_id);
+    block
+        .properties(state_id)?
+        .to_props()
+        .into_iter()
+        .find_map(|(key, value)| (key == name).then_some(value))
+}
+
+/// Returns the state of the same block with the property `name` set to `value`, or
+/// `None` if the block has no such property or the value is invalid.
+#[must_use]
+pub fn with_property(state_id: u16, name: &str, value: &str) -> Option<u16> {
+    let block = block_of(state_id);
+    let mut props = block.properties(state_id)?.to_props();
+    let (_, old) = props.iter_mut().find(|(key, _)| key == name)?;
+    *old = value.to_string();
+    Some(block.from_properties(props)?.to_state_id(block))
+}
+
+/// Whether the state is solid, e.g. to decide if snow can lie on it.
+#[must_use]
+pub fn is_solid(state_id: u16) -> bool {
+    BlockState::from_id(state_id).is_solid()
+}
+
+/// Resolves a block name and state properties, as stored in world files, to a state
+/// id. Properties the block doesn't have are ignored and missing ones keep their
+/// default values.
+#[must_use]
+pub fn state_from_properties(name: &str, properties: &[(String, String)]) -> Option<u16> {
+    let block = parse_block(name).ok()?;
+    let state_id = block.default_state.id;
+    let Some(default) = block.properties(state_id) else {
+        return Some(state_id);
+    };
+    let mut props = default.to_props();
+    for (key, value) in &mut props {
+        if let Some((_, new)) = properties.iter().find(|(name, _)| name == key) {
+            value.clone_from(new);
+        }
+    }
+    Some(block.from_properties(props)?.to_state_id(block))
+}
+
+/// Formats a state like `minecraft:oak_log[axis=y]`, for display.
+#[must_use]
+pub fn state_string(state_id: u16) -> String {
+    let block = block_of(state_id);
+    let props = block
+        .properties(state_id)
+        .map(|props| props.to_props())
+        .unwrap_or_default();
+    if props.is_empty() {
+        return format!("minecraft:{}", block.name);
+    }
+    let props: Vec<_> = props
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect();
+    format!("minecraft:{}[{}]", block.name, props.join(","))
+}
+
+/// Whether the block can be mined at all, which rules out bedrock and the like.
+#[must_use]
+pub fn is_breakable(state_id: u16) -> bool {
+    !is_air(state_id) && block_of(state_id).hardness >= 0.0
+}
+
+/// Returns whether the state is water, lava or a bubble column.
+#[must_use]
+pub fn is_liquid(state_id: u16) -> bool {
+    let block = block_of(state_id);
+    block.id == Block::WATER.id || block.id == Block::LAVA.id || block.id == Block::BUBBLE_COLUMN.id
+}
+
+/// Returns the state of the same block with the given properties set. Properties the
+/// block doesn't have are ignored, and invalid values leave the state as it is.
+#[must_use]
+pub fn with_properties(state_id: u16, properties: &[(String, String)]) -> u16 {
+    let block = block_of(state_id);
+    let Some(state) = block.properties(state_id) else {
+        return state_id;
+    };
+    let mut props = state.to_props();
+    for (key, value) in &mut props {
+        if let Some((_, new)) = properties.iter().find(|(name, _)| name == key) {
+            value.clone_from(new);
+        }
+    }
+    block
+        .from_properties(props)
+        .map_or(state_id, |state| state.to_state_id(block))
+}
+
+/// Whether the state has each of the given properties set to the given value.
+#[must_use]
+pub fn has_properties(state_id: u16, properties: &[(String, String)]) -> bool {
+    let props = block_of(state_id)
+        .properties(state_id)
+        .map(|props| props.to_props())
+        .unwrap_or_default();
+    properties
+        .iter()
+        .all(|property| props.iter().any(|prop| prop == property))
+}
+
+/// Parses block state properties written as `[key=value,...]`, with or without the
+/// brackets.
+pub fn parse_properties(input: &str) -> Result<Vec<(String, String)>, String> {
+    let inner = input
+        .strip_prefix('[')
+        .and_then(|inner| inner.strip_suffix(']'))
+        .unwrap_or(input);
+    inner
+        .split(',')
+        .filter(|property| !property.is_empty())
+        .map(|property| match property.split_once('=') {
+            Some((key, value)) if !key.is_empty() && !value.is_empty() => {
+                Ok((key.to_string(), value.to_string()))
+            }
+            _ => Err(format!("Invalid property '{property}'.")),
+        })
+        .collect()
+}
+
+/// Formats properties as `[key=value,...]`, for display.
+#[must_use]
+pub fn properties_string(properties: &[(String, String)]) -> String {
+    let properties: Vec<String> = properties
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect();
+    format!("[{}]", properties.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_properties, properties_string};
+
+    #[test]
+    fn test_properties() {
+        let properties = parse_properties("[axis=x,waterlogged=true]").unwrap();
+        assert_eq!(
+            properties,
+            [
+                ("axis".to_string(), "x".to_string()),
+                ("waterlogged".to_string(), "true".to_string())
+            ]
+        );
+        assert_eq!(parse_properties("axis=x").unwrap().len(), 1);
+        assert!(parse_properties("axis").is_err());
+        assert_eq!(properties_string(&properties), "[axis=x,waterlogged=true]");
+    }
+}
@@ -3,8 +3,6 @@ use std::{
     ops::{Bound, RangeBounds, RangeInclusive},
 };
 
-use num_traits::Euclid;
-
 /// An iterator that splits a given integer range into 16-value "chunks".
 ///
 /// Each item yielded by the iterator is a tuple `(chunk_index, range_within_chunk)`,
@@ -76,13 +74,13 @@ impl Iterator for ChunkedRange {
     type Item = (i32, RangeInclusive<i32>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (div_current, rem_current) = self.current.div_rem_euclid(&16);
-        let (div_end, rem_end) = self.end.div_rem_euclid(&16);
+        let (div_current, rem_current) = (self.current.div_euclid(16), self.current.rem_euclid(16));
+        let (div_end, rem_end) = (self.end.div_euclid(16), self.end.rem_euclid(16));
 
         let range_end = match div_current.cmp(&div_end) {
             Ordering::Less => 15,
             Ordering::Equal => {
-                if rem_current < rem_end {
+                if rem_current <= rem_end {
                     rem_end
                 } else {
                     return None;
@@ -98,9 +96,30 @@ impl Iterator for ChunkedRange {
     }
 }
 
+/// The indices of the first and last chunk the range touches, or `None` if the range
+/// is empty.
+#[must_use]
+pub fn chunk_span<R: RangeBounds<i32>>(range: R) -> Option<RangeInclusive<i32>> {
+    let mut chunks = ChunkedRange::new(range).map(|(chunk, _)| chunk);
+    let first = chunks.next()?;
+    Some(first..=chunks.last().unwrap_or(first))
+}
+
+/// The region files (of 32 chunks a side) a span of chunk indices touches, each with
+/// whether the span covers it entirely.
+#[must_use]
+pub fn region_span(chunks: &RangeInclusive<i32>) -> Vec<(i32, bool)> {
+    (chunks.start() >> 5..=chunks.end() >> 5)
+        .map(|region| {
+            let full = *chunks.start() <= region << 5 && *chunks.end() >= (region << 5) + 31;
+            (region, full)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::ChunkedRange;
+    use super::{ChunkedRange, chunk_span, region_span};
 
     #[test]
     fn test_chunked_range_restore() {
@@ -127,4 +146,26 @@ mod tests {
         assert_eq!(chunked.next(), Some((1, 0..=15)));
         assert_eq!(chunked.next(), None);
     }
+
+    #[test]
+    fn test_chunked_range_single() {
+        let chunks: Vec<_> = ChunkedRange::new(-10..=16).collect();
+        assert_eq!(chunks, vec![(-1, 6..=15), (0, 0..=15), (1, 0..=0)]);
+    }
+
+    #[test]
+    fn test_chunk_span() {
+        assert_eq!(chunk_span(-10..=16), Some(-1..=1));
+        assert_eq!(chunk_span(5..=5), Some(0..=0));
+        assert_eq!(chunk_span(5..5), None);
+    }
+
+    #[test]
+    fn test_region_span() {
+        assert_eq!(region_span(&(0..=31)), vec![(0, true)]);
+        assert_eq!(
+            region_span(&(-1..=40)),
+            vec![(-1, false), (0, true), (1, false)]
+        );
+    }
 }
@@ -0,0 +1,38 @@
+use std::path::Path;
+
+/// Returns the `key=value` pairs of a properties file, trimmed, skipping blank lines,
+/// `#` comments and lines without `=`.
+pub fn properties(content: &str) -> impl Iterator<Item = (&str, &str)> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim()))
+}
+
+/// Reads `file_name` from `data_folder`. If it doesn't exist, `default` is written
+/// there and returned instead.
+pub fn read_or_create(data_folder: &Path, file_name: &str, default: &str) -> String {
+    let path = data_folder.join(file_name);
+    std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        if let Err(err) = std::fs::write(&path, default) {
+            log::warn!("Failed to write {}: {err}", path.display());
+        }
+        default.to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::properties;
+
+    #[test]
+    fn test_properties() {
+        let content = "# comment\n\n range = 250\nundo.depth=20\ninvalid\nempty=\n";
+        assert_eq!(
+            properties(content).collect::<Vec<_>>(),
+            [("range", "250"), ("undo.depth", "20"), ("empty", "")]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+/// Returns the blocks of a 3D Bresenham line from `from` to `to`, both inclusive.
+#[must_use]
+pub fn line(from: BlockPos, to: BlockPos) -> Vec<BlockPos> {
+    let (from, to) = (from.0, to.0);
+    let delta = Vector3::new(to.x - from.x, to.y - from.y, to.z - from.z);
+    let step = Vector3::new(delta.x.signum(), delta.y.signum(), delta.z.signum());
+    let (ax, ay, az) = (delta.x.abs(), delta.y.abs(), delta.z.abs());
+    let length = ax.max(ay).max(az);
+
+    let mut points = Vec::with_capacity(length as usize + 1);
+    let mut current = from;
+    // Error terms of the two minor axes relative to the major axis.
+    let (mut ex, mut ey, mut ez) = (length / 2, length / 2, length / 2);
+    points.push(BlockPos(current));
+    for _ in 0..length {
+        ex -= ax;
+        ey -= ay;
+        ez -= az;
+        if ex < 0 {
+            ex += length;
+            current.x += step.x;
+        }
+        if ey < 0 {
+            ey += length;
+            current.y += step.y;
+        }
+        if ez < 0 {
+            ez += length;
+            current.z += step.z;
+        }
+        points.push(BlockPos(current));
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+    use super::line;
+
+    fn pos(x: i32, y: i32, z: i32) -> BlockPos {
+        BlockPos(Vector3::new(x, y, z))
+    }
+
+    #[test]
+    fn test_line_is_connected() {
+        let points = line(pos(-3, 10, 7), pos(12, -4, 1));
+        assert_eq!(points.first(), Some(&pos(-3, 10, 7)));
+        assert_eq!(points.last(), Some(&pos(12, -4, 1)));
+        for pair in points.windows(2) {
+            let (a, b) = (pair[0].0, pair[1].0);
+            assert!((a.x - b.x).abs() <= 1 && (a.y - b.y).abs() <= 1 && (a.z - b.z).abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn test_line_single_point() {
+        assert_eq!(line(pos(1, 2, 3), pos(1, 2, 3)), vec![pos(1, 2, 3)]);
+    }
+}
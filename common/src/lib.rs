@@ -0,0 +1,9 @@
+//! Helpers shared by the plugins of this workspace: reading and editing blocks in
+//! bulk, drawing lines, tracing the player's line of sight and loading config files.
+
+pub mod block;
+pub mod chunked_range;
+pub mod config;
+pub mod draw;
+pub mod region;
+pub mod trace;
@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+
+use pumpkin::world::World;
+use pumpkin_protocol::client::play::{CBlockUpdate, CMultiBlockUpdate};
+use pumpkin_registry::VanillaDimensionType;
+use pumpkin_util::math::{position::BlockPos, vector2::Vector2, vector3::Vector3};
+
+use crate::chunked_range::ChunkedRange;
+
+/// Offsets of the six face-adjacent neighbours of a block.
+pub const NEIGHBOURS: [(i32, i32, i32); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+/// Returns the lowest block Y coordinate of the world's dimension.
+#[must_use]
+pub fn min_y(world: &World) -> i32 {
+    match world.dimension_type {
+        VanillaDimensionType::Overworld | VanillaDimensionType::OverworldCaves => -64,
+        VanillaDimensionType::TheEnd | VanillaDimensionType::TheNether => 0,
+    }
+}
+
+/// Returns the highest block Y coordinate of the world's dimension.
+#[must_use]
+pub fn max_y(world: &World) -> i32 {
+    match world.dimension_type {
+        VanillaDimensionType::Overworld | VanillaDimensionType::OverworldCaves => 319,
+        VanillaDimensionType::TheEnd | VanillaDimensionType::TheNether => 255,
+    }
+}
+
+/// Returns the smallest cuboid containing every position, or `None` if there are none.
+#[must_use]
+pub fn bounds<'a, I>(positions: I) -> Option<(BlockPos, BlockPos)>
+where
+    I: IntoIterator<Item = &'a BlockPos>,
+{
+    let mut positions = positions.into_iter();
+    let first = *positions.next()?;
+    Some(positions.fold((first, first), |(min, max), pos| {
+        (
+            BlockPos(Vector3::new(
+                min.0.x.min(pos.0.x),
+                min.0.y.min(pos.0.y),
+                min.0.z.min(pos.0.z),
+            )),
+            BlockPos(Vector3::new(
+                max.0.x.max(pos.0.x),
+                max.0.y.max(pos.0.y),
+                max.0.z.max(pos.0.z),
+            )),
+        )
+    }))
+}
+
+/// Visits every block of the cuboid spanned by `pos1` and `pos2` (which must be
+/// normalized) and replaces it with the state returned by `f`, if any.
+///
+/// `f` receives the block position and its current state id. Writes go straight into
+/// the chunk sections, one chunk at a time, and every changed section is sent to the
+/// clients as a single (multi) block update. This is much faster than calling
+/// `World::set_block_state` for each block.
+///
+/// Returns the number of blocks that were changed.
+pub async fn edit_blocks<F>(world: &World, pos1: BlockPos, pos2: BlockPos, mut f: F) -> usize
+where
+    F: FnMut(BlockPos, u16) -> Option<u16> + Send,
+{
+    let min_y = min_y(world);
+
+    let (x1, x2) = (pos1.0.x, pos2.0.x);
+    let (z1, z2) = (pos1.0.z, pos2.0.z);
+    let (y1, y2) = (pos1.0.y - min_y, pos2.0.y - min_y);
+
+    let mut total_cnt = 0;
+
+    for (chunk_x, x_range) in ChunkedRange::new(x1..=x2) {
+        for (chunk_z, z_range) in ChunkedRange::new(z1..=z2) {
+            let chunk = world.level.get_chunk(Vector2::new(chunk_x, chunk_z)).await;
+            let mut chunk = chunk.write().await;
+            let mut cnt = 0;
+            for (chunk_y, y_range) in ChunkedRange::new(y1..=y2) {
+                let mut chunk_section = Vec::new();
+                if let Some(section) = chunk.section.sections.get_mut(chunk_y as usize) {
+                    for x in x_range.clone() {
+                        for z in z_range.clone() {
+                            for y in y_range.clone() {
+                                let block_pos = BlockPos(Vector3::new(
+                                    (chunk_x << 4) + x,
+                                    (chunk_y << 4) + y + min_y,
+                                    (chunk_z << 4) + z,
+                                ));
+                                let cur_block_id =
+                                    section.block_states.get(x as usize, y as usize, z as usize);
+                                if let Some(block_id) = f(block_pos, cur_block_id) {
+                                    if block_id == cur_block_id {
+                                        continue;
+                                    }
+                                    section
+                                        .block_states
+                                        .set(x as usize, y as usize, z as usize, block_id);
+                                    chunk_section.push((block_pos, block_id));
+                                }
+                            }
+                        }
+                    }
+                }
+                if !chunk_section.is_empty() {
+                    cnt += chunk_section.len();
+                    if chunk_section.len() == 1 {
+                        let (block_pos, block_state_id) = chunk_section[0];
+                        world
+                            .broadcast_packet_all(&CBlockUpdate::new(
+                                block_pos,
+                                i32::from(block_state_id).into(),
+                            ))
+                            .await;
+                    } else {
+                        world
+                            .broadcast_packet_all(&CMultiBlockUpdate::new(chunk_section))
+                            .await;
+                    }
+                }
+            }
+            if cnt != 0 {
+                chunk.dirty = true;
+                total_cnt += cnt;
+            }
+            drop(chunk);
+        }
+    }
+
+    total_cnt
+}
+
+/// Like [`edit_blocks`], but visits only the given positions instead of a cuboid.
+///
+/// The positions are grouped by chunk section first, so each chunk is locked once
+/// and each section is sent to the clients as a single (multi) block update.
+/// Positions outside the world's height are skipped.
+///
+/// Returns the number of blocks that were changed.
+pub async fn edit_positions<I, F>(world: &World, positions: I, mut f: F) -> usize
+where
+    I: IntoIterator<Item = BlockPos>,
+    F: FnMut(BlockPos, u16) -> Option<u16> + Send,
+{
+    let min_y = min_y(world);
+
+    let mut chunks: BTreeMap<(i32, i32), BTreeMap<i32, Vec<BlockPos>>> = BTreeMap::new();
+    for pos in positions {
+        chunks
+            .entry((pos.0.x >> 4, pos.0.z >> 4))
+            .or_default()
+            .entry((pos.0.y - min_y) >> 4)
+            .or_default()
+            .push(pos);
+    }
+
+    let mut total_cnt = 0;
+
+    for ((chunk_x, chunk_z), sections) in chunks {
+        let chunk = world.level.get_chunk(Vector2::new(chunk_x, chunk_z)).await;
+        let mut chunk = chunk.write().await;
+        let mut cnt = 0;
+        for (chunk_y, mut positions) in sections {
+            if chunk_y < 0 {
+                continue;
+            }
+            positions.sort_unstable_by_key(|pos| (pos.0.x, pos.0.y, pos.0.z));
+            positions.dedup();
+            let mut chunk_section = Vec::new();
+            if let Some(section) = chunk.section.sections.get_mut(chunk_y as usize) {
+                for block_pos in positions {
+                    let (x, y, z) = (
+                        (block_pos.0.x & 15) as usize,
+                        ((block_pos.0.y - min_y) & 15) as usize,
+                        (block_pos.0.z & 15) as usize,
+                    );
+                    let cur_block_id = section.block_states.get(x, y, z);
+                    if let Some(block_id) = f(block_pos, cur_block_id) {
+                        if block_id == cur_block_id {
+                            continue;
+                        }
+                        section.block_states.set(x, y, z, block_id);
+                        chunk_section.push((block_pos, block_id));
+                    }
+                }
+            }
+            if !chunk_section.is_empty() {
+                cnt += chunk_section.len();
+                if chunk_section.len() == 1 {
+                    let (block_pos, block_state_id) = chunk_section[0];
+                    world
+                        .broadcast_packet_all(&CBlockUpdate::new(
+                            block_pos,
+                            i32::from(block_state_id).into(),
+                        ))
+                        .await;
+                } else {
+                    world
+                        .broadcast_packet_all(&CMultiBlockUpdate::new(chunk_section))
+                        .await;
+                }
+            }
+        }
+        if cnt != 0 {
+            chunk.dirty = true;
+            total_cnt += cnt;
+        }
+        drop(chunk);
+    }
+
+    total_cnt
+}
+
+/// A snapshot of the block states of a cuboid.
+#[derive(Debug)]
+pub struct BlockBuffer {
+    min: Vector3<i32>,
+    size: Vector3<i32>,
+    states: Vec<u16>,
+}
+
+impl BlockBuffer {
+    #[must_use]
+    pub fn min(&self) -> Vector3<i32> {
+        self.min
+    }
+
+    #[must_use]
+    pub fn max(&self) -> Vector3<i32> {
+        Vector3::new(
+            self.min.x + self.size.x - 1,
+            self.min.y + self.size.y - 1,
+            self.min.z + self.size.z - 1,
+        )
+    }
+
+    #[must_use]
+    pub fn size(&self) -> Vector3<i32> {
+        self.size
+    }
+
+    /// Returns the index of `pos` into a buffer-sized array, or `None` if it lies
+    /// outside the buffer. Positions are laid out x-major, then y, then z.
+    #[must_use]
+    pub fn index(&self, pos: BlockPos) -> Option<usize> {
+        let pos = pos.0;
+        let (x, y, z) = (pos.x - self.min.x, pos.y - self.min.y, pos.z - self.min.z);
+        if x < 0 || y < 0 || z < 0 || x >= self.size.x || y >= self.size.y || z >= self.size.z {
+            return None;
+        }
+        Some(((x * self.size.y + y) * self.size.z + z) as usize)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Returns the state id at `pos`, or `None` if it lies outside the buffer.
+    #[must_use]
+    pub fn get(&self, pos: BlockPos) -> Option<u16> {
+        self.index(pos).map(|index| self.states[index])
+    }
+}
+
+/// Reads the block states of the cuboid spanned by `pos1` and `pos2` (which must be
+/// normalized), one chunk at a time. Blocks outside the world's height read as air.
+pub async fn read_blocks(world: &World, pos1: BlockPos, pos2: BlockPos) -> BlockBuffer {
+    let min_y = min_y(world);
+
+    let min = pos1.0;
+    let size = Vector3::new(
+        pos2.0.x - pos1.0.x + 1,
+        pos2.0.y - pos1.0.y + 1,
+        pos2.0.z - pos1.0.z + 1,
+    );
+    let mut buffer = BlockBuffer {
+        min,
+        size,
+        states: vec![0; (size.x * size.y * size.z) as usize],
+    };
+
+    let (x1, x2) = (pos1.0.x, pos2.0.x);
+    let (z1, z2) = (pos1.0.z, pos2.0.z);
+    let (y1, y2) = (pos1.0.y - min_y, pos2.0.y - min_y);
+
+    for (chunk_x, x_range) in ChunkedRange::new(x1..=x2) {
+        for (chunk_z, z_range) in ChunkedRange::new(z1..=z2) {
+            let chunk = world.level.get_chunk(Vector2::new(chunk_x, chunk_z)).await;
+            let chunk = chunk.read().await;
+            for (chunk_y, y_range) in ChunkedRange::new(y1..=y2) {
+                let Some(section) = chunk.section.sections.get(chunk_y as usize) else {
+                    continue;
+                };
+                for x in x_range.clone() {
+                    for z in z_range.clone() {
+                        for y in y_range.clone() {
+                            let pos = BlockPos(Vector3::new(
+                                (chunk_x << 4) + x,
+                                (chunk_y << 4) + y + min_y,
+                                (chunk_z << 4) + z,
+                            ));
+                            if let Some(index) = buffer.index(pos) {
+                                buffer.states[index] =
+                                    section.block_states.get(x as usize, y as usize, z as usize);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    buffer
+}
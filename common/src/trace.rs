@@ -0,0 +1,147 @@
+use pumpkin::entity::EntityBase;
+use pumpkin::entity::player::Player;
+use pumpkin_util::math::{position::BlockPos, vector2::Vector2, vector3::Vector3};
+
+use crate::block::is_air;
+
+/// The height of a standing player's eyes above their feet.
+pub const EYE_HEIGHT: f64 = 1.62;
+
+/// Returns the unit vector a player with the given rotation (in degrees) is looking
+/// along.
+#[must_use]
+pub fn look_vector(yaw: f32, pitch: f32) -> Vector3<f64> {
+    let (yaw, pitch) = (f64::from(yaw).to_radians(), f64::from(pitch).to_radians());
+    Vector3::new(
+        -yaw.sin() * pitch.cos(),
+        -pitch.sin(),
+        yaw.cos() * pitch.cos(),
+    )
+}
+
+/// Returns every block the ray from `origin` along the unit vector `direction` passes
+/// through within `range`, in order, starting with the block containing `origin`.
+#[must_use]
+pub fn ray_blocks(origin: Vector3<f64>, direction: Vector3<f64>, range: f64) -> Vec<BlockPos> {
+    let mut current = Vector3::new(
+        origin.x.floor() as i32,
+        origin.y.floor() as i32,
+        origin.z.floor() as i32,
+    );
+    // Per axis: the step direction, the ray length between two block boundaries and
+    // the ray length until the next boundary is crossed.
+    let axis = |origin: f64, direction: f64, current: i32| {
+        if direction > 0.0 {
+            (
+                1,
+                1.0 / direction,
+                (f64::from(current) + 1.0 - origin) / direction,
+            )
+        } else if direction < 0.0 {
+            (
+                -1,
+                -1.0 / direction,
+                (origin - f64::from(current)) / -direction,
+            )
+        } else {
+            (0, f64::INFINITY, f64::INFINITY)
+        }
+    };
+    let (step_x, delta_x, mut next_x) = axis(origin.x, direction.x, current.x);
+    let (step_y, delta_y, mut next_y) = axis(origin.y, direction.y, current.y);
+    let (step_z, delta_z, mut next_z) = axis(origin.z, direction.z, current.z);
+
+    let mut blocks = vec![BlockPos(current)];
+    loop {
+        let t = next_x.min(next_y).min(next_z);
+        if t > range {
+            return blocks;
+        }
+        if t == next_x {
+            current.x += step_x;
+            next_x += delta_x;
+        } else if t == next_y {
+            current.y += step_y;
+            next_y += delta_y;
+        } else {
+            current.z += step_z;
+            next_z += delta_z;
+        }
+        blocks.push(BlockPos(current));
+    }
+}
+
+/// What a player aims at: the first non-air block in sight, and the block the ray
+/// passed through just before it, on the side facing the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+    pub block: BlockPos,
+    pub last: BlockPos,
+}
+
+/// Traces the player's line of sight up to `range` blocks and returns what it hits.
+///
+/// The trace stops at the first chunk that isn't loaded, so aiming far out never loads
+/// or generates chunks.
+pub async fn target(player: &Player, range: f64) -> Option<Target> {
+    let entity = player.get_entity();
+    let position = player.position();
+    let eyes = Vector3::new(position.x, position.y + EYE_HEIGHT, position.z);
+    let direction = look_vector(entity.yaw.load(), entity.pitch.load());
+
+    let world = player.world().await;
+    let mut last = None;
+    let mut loaded = None;
+    for pos in ray_blocks(eyes, direction, range) {
+        let chunk = Vector2::new(pos.0.x >> 4, pos.0.z >> 4);
+        if loaded != Some(chunk) {
+            world.level.try_get_chunk(&chunk)?;
+            loaded = Some(chunk);
+        }
+        if !is_air(world.get_block_state_id(&pos).await) {
+            return Some(Target {
+                block: pos,
+                last: last.unwrap_or(pos),
+            });
+        }
+        last = Some(pos);
+    }
+    None
+}
+
+/// Returns the first non-air block the player is looking at within `range`.
+pub async fn target_block(player: &Player, range: f64) -> Option<BlockPos> {
+    target(player, range).await.map(|target| target.block)
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+    use super::ray_blocks;
+
+    #[test]
+    fn test_ray_blocks_is_connected() {
+        let direction = Vector3::new(0.6, -0.48, 0.64);
+        let blocks = ray_blocks(Vector3::new(0.5, 65.62, -3.2), direction, 20.0);
+        assert_eq!(blocks[0], BlockPos(Vector3::new(0, 65, -4)));
+        for pair in blocks.windows(2) {
+            let (a, b) = (pair[0].0, pair[1].0);
+            let steps = (a.x - b.x).abs() + (a.y - b.y).abs() + (a.z - b.z).abs();
+            assert_eq!(steps, 1);
+        }
+        let last = blocks.last().unwrap().0;
+        assert!((f64::from(last.x) - 12.5).abs() < 2.0);
+    }
+
+    #[test]
+    fn test_ray_blocks_straight_down() {
+        let blocks = ray_blocks(
+            Vector3::new(3.5, 10.5, 3.5),
+            Vector3::new(0.0, -1.0, 0.0),
+            3.0,
+        );
+        let heights: Vec<_> = blocks.iter().map(|pos| pos.0.y).collect();
+        assert_eq!(heights, vec![10, 9, 8, 7]);
+    }
+}
@@ -0,0 +1,528 @@
+use pumpkin::entity::player::Player;
+use pumpkin_data::Block;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+use pumpkin_util::text::TextComponent;
+
+use crate::sniper::{Action, Sniper};
+use crate::utils::block::{is_air, is_liquid};
+use crate::utils::region::{max_y, min_y, read_blocks};
+use crate::utils::shape::{cube, ellipsoid, line, triangle};
+use crate::utils::terrain::{Erosion, blend, erode, neighbours, surface};
+use crate::utils::trace::Target;
+
+/// The names brushes are selected by with `/b`.
+pub const BRUSH_NAMES: &[&str] = &[
+    "s",
+    "snipe",
+    "b",
+    "ball",
+    "bd",
+    "disc",
+    "v",
+    "voxel",
+    "c",
+    "cylinder",
+    "el",
+    "ellipse",
+    "eb",
+    "ellipsoid",
+    "tri",
+    "triangle",
+    "l",
+    "line",
+    "e",
+    "erode",
+    "bb",
+    "blendball",
+    "bld",
+    "blenddisc",
+    "bv",
+    "blendvoxel",
+    "dr",
+    "drain",
+    "ov",
+    "overlay",
+    "u",
+    "underlay",
+    "ca",
+    "canyon",
+];
+
+/// The largest size, or ellipse radius, a brush may be given.
+pub const MAX_SIZE: i32 = 50;
+
+/// How deep overlay and underlay brushes reach unless told otherwise.
+const DEFAULT_DEPTH: i32 = 3;
+
+/// The height canyon brushes lower the terrain to unless told otherwise.
+const DEFAULT_CANYON_LEVEL: i32 = 10;
+
+/// The height canyon brushes lower from, usually the surface of the sea.
+const SEA_LEVEL: i32 = 63;
+
+/// The lowest canyon level, the bottom of the overworld.
+const MIN_CANYON_LEVEL: i32 = -64;
+
+/// What a stroke of a brush produced.
+#[derive(Debug)]
+pub enum Stroke {
+    /// Positions for the sniper's [`Performer`](crate::performer::Performer) to write.
+    Positions(Vec<BlockPos>),
+    /// Block states worked out by the brush itself, written as they are.
+    States(Vec<(BlockPos, u16)>),
+}
+
+/// What a sniper does around the block it is aimed at. Shape brushes pick the
+/// positions and leave the sniper's [`Performer`](crate::performer::Performer) to
+/// decide what happens to them; terrain brushes work out the new blocks themselves
+/// from a snapshot of the blocks around the target.
+///
+/// Round brushes take a "true circle" option, which adds half a block to their radius
+/// so odd sizes come out round instead of pointy.
+#[derive(Debug, Clone)]
+pub enum Brush {
+    /// The single block aimed at.
+    Snipe,
+    /// A ball of the brush size.
+    Ball { true_circle: bool },
+    /// A horizontal disc of the brush size.
+    Disc { true_circle: bool },
+    /// A cube reaching the brush size from its centre.
+    Voxel,
+    /// A stack of discs, as tall as the sniper's height and starting at its center.
+    Cylinder { true_circle: bool },
+    /// A horizontal ellipse. Radii that aren't set follow the brush size.
+    Ellipse {
+        x: Option<i32>,
+        z: Option<i32>,
+        true_circle: bool,
+    },
+    /// An ellipsoid. Radii that aren't set follow the brush size.
+    Ellipsoid {
+        x: Option<i32>,
+        y: Option<i32>,
+        z: Option<i32>,
+        true_circle: bool,
+    },
+    /// A filled triangle through three clicked corners.
+    Triangle { corners: Vec<BlockPos> },
+    /// A line from a point set with an arrow to where gunpowder is fired.
+    Line { origin: Option<BlockPos> },
+    /// Erodes and fills a ball of terrain as the preset says.
+    Erode { erosion: Erosion, true_circle: bool },
+    /// Blends each block of a ball with the blocks around it.
+    BlendBall { air: bool, true_circle: bool },
+    /// Blends each block of a disc with the blocks around it in its layer.
+    BlendDisc { air: bool, true_circle: bool },
+    /// Blends each block of a cube with the blocks around it.
+    BlendVoxel { air: bool },
+    /// Removes the liquids of a ball.
+    Drain { true_circle: bool },
+    /// Covers the top blocks of the terrain in a disc.
+    Overlay { depth: i32, true_circle: bool },
+    /// Covers the blocks just below the top of the terrain in a disc.
+    Underlay { depth: i32, true_circle: bool },
+    /// Lowers the terrain of the target's chunk from sea level down to `level`.
+    Canyon { level: i32 },
+}
+
+impl Brush {
+    /// Looks up a brush by one of its [names](BRUSH_NAMES).
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "s" | "snipe" => Some(Self::Snipe),
+            "b" | "ball" => Some(Self::Ball { true_circle: false }),
+            "bd" | "disc" => Some(Self::Disc { true_circle: false }),
+            "v" | "voxel" => Some(Self::Voxel),
+            "c" | "cylinder" => Some(Self::Cylinder { true_circle: false }),
+            "el" | "ellipse" => Some(Self::Ellipse {
+                x: None,
+                z: None,
+                true_circle: false,
+            }),
+            "eb" | "ellipsoid" => Some(Self::Ellipsoid {
+                x: None,
+                y: None,
+                z: None,
+                true_circle: false,
+            }),
+            "tri" | "triangle" => Some(Self::Triangle {
+                corners: Vec::new(),
+            }),
+            "l" | "line" => Some(Self::Line { origin: None }),
+            "e" | "erode" => Some(Self::Erode {
+                erosion: Erosion::Smooth,
+                true_circle: false,
+            }),
+            "bb" | "blendball" => Some(Self::BlendBall {
+                air: false,
+                true_circle: false,
+            }),
+            "bld" | "blenddisc" => Some(Self::BlendDisc {
+                air: false,
+                true_circle: false,
+            }),
+            "bv" | "blendvoxel" => Some(Self::BlendVoxel { air: false }),
+            "dr" | "drain" => Some(Self::Drain { true_circle: false }),
+            "ov" | "overlay" => Some(Self::Overlay {
+                depth: DEFAULT_DEPTH,
+                true_circle: false,
+            }),
+            "u" | "underlay" => Some(Self::Underlay {
+                depth: DEFAULT_DEPTH,
+                true_circle: false,
+            }),
+            "ca" | "canyon" => Some(Self::Canyon {
+                level: DEFAULT_CANYON_LEVEL,
+            }),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Snipe => "Snipe",
+            Self::Ball { .. } => "Ball",
+            Self::Disc { .. } => "Disc",
+            Self::Voxel => "Voxel",
+            Self::Cylinder { .. } => "Cylinder",
+            Self::Ellipse { .. } => "Ellipse",
+            Self::Ellipsoid { .. } => "Ellipsoid",
+            Self::Triangle { .. } => "Triangle",
+            Self::Line { .. } => "Line",
+            Self::Erode { .. } => "Erode",
+            Self::BlendBall { .. } => "Blend Ball",
+            Self::BlendDisc { .. } => "Blend Disc",
+            Self::BlendVoxel { .. } => "Blend Voxel",
+            Self::Drain { .. } => "Drain",
+            Self::Overlay { .. } => "Overlay",
+            Self::Underlay { .. } => "Underlay",
+            Self::Canyon { .. } => "Canyon",
+        }
+    }
+
+    /// Whether strokes of the brush go through the sniper's performer. Terrain brushes
+    /// other than overlay and underlay write the blocks they work out themselves.
+    #[must_use]
+    pub fn performs(&self) -> bool {
+        !matches!(
+            self,
+            Self::Erode { .. }
+                | Self::BlendBall { .. }
+                | Self::BlendDisc { .. }
+                | Self::BlendVoxel { .. }
+                | Self::Drain { .. }
+                | Self::Canyon { .. }
+        )
+    }
+
+    /// Applies a brush parameter given to `/b` other than the size:
+    ///
+    /// - `true` or `false` for the true circle option of round brushes,
+    /// - `x<n>`, `y<n>` and `z<n>` for the radii of ellipses,
+    /// - an [erosion preset](Erosion::NAMES) for the erode brush,
+    /// - `air` to toggle whether blend brushes blend air,
+    /// - `d<n>` for the depth of overlay and underlay brushes,
+    /// - `y<n>` for the level of canyon brushes.
+    ///
+    /// Returns the message confirming the change.
+    pub fn configure(&mut self, parameter: &str) -> Result<String, String> {
+        let parameter = parameter.to_ascii_lowercase();
+        let name = self.name();
+        let invalid = || format!("Unknown parameter '{parameter}' for the {name} brush.");
+
+        if let Ok(value) = parameter.parse::<bool>() {
+            let (Self::Ball { true_circle }
+            | Self::Disc { true_circle }
+            | Self::Cylinder { true_circle }
+            | Self::Ellipse { true_circle, .. }
+            | Self::Ellipsoid { true_circle, .. }
+            | Self::Erode { true_circle, .. }
+            | Self::BlendBall { true_circle, .. }
+            | Self::BlendDisc { true_circle, .. }
+            | Self::Drain { true_circle }
+            | Self::Overlay { true_circle, .. }
+            | Self::Underlay { true_circle, .. }) = self
+            else {
+                return Err(format!("The {name} brush isn't round."));
+            };
+            *true_circle = value;
+            return Ok(format!("True circle {}.", if value { "on" } else { "off" }));
+        }
+
+        if let Self::Erode { erosion, .. } = self {
+            *erosion = Erosion::parse(&parameter).ok_or_else(|| {
+                format!(
+                    "Unknown erosion preset '{parameter}', expected one of: {}.",
+                    Erosion::NAMES.join(", ")
+                )
+            })?;
+            return Ok(format!("Erosion preset set to {}.", erosion.name()));
+        }
+
+        if parameter == "air" {
+            let (Self::BlendBall { air, .. }
+            | Self::BlendDisc { air, .. }
+            | Self::BlendVoxel { air }) = self
+            else {
+                return Err(invalid());
+            };
+            *air = !*air;
+            return Ok(format!("Blending air {}.", if *air { "on" } else { "off" }));
+        }
+
+        let (key, value) = parameter.split_at_checked(1).ok_or_else(invalid)?;
+        let value: i32 = value.parse().map_err(|_| invalid())?;
+        match (self, key) {
+            (Self::Canyon { level }, "y") => {
+                if !(MIN_CANYON_LEVEL..SEA_LEVEL).contains(&value) {
+                    return Err(format!(
+                        "Canyon level must be between {MIN_CANYON_LEVEL} and {}.",
+                        SEA_LEVEL - 1
+                    ));
+                }
+                *level = value;
+                Ok(format!("Canyon level set to {value}."))
+            }
+            (Self::Overlay { depth, .. } | Self::Underlay { depth, .. }, "d") => {
+                if !(1..=MAX_SIZE).contains(&value) {
+                    return Err(format!("Depth must be between 1 and {MAX_SIZE}."));
+                }
+                *depth = value;
+                Ok(format!("Depth set to {value}."))
+            }
+            (Self::Ellipse { x, .. } | Self::Ellipsoid { x, .. }, "x")
+            | (Self::Ellipsoid { y: x, .. }, "y")
+            | (Self::Ellipse { z: x, .. } | Self::Ellipsoid { z: x, .. }, "z") => {
+                if !(0..=MAX_SIZE).contains(&value) {
+                    return Err(format!("Radius must be between 0 and {MAX_SIZE}."));
+                }
+                *x = Some(value);
+                Ok(format!(
+                    "{} radius set to {value}.",
+                    key.to_ascii_uppercase()
+                ))
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Runs a stroke of the brush. An arrow acts on the target block itself, gunpowder
+    /// on the block in front of it.
+    ///
+    /// The triangle and line brushes remember the points clicked so far and tell the
+    /// player about them; they only return positions once they are complete.
+    pub async fn stroke(
+        &mut self,
+        sniper: &Sniper,
+        player: &Player,
+        action: Action,
+        target: Target,
+    ) -> Stroke {
+        let center = match action {
+            Action::Primary => target.block,
+            Action::Secondary => target.last,
+        };
+        let axis =
+            |axis: Option<i32>, true_circle: bool| radius(axis.unwrap_or(sniper.size), true_circle);
+
+        let positions = match self {
+            Self::Snipe => vec![center],
+            Self::Ball { true_circle } => ball(center, sniper.size, *true_circle),
+            Self::Disc { true_circle } => disc(center, sniper.size, *true_circle),
+            Self::Voxel => cube(center, sniper.size),
+            Self::Cylinder { true_circle } => (0..sniper.height)
+                .flat_map(|dy| {
+                    let base = BlockPos(center.0 + Vector3::new(0, sniper.center + dy, 0));
+                    disc(base, sniper.size, *true_circle)
+                })
+                .collect(),
+            Self::Ellipse { x, z, true_circle } => ellipsoid(
+                center,
+                Vector3::new(axis(*x, *true_circle), 0.0, axis(*z, *true_circle)),
+            ),
+            Self::Ellipsoid {
+                x,
+                y,
+                z,
+                true_circle,
+            } => ellipsoid(
+                center,
+                Vector3::new(
+                    axis(*x, *true_circle),
+                    axis(*y, *true_circle),
+                    axis(*z, *true_circle),
+                ),
+            ),
+            Self::Triangle { corners } => {
+                corners.push(center);
+                if corners.len() < 3 {
+                    let message = format!("Corner {} of 3 set.", corners.len());
+                    player
+                        .send_system_message(&TextComponent::text(message))
+                        .await;
+                    return Stroke::Positions(Vec::new());
+                }
+                let positions = triangle(corners[0], corners[1], corners[2]);
+                corners.clear();
+                positions.into_iter().collect()
+            }
+            Self::Line { origin } => match (action, *origin) {
+                (Action::Primary, _) => {
+                    *origin = Some(target.block);
+                    player
+                        .send_system_message(&TextComponent::text("Line origin set."))
+                        .await;
+                    Vec::new()
+                }
+                (Action::Secondary, Some(origin)) => line(origin, target.block),
+                (Action::Secondary, None) => {
+                    player
+                        .send_system_message(&TextComponent::text(
+                            "Set the line's origin with an arrow first.",
+                        ))
+                        .await;
+                    Vec::new()
+                }
+            },
+            _ => return self.terrain(sniper, player, center).await,
+        };
+        Stroke::Positions(positions)
+    }
+
+    /// Runs a stroke of a terrain brush, on a snapshot of the blocks around `center`
+    /// read up front.
+    async fn terrain(&self, sniper: &Sniper, player: &Player, center: BlockPos) -> Stroke {
+        let world = player.world().await;
+        let air = Block::AIR.default_state.id;
+        let is_empty = |state_id: u16| is_air(state_id) || is_liquid(state_id);
+
+        if let Self::Canyon { level } = self {
+            // Worlds may end above the lowest level allowed.
+            let level = (*level).max(min_y(&world));
+            let shift = SEA_LEVEL - level;
+            if shift <= 0 {
+                let message = format!("The canyon level must be below {SEA_LEVEL}.");
+                player
+                    .send_system_message(&TextComponent::text(message))
+                    .await;
+                return Stroke::Positions(Vec::new());
+            }
+            let (x0, z0) = (center.0.x & !15, center.0.z & !15);
+            let top = max_y(&world);
+            let buffer = read_blocks(
+                &world,
+                BlockPos(Vector3::new(x0, level, z0)),
+                BlockPos(Vector3::new(x0 + 15, top, z0 + 15)),
+            )
+            .await;
+            let mut states = Vec::new();
+            for x in x0..x0 + 16 {
+                for z in z0..z0 + 16 {
+                    for y in level..=top {
+                        let from = BlockPos(Vector3::new(x, y + shift, z));
+                        let state = buffer.get(from).unwrap_or(air);
+                        states.push((BlockPos(Vector3::new(x, y, z)), state));
+                    }
+                }
+            }
+            return Stroke::States(states);
+        }
+
+        // Everything else stays within the brush size of the centre, and looks at most
+        // one block further.
+        let size = sniper.size;
+        let reach = Vector3::new(size + 1, size + 1, size + 1);
+        let buffer = read_blocks(
+            &world,
+            BlockPos(center.0 - reach),
+            BlockPos(center.0 + reach),
+        )
+        .await;
+        let get = |pos: BlockPos| buffer.get(pos).unwrap_or(air);
+        let blends = |blend_air: bool| move |state_id: u16| blend_air || !is_air(state_id);
+
+        match self {
+            Self::Erode {
+                erosion,
+                true_circle,
+            } => Stroke::States(erode(
+                &ball(center, size, *true_circle),
+                get,
+                is_empty,
+                air,
+                *erosion,
+            )),
+            Self::BlendBall {
+                air: blend_air,
+                true_circle,
+            } => Stroke::States(blend(
+                &ball(center, size, *true_circle),
+                get,
+                &neighbours(false),
+                blends(*blend_air),
+            )),
+            Self::BlendDisc {
+                air: blend_air,
+                true_circle,
+            } => Stroke::States(blend(
+                &disc(center, size, *true_circle),
+                get,
+                &neighbours(true),
+                blends(*blend_air),
+            )),
+            Self::BlendVoxel { air: blend_air } => Stroke::States(blend(
+                &cube(center, size),
+                get,
+                &neighbours(false),
+                blends(*blend_air),
+            )),
+            Self::Drain { true_circle } => Stroke::States(
+                ball(center, size, *true_circle)
+                    .into_iter()
+                    .filter(|pos| is_liquid(get(*pos)))
+                    .map(|pos| (pos, air))
+                    .collect(),
+            ),
+            Self::Overlay { depth, true_circle } | Self::Underlay { depth, true_circle } => {
+                // Underlays start right below the surface block instead of at it.
+                let skip = i32::from(matches!(self, Self::Underlay { .. }));
+                let mut positions = Vec::new();
+                for column in disc(center, size, *true_circle) {
+                    let (x, z) = (column.0.x, column.0.z);
+                    let Some(top) =
+                        surface(x, z, center.0.y - size, center.0.y + size, get, is_empty)
+                    else {
+                        continue;
+                    };
+                    let top = top - skip;
+                    positions.extend(
+                        (top - depth + 1..=top)
+                            .rev()
+                            .map(|y| BlockPos(Vector3::new(x, y, z)))
+                            .take_while(|pos| !is_empty(get(*pos))),
+                    );
+                }
+                Stroke::Positions(positions)
+            }
+            _ => Stroke::Positions(Vec::new()),
+        }
+    }
+}
+
+/// The radius of a round brush, with the half block of the true circle option.
+fn radius(size: i32, true_circle: bool) -> f64 {
+    f64::from(size) + if true_circle { 0.5 } else { 0.0 }
+}
+
+fn ball(center: BlockPos, size: i32, true_circle: bool) -> Vec<BlockPos> {
+    let r = radius(size, true_circle);
+    ellipsoid(center, Vector3::new(r, r, r))
+}
+
+fn disc(center: BlockPos, size: i32, true_circle: bool) -> Vec<BlockPos> {
+    let r = radius(size, true_circle);
+    ellipsoid(center, Vector3::new(r, 0.0, r))
+}
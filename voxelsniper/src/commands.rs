@@ -0,0 +1,120 @@
+use pumpkin::plugin::Context;
+use pumpkin_util::permission::{Permission, PermissionDefault, PermissionLvl};
+
+mod brush;
+mod center;
+mod height;
+mod performer;
+
+mod ink;
+mod replace;
+mod replaceink;
+mod voxel;
+mod voxellist;
+
+mod default;
+mod info;
+
+mod undo;
+mod undouser;
+
+pub async fn register_commmand(context: &Context) {
+    context
+        .register_command(brush::init_command_tree(), "voxelsniper:brush")
+        .await;
+    context
+        .register_command(height::init_command_tree(), "voxelsniper:brush")
+        .await;
+    context
+        .register_command(center::init_command_tree(), "voxelsniper:brush")
+        .await;
+    context
+        .register_command(performer::init_command_tree(), "voxelsniper:brush")
+        .await;
+
+    context
+        .register_command(voxel::init_command_tree(), "voxelsniper:voxel")
+        .await;
+    context
+        .register_command(replace::init_command_tree(), "voxelsniper:voxel")
+        .await;
+    context
+        .register_command(ink::init_command_tree(), "voxelsniper:voxel")
+        .await;
+    context
+        .register_command(replaceink::init_command_tree(), "voxelsniper:voxel")
+        .await;
+    context
+        .register_command(voxellist::init_command_tree(), "voxelsniper:voxel")
+        .await;
+
+    context
+        .register_command(default::init_command_tree(), "voxelsniper:brush")
+        .await;
+    context
+        .register_command(info::init_command_tree(), "voxelsniper:info")
+        .await;
+
+    context
+        .register_command(undo::init_command_tree(), "voxelsniper:undo")
+        .await;
+    context
+        .register_command(undouser::init_command_tree(), "voxelsniper:undo.others")
+        .await;
+}
+
+pub async fn register_permission(context: &Context) {
+    context
+        .register_permission(Permission::new(
+            "voxelsniper:sniper",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "voxelsniper:brush",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "voxelsniper:voxel",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "voxelsniper:info",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "voxelsniper:undo",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "voxelsniper:undo.others",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::message::MsgArgConsumer;
+use pumpkin::command::args::{
+    Arg, ArgumentConsumer, ConsumedArgs, FindArg, GetClientSideArgParser,
+};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::command::tree::{CommandTree, RawArgs};
+use pumpkin::server::Server;
+use pumpkin_protocol::client::play::{
+    ArgumentType, CommandSuggestion, StringProto, SuggestionProviders,
+};
+use pumpkin_util::text::TextComponent;
+
+use crate::brush::{BRUSH_NAMES, Brush, MAX_SIZE};
+use crate::sniper::modify_sniper;
+
+const NAMES: [&str; 1] = ["b"];
+
+const DESCRIPTION: &str = "Select a brush, its size and parameters";
+
+const ARG_DESC_BRUSH: &str = "The brush to use";
+const ARG_DESC_SIZE: &str = "The brush size";
+const ARG_DESC_PARAMETERS: &str = "The brush size and parameters, such as true or x3";
+
+fn size_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(0)
+        .max(MAX_SIZE)
+        .name(ARG_DESC_SIZE)
+}
+
+/// Consumes a brush name, suggesting the known ones.
+struct BrushArgumentConsumer;
+
+impl GetClientSideArgParser for BrushArgumentConsumer {
+    fn get_client_side_parser(&self) -> ArgumentType {
+        ArgumentType::String(StringProto::SingleWord)
+    }
+
+    fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
+        Some(SuggestionProviders::AskServer)
+    }
+}
+
+#[async_trait]
+impl ArgumentConsumer for BrushArgumentConsumer {
+    async fn consume<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        _server: &'a Server,
+        args: &mut RawArgs<'a>,
+    ) -> Option<Arg<'a>> {
+        let name = args.pop()?;
+        Brush::parse(name).map(|_| Arg::Simple(name))
+    }
+
+    async fn suggest<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        _server: &'a Server,
+        input: &'a str,
+    ) -> Result<Option<Vec<CommandSuggestion>>, CommandError> {
+        let input = input.rsplit(' ').next().unwrap_or(input);
+        Ok(Some(
+            BRUSH_NAMES
+                .iter()
+                .filter(|name| name.starts_with(input))
+                .map(|name| CommandSuggestion::new((*name).to_string(), None))
+                .collect(),
+        ))
+    }
+}
+
+struct BrushExecuter;
+
+#[async_trait]
+impl CommandExecutor for BrushExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let size_error =
+            || CommandError::GeneralCommandIssue(format!("Size must be between 0 and {MAX_SIZE}."));
+        let mut brush = match args.get(ARG_DESC_BRUSH) {
+            Some(Arg::Simple(name)) => Brush::parse(name),
+            _ => None,
+        };
+        let mut size = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_SIZE) {
+            Ok(Ok(size)) => Some(size),
+            Ok(Err(_)) => return Err(size_error()),
+            Err(_) => None,
+        };
+
+        let mut messages = Vec::new();
+        if let Some(brush) = &brush {
+            messages.push(format!("Brush set to {}.", brush.name()));
+        }
+        if let (Some(Arg::Msg(parameters)), Some(brush)) =
+            (args.get(ARG_DESC_PARAMETERS), brush.as_mut())
+        {
+            for parameter in parameters.split_whitespace() {
+                if let Ok(value) = parameter.parse::<i32>() {
+                    if !(0..=MAX_SIZE).contains(&value) {
+                        return Err(size_error());
+                    }
+                    size = Some(value);
+                } else {
+                    messages.push(
+                        brush
+                            .configure(parameter)
+                            .map_err(CommandError::GeneralCommandIssue)?,
+                    );
+                }
+            }
+        }
+        if let Some(size) = size {
+            messages.push(format!("Brush size set to {size}."));
+        }
+        modify_sniper(player, |sniper| {
+            if let Some(brush) = brush {
+                sniper.brush = brush;
+                sniper.enabled = true;
+            }
+            if let Some(size) = size {
+                sniper.size = size;
+            }
+        })
+        .await;
+
+        for message in messages {
+            sender.send_message(TextComponent::text(message)).await;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC_SIZE, size_consumer()).execute(BrushExecuter))
+        .then(
+            argument(ARG_DESC_BRUSH, BrushArgumentConsumer)
+                .then(argument(ARG_DESC_PARAMETERS, MsgArgConsumer).execute(BrushExecuter))
+                .execute(BrushExecuter),
+        )
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::{ConsumedArgs, FindArg};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::brush::MAX_SIZE;
+use crate::sniper::modify_sniper;
+
+const NAMES: [&str; 1] = ["vc"];
+
+const DESCRIPTION: &str = "Set how far above the target brushes are centred";
+
+const ARG_DESC: &str = "The offset, negative to centre below the target";
+
+struct CenterExecuter;
+
+#[async_trait]
+impl CommandExecutor for CenterExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let Ok(center) = BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC)? else {
+            return Err(CommandError::GeneralCommandIssue(format!(
+                "Center must be between -{MAX_SIZE} and {MAX_SIZE}."
+            )));
+        };
+        modify_sniper(player, |sniper| sniper.center = center).await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Brush center set to {center}."
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(
+            ARG_DESC,
+            BoundedNumArgumentConsumer::new()
+                .min(-MAX_SIZE)
+                .max(MAX_SIZE)
+                .name(ARG_DESC),
+        )
+        .execute(CenterExecuter),
+    )
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::sniper::{Sniper, modify_sniper};
+
+const NAMES: [&str; 1] = ["d"];
+
+const DESCRIPTION: &str = "Reset the sniper to its defaults";
+
+struct DefaultExecuter;
+
+#[async_trait]
+impl CommandExecutor for DefaultExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        modify_sniper(player, |sniper| {
+            *sniper = Sniper {
+                enabled: sniper.enabled,
+                ..Sniper::default()
+            }
+        })
+        .await;
+
+        sender
+            .send_message(TextComponent::text(
+                "Sniper reset to its defaults.".to_string(),
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).execute(DefaultExecuter)
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::{ConsumedArgs, FindArg};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::brush::MAX_SIZE;
+use crate::sniper::modify_sniper;
+
+const NAMES: [&str; 1] = ["vh"];
+
+const DESCRIPTION: &str = "Set the height of brushes with one";
+
+const ARG_DESC: &str = "The height";
+
+struct HeightExecuter;
+
+#[async_trait]
+impl CommandExecutor for HeightExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let height = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC)? {
+            Ok(height) => height,
+            Err(_) => {
+                return Err(CommandError::GeneralCommandIssue(format!(
+                    "Height must be between 1 and {MAX_SIZE}."
+                )));
+            }
+        };
+        modify_sniper(player, |sniper| sniper.height = height).await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Brush height set to {height}."
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(
+            ARG_DESC,
+            BoundedNumArgumentConsumer::new()
+                .min(1)
+                .max(MAX_SIZE)
+                .name(ARG_DESC),
+        )
+        .execute(HeightExecuter),
+    )
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::sniper::get_sniper;
+use crate::utils::block::{block_of, properties_string};
+
+const NAMES: [&str; 1] = ["vs"];
+
+const DESCRIPTION: &str = "Show the sniper's settings";
+
+struct InfoExecuter;
+
+#[async_trait]
+impl CommandExecutor for InfoExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let sniper = get_sniper(player).await;
+        let voxel_list: Vec<&str> = sniper
+            .voxel_list
+            .iter()
+            .map(|state_id| block_of(*state_id).name)
+            .collect();
+        let lines = [
+            format!("Brush: {} (size {})", sniper.brush.name(), sniper.size),
+            format!("Performer: {}", sniper.performer.name()),
+            format!(
+                "Voxel: {}{}",
+                block_of(sniper.voxel).name,
+                properties_string(&sniper.ink)
+            ),
+            format!(
+                "Replace: {}{}",
+                block_of(sniper.replace).name,
+                properties_string(&sniper.replace_ink)
+            ),
+            format!("Height: {}, center: {}", sniper.height, sniper.center),
+            format!("Voxel list: [{}]", voxel_list.join(", ")),
+        ];
+        for line in lines {
+            sender.send_message(TextComponent::text(line)).await;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).execute(InfoExecuter)
+}
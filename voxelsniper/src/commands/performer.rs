@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::{Arg, ArgumentConsumer, ConsumedArgs, GetClientSideArgParser};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::command::tree::{CommandTree, RawArgs};
+use pumpkin::server::Server;
+use pumpkin_protocol::client::play::{
+    ArgumentType, CommandSuggestion, StringProto, SuggestionProviders,
+};
+use pumpkin_util::text::TextComponent;
+
+use crate::performer::{PERFORMER_NAMES, Performer};
+use crate::sniper::{get_sniper, modify_sniper};
+
+const NAMES: [&str; 1] = ["p"];
+
+const DESCRIPTION: &str = "Select how the brush writes its blocks";
+
+const ARG_DESC: &str = "The performer to use";
+
+/// Consumes a performer name, suggesting the known ones.
+struct PerformerArgumentConsumer;
+
+impl GetClientSideArgParser for PerformerArgumentConsumer {
+    fn get_client_side_parser(&self) -> ArgumentType {
+        ArgumentType::String(StringProto::SingleWord)
+    }
+
+    fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
+        Some(SuggestionProviders::AskServer)
+    }
+}
+
+#[async_trait]
+impl ArgumentConsumer for PerformerArgumentConsumer {
+    async fn consume<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        _server: &'a Server,
+        args: &mut RawArgs<'a>,
+    ) -> Option<Arg<'a>> {
+        let name = args.pop()?;
+        Performer::parse(name).map(|_| Arg::Simple(name))
+    }
+
+    async fn suggest<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        _server: &'a Server,
+        input: &'a str,
+    ) -> Result<Option<Vec<CommandSuggestion>>, CommandError> {
+        let input = input.rsplit(' ').next().unwrap_or(input);
+        Ok(Some(
+            PERFORMER_NAMES
+                .iter()
+                .filter(|name| name.starts_with(input))
+                .map(|name| CommandSuggestion::new((*name).to_string(), None))
+                .collect(),
+        ))
+    }
+}
+
+struct PerformerExecuter;
+
+#[async_trait]
+impl CommandExecutor for PerformerExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let Some(Arg::Simple(name)) = args.get(ARG_DESC) else {
+            return Err(CommandError::InvalidConsumption(Some(ARG_DESC.to_string())));
+        };
+        let Some(performer) = Performer::parse(name) else {
+            return Err(CommandError::GeneralCommandIssue(format!(
+                "Unknown performer '{name}'."
+            )));
+        };
+
+        let brush = get_sniper(player).await.brush;
+        if !brush.performs() {
+            return Err(CommandError::GeneralCommandIssue(format!(
+                "The {} brush doesn't use a performer.",
+                brush.name()
+            )));
+        }
+        modify_sniper(player, |sniper| sniper.performer = performer).await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Performer set to {}.",
+                performer.name()
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, PerformerArgumentConsumer).execute(PerformerExecuter))
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::block::BlockArgumentConsumer;
+use pumpkin::command::args::{ConsumedArgs, FindArg};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::sniper::modify_sniper;
+
+const NAMES: [&str; 1] = ["vr"];
+
+const DESCRIPTION: &str = "Set the material replacing performers change";
+
+const ARG_DESC: &str = "The material to replace";
+
+struct ReplaceExecuter;
+
+#[async_trait]
+impl CommandExecutor for ReplaceExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let block = BlockArgumentConsumer::find_arg(args, ARG_DESC)?;
+        modify_sniper(player, |sniper| sniper.replace = block.default_state.id).await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Replace material set to {}.",
+                block.name
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, BlockArgumentConsumer).execute(ReplaceExecuter))
+}
@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::args::{Arg, ConsumedArgs};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::sniper::modify_sniper;
+use crate::utils::block::{parse_properties, properties_string};
+
+const NAMES: [&str; 1] = ["vir"];
+
+const DESCRIPTION: &str = "Set the block state properties replacing performers match";
+
+const ARG_DESC: &str = "The properties, as [key=value,...]";
+
+struct ReplaceInkExecuter;
+
+#[async_trait]
+impl CommandExecutor for ReplaceInkExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let ink = match args.get(ARG_DESC) {
+            Some(Arg::Simple(ink)) => {
+                parse_properties(ink).map_err(CommandError::GeneralCommandIssue)?
+            }
+            _ => Vec::new(),
+        };
+        let message = format!("Replace ink set to {}.", properties_string(&ink));
+        modify_sniper(player, |sniper| sniper.replace_ink = ink).await;
+
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, SimpleArgConsumer).execute(ReplaceInkExecuter))
+        .execute(ReplaceInkExecuter)
+}
@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::{ConsumedArgs, FindArg};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::undo::undo;
+
+const NAMES: [&str; 1] = ["u"];
+
+const DESCRIPTION: &str = "Undo your last sniper strokes";
+
+const ARG_DESC: &str = "The number of strokes to undo";
+
+struct UndoExecuter;
+
+#[async_trait]
+impl CommandExecutor for UndoExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let count = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC) {
+            Ok(Ok(count)) => count as usize,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Count must be 1 or greater.".to_string(),
+                ));
+            }
+            Err(_) => 1,
+        };
+
+        let message = match undo(player, count).await {
+            0 => "Nothing left to undo.".to_string(),
+            1 => "Undid 1 stroke.".to_string(),
+            undone => format!("Undid {undone} strokes."),
+        };
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            argument(
+                ARG_DESC,
+                BoundedNumArgumentConsumer::new().min(1).name(ARG_DESC),
+            )
+            .execute(UndoExecuter),
+        )
+        .execute(UndoExecuter)
+}
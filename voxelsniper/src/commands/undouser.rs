@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::players::PlayersArgumentConsumer;
+use pumpkin::command::args::{ConsumedArgs, FindArg};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::undo::undo;
+
+const NAMES: [&str; 1] = ["uu"];
+
+const DESCRIPTION: &str = "Undo another player's last sniper stroke";
+
+const ARG_DESC: &str = "The player whose stroke to undo";
+
+struct UndoUserExecuter;
+
+#[async_trait]
+impl CommandExecutor for UndoUserExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let targets = PlayersArgumentConsumer::find_arg(args, ARG_DESC)?;
+
+        for target in targets {
+            let name = &target.gameprofile.name;
+            let message = if undo(target, 1).await == 0 {
+                format!("{name} has nothing left to undo.")
+            } else {
+                format!("Undid the last stroke of {name}.")
+            };
+            sender.send_message(TextComponent::text(message)).await;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, PlayersArgumentConsumer).execute(UndoUserExecuter))
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::block::BlockArgumentConsumer;
+use pumpkin::command::args::{ConsumedArgs, FindArg};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::sniper::modify_sniper;
+
+const NAMES: [&str; 1] = ["v"];
+
+const DESCRIPTION: &str = "Set the voxel material the sniper places";
+
+const ARG_DESC: &str = "The material to place";
+
+struct VoxelExecuter;
+
+#[async_trait]
+impl CommandExecutor for VoxelExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let block = BlockArgumentConsumer::find_arg(args, ARG_DESC)?;
+        modify_sniper(player, |sniper| sniper.voxel = block.default_state.id).await;
+
+        sender
+            .send_message(TextComponent::text(format!("Voxel set to {}.", block.name)))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, BlockArgumentConsumer).execute(VoxelExecuter))
+}
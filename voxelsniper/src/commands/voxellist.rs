@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::block::BlockArgumentConsumer;
+use pumpkin::command::args::{ConsumedArgs, FindArg};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::{argument, literal};
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::sniper::modify_sniper;
+
+const NAMES: [&str; 1] = ["vl"];
+
+const DESCRIPTION: &str = "Add blocks to or remove them from the voxel list";
+
+const ARG_DESC: &str = "The block";
+
+#[derive(Clone, Copy)]
+enum Change {
+    Add,
+    Remove,
+    Clear,
+}
+
+struct VoxelListExecuter(Change);
+
+#[async_trait]
+impl CommandExecutor for VoxelListExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let message = match self.0 {
+            Change::Add => {
+                let block = BlockArgumentConsumer::find_arg(args, ARG_DESC)?;
+                let state_id = block.default_state.id;
+                modify_sniper(player, |sniper| {
+                    if !sniper.voxel_list.contains(&state_id) {
+                        sniper.voxel_list.push(state_id);
+                    }
+                })
+                .await;
+                format!("Added {} to the voxel list.", block.name)
+            }
+            Change::Remove => {
+                let block = BlockArgumentConsumer::find_arg(args, ARG_DESC)?;
+                let state_id = block.default_state.id;
+                modify_sniper(player, |sniper| {
+                    sniper.voxel_list.retain(|voxel| *voxel != state_id);
+                })
+                .await;
+                format!("Removed {} from the voxel list.", block.name)
+            }
+            Change::Clear => {
+                modify_sniper(player, |sniper| sniper.voxel_list.clear()).await;
+                "Voxel list cleared.".to_string()
+            }
+        };
+
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(literal("clear").execute(VoxelListExecuter(Change::Clear)))
+        .then(literal("remove").then(
+            argument(ARG_DESC, BlockArgumentConsumer).execute(VoxelListExecuter(Change::Remove)),
+        ))
+        .then(argument(ARG_DESC, BlockArgumentConsumer).execute(VoxelListExecuter(Change::Add)))
+}
@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use common::config::{properties, read_or_create};
+
+const FILE_NAME: &str = "config.properties";
+
+const DEFAULT: &str = "\
+# How far the sniper reaches, in blocks.
+sniper.range=250
+# How many strokes each player can undo.
+undo.depth=20
+# How many changed blocks are kept for undo per player. Older strokes are forgotten
+# beyond this, and a single stroke changing more can't be undone.
+undo.blocks=1000000
+";
+
+/// Settings read from `config.properties` in the plugin's data folder.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub range: f64,
+    pub undo_depth: usize,
+    pub undo_blocks: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::parse(DEFAULT)
+    }
+}
+
+impl Config {
+    /// Parses `key=value` lines, ignoring blank lines, `#` comments and unknown keys.
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        let mut config = Self {
+            range: 250.0,
+            undo_depth: 20,
+            undo_blocks: 1_000_000,
+        };
+        for (key, value) in properties(content) {
+            match key {
+                "sniper.range" => match value.parse() {
+                    Ok(range) => config.range = range,
+                    Err(_) => log::warn!("Invalid sniper.range {value}"),
+                },
+                "undo.depth" => match value.parse() {
+                    Ok(depth) => config.undo_depth = depth,
+                    Err(_) => log::warn!("Invalid undo.depth {value}"),
+                },
+                "undo.blocks" => match value.parse() {
+                    Ok(blocks) => config.undo_blocks = blocks,
+                    Err(_) => log::warn!("Invalid undo.blocks {value}"),
+                },
+                key => log::warn!("Unknown config key {key}"),
+            }
+        }
+        config
+    }
+
+    /// Loads the config from `data_folder`, writing the defaults if it doesn't exist.
+    pub fn load(data_folder: &Path) -> Self {
+        Self::parse(&read_or_create(data_folder, FILE_NAME, DEFAULT))
+    }
+}
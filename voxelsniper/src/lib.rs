@@ -1,11 +1,112 @@
-use pumpkin::plugin::Context;
-use pumpkin_api_macros::{plugin_impl, plugin_method};
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, OnceLock},
+};
+
+use async_trait::async_trait;
+use config::Config;
+use pumpkin::{
+    plugin::{
+        Context, EventHandler, EventPriority,
+        player::player_interact_event::{InteractAction, PlayerInteractEvent},
+    },
+    server::Server,
+};
+use pumpkin_api_macros::{plugin_impl, plugin_method, with_runtime};
+use pumpkin_data::item::Item;
+use pumpkin_util::text::TextComponent;
+use sniper::{Action, Sniper, get_sniper, modify_sniper};
+use tokio::sync::RwLock;
+use utils::history::History;
+
+pub mod utils;
+
+mod brush;
+mod commands;
+mod config;
+mod performer;
+mod sniper;
+mod undo;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+fn config() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+type Snipers = HashMap<uuid::Uuid, Sniper>;
+
+static SNIPERS: OnceLock<RwLock<Snipers>> = OnceLock::new();
+
+fn snipers() -> &'static RwLock<Snipers> {
+    SNIPERS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+type Histories = HashMap<uuid::Uuid, History>;
+
+static HISTORIES: OnceLock<RwLock<Histories>> = OnceLock::new();
+
+fn histories() -> &'static RwLock<Histories> {
+    HISTORIES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+struct SniperHandler;
+
+#[with_runtime(global)]
+#[async_trait]
+impl EventHandler<PlayerInteractEvent> for SniperHandler {
+    async fn handle_blocking(&self, _server: &Arc<Server>, event: &mut PlayerInteractEvent) {
+        if !matches!(
+            event.action,
+            InteractAction::RightClickBlock | InteractAction::RightClickAir
+        ) {
+            return;
+        }
+        let item = event.item.lock().await.item;
+        let action = if item == &Item::ARROW {
+            Action::Primary
+        } else if item == &Item::GUNPOWDER {
+            Action::Secondary
+        } else {
+            return;
+        };
+
+        if !event.player.has_permission("voxelsniper:sniper").await {
+            return;
+        }
+        let mut sniper = get_sniper(&event.player).await;
+        if !sniper.enabled {
+            return;
+        }
+
+        event.cancelled = true;
+
+        let result = sniper.snipe(&event.player, action).await;
+        modify_sniper(&event.player, |current| current.brush = sniper.brush).await;
+        if let Err(message) = result {
+            event
+                .player
+                .send_system_message(&TextComponent::text(message))
+                .await;
+        }
+    }
+}
 
 #[plugin_method]
 async fn on_load(&mut self, context: &Context) -> Result<(), String> {
     pumpkin::init_log!();
 
-    // log::debug!("Hello World");
+    let _ = CONFIG.set(Config::load(Path::new(&context.get_data_folder())));
+
+    log::debug!("Registering commands...");
+    commands::register_permission(context).await;
+    commands::register_commmand(context).await;
+    log::debug!("Commands registered!");
+
+    context
+        .register_event(Arc::new(SniperHandler), EventPriority::Lowest, true)
+        .await;
 
     Ok(())
 }
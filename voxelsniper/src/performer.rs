@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use pumpkin::world::World;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_world::world::BlockFlags;
+
+use crate::sniper::Sniper;
+use crate::utils::block::{block_of, has_properties, with_properties};
+use crate::utils::history::Change;
+use crate::utils::region::edit_positions_recorded;
+
+/// The names performers are selected by with `/p`.
+pub const PERFORMER_NAMES: &[&str] = &["m", "i", "c", "mm", "ii", "cc", "mp", "n", "x"];
+
+/// How a sniper writes the positions its brush picked.
+///
+/// The voxel is the block set with `/v` and the ink the properties set with `/vi`.
+/// Replacing performers only touch the blocks matching the replace block set with
+/// `/vr`, the replace ink set with `/vir`, or both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Performer {
+    /// Places the voxel.
+    Material,
+    /// Applies the ink to the blocks already there.
+    Ink,
+    /// Places the voxel with the ink applied.
+    Combo,
+    /// Places the voxel over the replace block.
+    MaterialReplace,
+    /// Applies the ink to blocks with the replace ink.
+    InkReplace,
+    /// Places the inked voxel over the replace block with the replace ink.
+    ComboReplace,
+    /// Places the voxel one block at a time, updating the blocks around it, so sand
+    /// falls and water flows.
+    MaterialPhysics,
+    /// Places the voxel without recording the stroke for undo.
+    MaterialNoUndo,
+    /// Places the inked voxel without recording the stroke for undo.
+    ComboNoUndo,
+}
+
+impl Performer {
+    /// Looks up a performer by one of its [names](PERFORMER_NAMES).
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "m" => Some(Self::Material),
+            "i" => Some(Self::Ink),
+            "c" => Some(Self::Combo),
+            "mm" => Some(Self::MaterialReplace),
+            "ii" => Some(Self::InkReplace),
+            "cc" => Some(Self::ComboReplace),
+            "mp" => Some(Self::MaterialPhysics),
+            "n" => Some(Self::MaterialNoUndo),
+            "x" => Some(Self::ComboNoUndo),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Material => "Material",
+            Self::Ink => "Ink",
+            Self::Combo => "Combo",
+            Self::MaterialReplace => "Material Replace",
+            Self::InkReplace => "Ink Replace",
+            Self::ComboReplace => "Combo Replace",
+            Self::MaterialPhysics => "Material Physics",
+            Self::MaterialNoUndo => "Material (no undo)",
+            Self::ComboNoUndo => "Combo (no undo)",
+        }
+    }
+
+    /// Whether strokes written by the performer are recorded for undo.
+    #[must_use]
+    pub fn undoable(self) -> bool {
+        !matches!(self, Self::MaterialNoUndo | Self::ComboNoUndo)
+    }
+
+    /// Writes `positions` into the world and returns the changed blocks with the
+    /// states they had before.
+    pub async fn perform(
+        self,
+        sniper: &Sniper,
+        world: &Arc<World>,
+        positions: Vec<BlockPos>,
+    ) -> Change {
+        let combo = with_properties(sniper.voxel, &sniper.ink);
+        let replaces = |state_id: u16| block_of(state_id).id == block_of(sniper.replace).id;
+        let replaces_ink = |state_id: u16| has_properties(state_id, &sniper.replace_ink);
+
+        match self {
+            Self::Material | Self::MaterialNoUndo => {
+                edit_positions_recorded(world, positions, |_, _| Some(sniper.voxel)).await
+            }
+            Self::Ink => {
+                edit_positions_recorded(world, positions, |_, state_id| {
+                    Some(with_properties(state_id, &sniper.ink))
+                })
+                .await
+            }
+            Self::Combo | Self::ComboNoUndo => {
+                edit_positions_recorded(world, positions, |_, _| Some(combo)).await
+            }
+            Self::MaterialReplace => {
+                edit_positions_recorded(world, positions, |_, state_id| {
+                    replaces(state_id).then_some(sniper.voxel)
+                })
+                .await
+            }
+            Self::InkReplace => {
+                edit_positions_recorded(world, positions, |_, state_id| {
+                    replaces_ink(state_id).then(|| with_properties(state_id, &sniper.ink))
+                })
+                .await
+            }
+            Self::ComboReplace => {
+                edit_positions_recorded(world, positions, |_, state_id| {
+                    (replaces(state_id) && replaces_ink(state_id)).then_some(combo)
+                })
+                .await
+            }
+            Self::MaterialPhysics => {
+                let mut change = Vec::new();
+                for pos in positions {
+                    let old = world
+                        .set_block_state(&pos, sniper.voxel, BlockFlags::NOTIFY_ALL)
+                        .await;
+                    if old != sniper.voxel {
+                        change.push((pos, old));
+                    }
+                }
+                change
+            }
+        }
+    }
+}
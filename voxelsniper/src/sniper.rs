@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use pumpkin::entity::EntityBase;
+use pumpkin::entity::player::Player;
+use pumpkin_data::Block;
+
+use crate::brush::{Brush, Stroke};
+use crate::performer::Performer;
+use crate::undo::record;
+use crate::utils::region::set_states;
+use crate::utils::trace::target;
+
+/// The brush size a new sniper starts with.
+pub const DEFAULT_SIZE: i32 = 3;
+
+/// How a sniper was fired: an arrow fires the primary action, gunpowder the
+/// secondary one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Primary,
+    Secondary,
+}
+
+/// The settings of a player's sniper.
+#[derive(Debug, Clone)]
+pub struct Sniper {
+    /// Whether a brush was chosen with `/b`. Until then, arrows and gunpowder are
+    /// used as usual.
+    pub enabled: bool,
+    pub brush: Brush,
+    pub performer: Performer,
+    /// The radius of the brush.
+    pub size: i32,
+    /// The block state the performer places, set with `/v`.
+    pub voxel: u16,
+    /// The block replacing performers change, set with `/vr`.
+    pub replace: u16,
+    /// The block state properties the performer applies, set with `/vi`.
+    pub ink: Vec<(String, String)>,
+    /// The properties replacing performers match, set with `/vir`.
+    pub replace_ink: Vec<(String, String)>,
+    /// The height of brushes with one, such as cylinders, set with `/vh`.
+    pub height: i32,
+    /// How far above the target brushes are centred, set with `/vc`.
+    pub center: i32,
+    /// The blocks some brushes work on, set with `/vl`.
+    pub voxel_list: Vec<u16>,
+}
+
+impl Default for Sniper {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            brush: Brush::Snipe,
+            performer: Performer::Material,
+            size: DEFAULT_SIZE,
+            voxel: Block::AIR.default_state.id,
+            replace: Block::AIR.default_state.id,
+            ink: Vec::new(),
+            replace_ink: Vec::new(),
+            height: 1,
+            center: 0,
+            voxel_list: Vec::new(),
+        }
+    }
+}
+
+impl Sniper {
+    /// Aims at the block the player is looking at and applies the brush there. Brushes
+    /// that take several clicks keep their progress in `self.brush`.
+    pub async fn snipe(&mut self, player: &Arc<Player>, action: Action) -> Result<(), String> {
+        let Some(target) = target(player, crate::config().range).await else {
+            return Err("Target out of range.".to_string());
+        };
+        let mut brush = self.brush.clone();
+        let stroke = brush.stroke(self, player, action, target).await;
+        self.brush = brush;
+        let world = player.world().await;
+        let change = match stroke {
+            Stroke::Positions(positions) if positions.is_empty() => return Ok(()),
+            Stroke::Positions(positions) => {
+                let change = self.performer.perform(self, &world, positions).await;
+                if !self.performer.undoable() {
+                    return Ok(());
+                }
+                change
+            }
+            Stroke::States(states) => set_states(&world, states).await,
+        };
+        record(player, &world, change).await;
+        Ok(())
+    }
+}
+
+/// Changes the player's sniper, creating it with the defaults if needed.
+pub async fn modify_sniper<F>(player: &Player, f: F)
+where
+    F: FnOnce(&mut Sniper),
+{
+    let mut snipers = crate::snipers().write().await;
+    f(snipers.entry(player.get_entity().entity_uuid).or_default());
+}
+
+/// Returns a copy of the player's sniper.
+pub async fn get_sniper(player: &Player) -> Sniper {
+    crate::snipers()
+        .read()
+        .await
+        .get(&player.get_entity().entity_uuid)
+        .cloned()
+        .unwrap_or_default()
+}
@@ -0,0 +1,41 @@
+use pumpkin::entity::EntityBase;
+use pumpkin::entity::player::Player;
+use pumpkin::world::World;
+
+use crate::utils::history::Change;
+use crate::utils::region::set_states;
+
+/// Records a stroke of the player's in `world` as one undo unit.
+pub async fn record(player: &Player, world: &World, change: Change) {
+    let config = crate::config();
+    let mut histories = crate::histories().write().await;
+    histories
+        .entry(player.get_entity().entity_uuid)
+        .or_default()
+        .push(
+            world.dimension_type,
+            change,
+            config.undo_depth,
+            config.undo_blocks,
+        );
+}
+
+/// Undoes up to `count` of the player's strokes in the world they are in, newest
+/// first. Returns the number of strokes undone.
+pub async fn undo(player: &Player, count: usize) -> usize {
+    let world = player.world().await;
+    let changes: Vec<Change> = {
+        let mut histories = crate::histories().write().await;
+        let Some(history) = histories.get_mut(&player.get_entity().entity_uuid) else {
+            return 0;
+        };
+        (0..count)
+            .map_while(|_| history.pop(world.dimension_type))
+            .collect()
+    };
+
+    for change in &changes {
+        set_states(&world, change.iter().copied()).await;
+    }
+    changes.len()
+}
@@ -0,0 +1,6 @@
+pub use common::{block, chunked_range, trace};
+
+pub mod history;
+pub mod region;
+pub mod shape;
+pub mod terrain;
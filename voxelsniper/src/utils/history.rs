@@ -0,0 +1,105 @@
+use std::collections::VecDeque;
+
+use pumpkin_registry::VanillaDimensionType;
+use pumpkin_util::math::position::BlockPos;
+
+/// The blocks a stroke changed, each with the state it had before.
+pub type Change = Vec<(BlockPos, u16)>;
+
+/// A player's recent strokes, newest last, each kept as one undo unit together with
+/// the dimension it was made in.
+#[derive(Debug, Default)]
+pub struct History {
+    changes: VecDeque<(VanillaDimensionType, Change)>,
+    /// The number of blocks recorded over all changes.
+    blocks: usize,
+}
+
+impl History {
+    /// Records a stroke's change, then drops the oldest ones until at most `depth`
+    /// changes with `max_blocks` blocks between them are left. A change larger than
+    /// `max_blocks` on its own isn't kept at all, and leaves the older ones alone.
+    pub fn push(
+        &mut self,
+        dimension: VanillaDimensionType,
+        change: Change,
+        depth: usize,
+        max_blocks: usize,
+    ) {
+        if change.is_empty() || change.len() > max_blocks {
+            return;
+        }
+        self.blocks += change.len();
+        self.changes.push_back((dimension, change));
+        while self.changes.len() > depth || self.blocks > max_blocks {
+            let Some((_, oldest)) = self.changes.pop_front() else {
+                break;
+            };
+            self.blocks -= oldest.len();
+        }
+    }
+
+    /// Takes the newest change made in `dimension` out of the history. Changes made in
+    /// other dimensions are kept for when the player returns.
+    pub fn pop(&mut self, dimension: VanillaDimensionType) -> Option<Change> {
+        let index = self.changes.iter().rposition(|(d, _)| *d == dimension)?;
+        let (_, change) = self.changes.remove(index)?;
+        self.blocks -= change.len();
+        Some(change)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_registry::VanillaDimensionType;
+    use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+    use super::{Change, History};
+
+    const OVERWORLD: VanillaDimensionType = VanillaDimensionType::Overworld;
+
+    fn change(blocks: i32) -> Change {
+        (0..blocks)
+            .map(|x| (BlockPos(Vector3::new(x, 0, 0)), 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_history_limits() {
+        let mut history = History::default();
+        for blocks in 1..=4 {
+            history.push(OVERWORLD, change(blocks), 3, 100);
+        }
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.pop(OVERWORLD).map(|change| change.len()), Some(4));
+
+        // The oldest changes make room for a big one.
+        history.push(OVERWORLD, change(8), 3, 10);
+        assert_eq!(history.len(), 1);
+        // One too big on its own is dropped without touching the older ones.
+        history.push(OVERWORLD, change(11), 3, 10);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.pop(OVERWORLD).map(|change| change.len()), Some(8));
+        assert!(history.pop(OVERWORLD).is_none());
+    }
+
+    #[test]
+    fn test_history_dimensions() {
+        let mut history = History::default();
+        history.push(OVERWORLD, change(1), 3, 100);
+        history.push(VanillaDimensionType::TheNether, change(2), 3, 100);
+        assert_eq!(history.pop(OVERWORLD).map(|change| change.len()), Some(1));
+        assert!(history.pop(OVERWORLD).is_none());
+        assert_eq!(history.len(), 1);
+    }
+}
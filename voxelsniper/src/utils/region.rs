@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use common::region::edit_positions;
+pub use common::region::{NEIGHBOURS, max_y, min_y, read_blocks};
+use pumpkin::world::World;
+use pumpkin_util::math::position::BlockPos;
+
+use super::history::Change;
+
+/// Like [`edit_positions`], but returns the changed blocks with the states they had
+/// before instead of their number, so the edit can be undone.
+pub async fn edit_positions_recorded<I, F>(world: &World, positions: I, mut f: F) -> Change
+where
+    I: IntoIterator<Item = BlockPos>,
+    F: FnMut(BlockPos, u16) -> Option<u16> + Send,
+{
+    let mut change = Vec::new();
+    edit_positions(world, positions, |pos, cur_block_id| {
+        let block_id = f(pos, cur_block_id)?;
+        if block_id != cur_block_id {
+            change.push((pos, cur_block_id));
+        }
+        Some(block_id)
+    })
+    .await;
+    change
+}
+
+/// Sets every position to the state paired with it, batched like [`edit_positions`].
+///
+/// Returns the changed blocks with the states they had before.
+pub async fn set_states<I>(world: &World, states: I) -> Change
+where
+    I: IntoIterator<Item = (BlockPos, u16)>,
+{
+    let states: HashMap<BlockPos, u16> = states.into_iter().collect();
+    let positions: Vec<BlockPos> = states.keys().copied().collect();
+    edit_positions_recorded(world, positions, |pos, _| states.get(&pos).copied()).await
+}
@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+
+pub use common::draw::line;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+/// Returns the blocks within the ellipsoid with the given radii around `center`. A
+/// radius of zero flattens the shape along that axis, so `(r, 0, r)` is a disc.
+#[must_use]
+pub fn ellipsoid(center: BlockPos, radii: Vector3<f64>) -> Vec<BlockPos> {
+    let extent = |radius: f64| radius.max(0.0).floor() as i32;
+    let term = |d: i32, radius: f64| {
+        if radius <= 0.0 {
+            0.0
+        } else {
+            (f64::from(d) / radius).powi(2)
+        }
+    };
+    let (ex, ey, ez) = (extent(radii.x), extent(radii.y), extent(radii.z));
+
+    let mut points = Vec::new();
+    for dx in -ex..=ex {
+        for dy in -ey..=ey {
+            for dz in -ez..=ez {
+                if term(dx, radii.x) + term(dy, radii.y) + term(dz, radii.z) <= 1.0 {
+                    points.push(BlockPos(center.0 + Vector3::new(dx, dy, dz)));
+                }
+            }
+        }
+    }
+    points
+}
+
+/// Returns the blocks of the cube reaching `radius` blocks from `center` along each
+/// axis.
+#[must_use]
+pub fn cube(center: BlockPos, radius: i32) -> Vec<BlockPos> {
+    let mut points = Vec::new();
+    for dx in -radius..=radius {
+        for dy in -radius..=radius {
+            for dz in -radius..=radius {
+                points.push(BlockPos(center.0 + Vector3::new(dx, dy, dz)));
+            }
+        }
+    }
+    points
+}
+
+/// Returns the blocks of the filled triangle with the given corners.
+///
+/// The triangle is filled with lines fanning out from every point of each edge to the
+/// opposite corner; doing so from all three edges leaves no gaps.
+#[must_use]
+pub fn triangle(a: BlockPos, b: BlockPos, c: BlockPos) -> HashSet<BlockPos> {
+    let mut points = HashSet::new();
+    for (from, to, corner) in [(a, b, c), (b, c, a), (c, a, b)] {
+        for point in line(from, to) {
+            points.extend(line(point, corner));
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+    use super::{ellipsoid, triangle};
+
+    fn pos(x: i32, y: i32, z: i32) -> BlockPos {
+        BlockPos(Vector3::new(x, y, z))
+    }
+
+    #[test]
+    fn test_ellipsoid() {
+        let center = pos(0, 64, 0);
+        assert_eq!(ellipsoid(center, Vector3::new(1.0, 1.0, 1.0)).len(), 7);
+        // The half block of a "true circle" rounds out the ball.
+        assert_eq!(ellipsoid(center, Vector3::new(1.5, 1.5, 1.5)).len(), 27 - 8);
+        assert_eq!(ellipsoid(center, Vector3::new(1.0, 0.0, 1.0)).len(), 5);
+        assert!(
+            ellipsoid(center, Vector3::new(3.0, 0.0, 1.0))
+                .iter()
+                .all(|point| point.0.y == 64 && point.0.z.abs() <= 1)
+        );
+    }
+
+    #[test]
+    fn test_triangle() {
+        let corners = [pos(0, 0, 0), pos(8, 0, 0), pos(0, 0, 8)];
+        let points = triangle(corners[0], corners[1], corners[2]);
+        assert!(corners.iter().all(|corner| points.contains(corner)));
+        assert!(points.contains(&pos(3, 0, 3)));
+        assert!(!points.contains(&pos(5, 0, 5)));
+        assert!(points.iter().all(|point| point.0.y == 0));
+    }
+}
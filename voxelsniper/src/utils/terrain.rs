@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::region::NEIGHBOURS;
+
+// The terrain brushes work on a snapshot of the blocks around a stroke, passed in as
+// `get`, so a stroke's result never depends on the order its blocks are visited in.
+
+fn offset(pos: BlockPos, (dx, dy, dz): (i32, i32, i32)) -> BlockPos {
+    BlockPos(pos.0 + Vector3::new(dx, dy, dz))
+}
+
+/// Counts the states, most common first. States that are equally common keep the
+/// order they were first seen in.
+fn tally<I: IntoIterator<Item = u16>>(states: I) -> Vec<(u16, usize)> {
+    let mut counts: Vec<(u16, usize)> = Vec::new();
+    for state in states {
+        match counts.iter_mut().find(|(counted, _)| *counted == state) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((state, 1)),
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1));
+    counts
+}
+
+/// The erosion presets of the erode brush.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Erosion {
+    /// Wears down edges and corners.
+    Melt,
+    /// Fills holes and dents.
+    Fill,
+    /// Evens out bumps and dents alike.
+    Smooth,
+    /// Grows a layer on everything.
+    Lift,
+    /// Removes floating blocks and fills enclosed gaps.
+    FloatClean,
+}
+
+impl Erosion {
+    pub const NAMES: &[&str] = &["melt", "fill", "smooth", "lift", "floatclean"];
+
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "melt" => Some(Self::Melt),
+            "fill" => Some(Self::Fill),
+            "smooth" => Some(Self::Smooth),
+            "lift" => Some(Self::Lift),
+            "floatclean" => Some(Self::FloatClean),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Melt => "melt",
+            Self::Fill => "fill",
+            Self::Smooth => "smooth",
+            Self::Lift => "lift",
+            Self::FloatClean => "floatclean",
+        }
+    }
+
+    /// How many faces of a block must be exposed for it to erode and how many passes
+    /// erode, then how many faces of a gap must be solid for it to fill and how many
+    /// passes fill.
+    fn settings(self) -> (usize, usize, usize, usize) {
+        match self {
+            Self::Melt => (2, 1, 5, 1),
+            Self::Fill => (5, 1, 2, 1),
+            Self::Smooth => (3, 1, 3, 1),
+            Self::Lift => (6, 0, 1, 1),
+            Self::FloatClean => (6, 1, 6, 1),
+        }
+    }
+}
+
+/// Erodes the blocks at `positions` into `air`, then fills the gaps among them with
+/// the most common of their solid neighbours, as set out by the `erosion` preset.
+/// Each pass sees the result of the previous one.
+///
+/// Returns the positions whose state changed, with their new states.
+pub fn erode<G, E>(
+    positions: &[BlockPos],
+    get: G,
+    is_empty: E,
+    air: u16,
+    erosion: Erosion,
+) -> Vec<(BlockPos, u16)>
+where
+    G: Fn(BlockPos) -> u16,
+    E: Fn(u16) -> bool,
+{
+    let (erosion_faces, erosion_passes, fill_faces, fill_passes) = erosion.settings();
+    let mut current: HashMap<BlockPos, u16> = HashMap::new();
+    let state = |current: &HashMap<BlockPos, u16>, pos: BlockPos| {
+        current.get(&pos).copied().unwrap_or_else(|| get(pos))
+    };
+
+    for _ in 0..erosion_passes {
+        let eroded: Vec<(BlockPos, u16)> = positions
+            .iter()
+            .filter(|pos| {
+                !is_empty(state(&current, **pos))
+                    && NEIGHBOURS
+                        .iter()
+                        .filter(|face| is_empty(state(&current, offset(**pos, **face))))
+                        .count()
+                        >= erosion_faces
+            })
+            .map(|pos| (*pos, air))
+            .collect();
+        current.extend(eroded);
+    }
+
+    for _ in 0..fill_passes {
+        let filled: Vec<(BlockPos, u16)> = positions
+            .iter()
+            .filter(|pos| is_empty(state(&current, **pos)))
+            .filter_map(|pos| {
+                let solid: Vec<u16> = NEIGHBOURS
+                    .iter()
+                    .map(|face| state(&current, offset(*pos, *face)))
+                    .filter(|neighbour| !is_empty(*neighbour))
+                    .collect();
+                if solid.len() < fill_faces {
+                    return None;
+                }
+                tally(solid)
+                    .first()
+                    .map(|(neighbour, _)| (*pos, *neighbour))
+            })
+            .collect();
+        current.extend(filled);
+    }
+
+    current
+        .into_iter()
+        .filter(|(pos, state)| get(*pos) != *state)
+        .collect()
+}
+
+/// Returns the offsets of the blocks around a block, either all 26 or only the 8 in
+/// its horizontal layer.
+#[must_use]
+pub fn neighbours(horizontal: bool) -> Vec<(i32, i32, i32)> {
+    let dys = if horizontal { 0..=0 } else { -1..=1 };
+    let mut offsets = Vec::new();
+    for dx in -1..=1 {
+        for dy in dys.clone() {
+            for dz in -1..=1 {
+                if (dx, dy, dz) != (0, 0, 0) {
+                    offsets.push((dx, dy, dz));
+                }
+            }
+        }
+    }
+    offsets
+}
+
+/// Sets every block at `positions` to the most common state among its `neighbours`,
+/// unless several states are equally common. Only blocks `blends` accepts are changed
+/// or counted, so air can be kept out of the blend.
+///
+/// Returns the positions whose state changed, with their new states.
+pub fn blend<G, B>(
+    positions: &[BlockPos],
+    get: G,
+    neighbours: &[(i32, i32, i32)],
+    blends: B,
+) -> Vec<(BlockPos, u16)>
+where
+    G: Fn(BlockPos) -> u16,
+    B: Fn(u16) -> bool,
+{
+    positions
+        .iter()
+        .filter(|pos| blends(get(**pos)))
+        .filter_map(|pos| {
+            let counts = tally(
+                neighbours
+                    .iter()
+                    .map(|neighbour| get(offset(*pos, *neighbour)))
+                    .filter(|state| blends(*state)),
+            );
+            match counts.as_slice() {
+                [(state, _)] => Some(*state),
+                [(state, count), (_, second), ..] if count > second => Some(*state),
+                _ => None,
+            }
+            .filter(|state| *state != get(*pos))
+            .map(|state| (*pos, state))
+        })
+        .collect()
+}
+
+/// Returns the height of the topmost non-empty block of the column at `x`, `z`
+/// between `bottom` and `top` with an empty block above it.
+pub fn surface<G, E>(x: i32, z: i32, bottom: i32, top: i32, get: G, is_empty: E) -> Option<i32>
+where
+    G: Fn(BlockPos) -> u16,
+    E: Fn(u16) -> bool,
+{
+    let at = |y: i32| get(BlockPos(Vector3::new(x, y, z)));
+    (bottom..=top)
+        .rev()
+        .find(|y| !is_empty(at(*y)) && is_empty(at(y + 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+    use super::{Erosion, blend, erode, neighbours, surface};
+
+    const AIR: u16 = 0;
+    const STONE: u16 = 1;
+    const DIRT: u16 = 2;
+
+    fn pos(x: i32, y: i32, z: i32) -> BlockPos {
+        BlockPos(Vector3::new(x, y, z))
+    }
+
+    /// Flat stone ground up to y = 0, with a single stone block sticking out at the
+    /// origin.
+    fn ground(pos: BlockPos) -> u16 {
+        if pos.0.y <= 0 || pos.0 == Vector3::new(0, 1, 0) {
+            STONE
+        } else {
+            AIR
+        }
+    }
+
+    fn cube(radius: i32) -> Vec<BlockPos> {
+        let mut positions = Vec::new();
+        for x in -radius..=radius {
+            for y in -radius..=radius {
+                for z in -radius..=radius {
+                    positions.push(pos(x, y + 1, z));
+                }
+            }
+        }
+        positions
+    }
+
+    #[test]
+    fn test_erode() {
+        let is_empty = |state: u16| state == AIR;
+        let smoothed = erode(&cube(2), ground, is_empty, AIR, Erosion::Smooth);
+        assert_eq!(smoothed, vec![(pos(0, 1, 0), AIR)]);
+
+        let lifted = erode(&cube(2), ground, is_empty, AIR, Erosion::Lift);
+        assert!(lifted.contains(&(pos(2, 1, 2), STONE)));
+        assert!(lifted.contains(&(pos(0, 2, 0), STONE)));
+        assert!(!lifted.iter().any(|(pos, _)| pos.0.y > 2));
+    }
+
+    #[test]
+    fn test_blend() {
+        let layer = |pos: BlockPos| match (pos.0.x, pos.0.y, pos.0.z) {
+            (0, 0, 0) => DIRT,
+            (_, 0, _) => STONE,
+            _ => AIR,
+        };
+        let center = [pos(0, 0, 0)];
+        assert_eq!(
+            blend(&center, layer, &neighbours(true), |_| true),
+            vec![(pos(0, 0, 0), STONE)]
+        );
+        // Air above and below outnumbers the stone around, unless left out.
+        assert_eq!(
+            blend(&center, layer, &neighbours(false), |_| true),
+            vec![(pos(0, 0, 0), AIR)]
+        );
+        assert_eq!(
+            blend(&center, layer, &neighbours(false), |state| state != AIR),
+            vec![(pos(0, 0, 0), STONE)]
+        );
+    }
+
+    #[test]
+    fn test_surface() {
+        let is_empty = |state: u16| state == AIR;
+        assert_eq!(surface(0, 0, -5, 5, ground, is_empty), Some(1));
+        assert_eq!(surface(3, 0, -5, 5, ground, is_empty), Some(0));
+        assert_eq!(surface(3, 0, 2, 5, ground, is_empty), None);
+    }
+}
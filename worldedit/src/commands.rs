@@ -1,5 +1,5 @@
 use pumpkin::plugin::Context;
-use pumpkin_util::permission::{Permission, PermissionDefault};
+use pumpkin_util::permission::{Permission, PermissionDefault, PermissionLvl};
 
 mod pos1;
 mod pos2;
@@ -8,6 +8,76 @@ mod select;
 mod replace;
 mod set;
 
+mod generate;
+mod generatebiome;
+
+mod center;
+mod faces;
+mod hollow;
+mod walls;
+
+mod curve;
+mod line;
+
+mod r#move;
+mod stack;
+
+mod naturalize;
+mod overlay;
+mod smooth;
+
+mod drain;
+mod fill;
+mod fillr;
+mod fixlava;
+mod fixwater;
+mod removenear;
+
+mod flora;
+mod forest;
+mod green;
+mod snow;
+mod thaw;
+
+mod biomeinfo;
+mod setbiome;
+
+mod butcher;
+mod copy;
+mod paste;
+mod remove;
+
+mod regen;
+
+mod restore;
+mod snapshot;
+
+mod brush;
+mod mask;
+mod material;
+mod range;
+mod size;
+
+mod none;
+mod tool;
+
+mod superpickaxe;
+
+mod ascend;
+mod ceil;
+mod descend;
+mod jumpto;
+mod thru;
+mod unstuck;
+mod up;
+
+mod count;
+mod gmask;
+
+mod chunk;
+mod delchunks;
+mod listchunks;
+
 pub async fn register_commmand(context: &Context) {
     context
         .register_command(pos1::init_command_tree(), "worldedit:selection.pos")
@@ -25,6 +95,191 @@ pub async fn register_commmand(context: &Context) {
     context
         .register_command(set::init_command_tree(), "worldedit:region.set")
         .await;
+
+    context
+        .register_command(generate::init_command_tree(), "worldedit:generation.shape")
+        .await;
+    context
+        .register_command(
+            generatebiome::init_command_tree(),
+            "worldedit:generation.shape.biome",
+        )
+        .await;
+
+    context
+        .register_command(walls::init_command_tree(), "worldedit:region.walls")
+        .await;
+    context
+        .register_command(faces::init_command_tree(), "worldedit:region.faces")
+        .await;
+    context
+        .register_command(center::init_command_tree(), "worldedit:region.center")
+        .await;
+    context
+        .register_command(hollow::init_command_tree(), "worldedit:region.hollow")
+        .await;
+
+    context
+        .register_command(line::init_command_tree(), "worldedit:region.line")
+        .await;
+    context
+        .register_command(curve::init_command_tree(), "worldedit:region.curve")
+        .await;
+
+    context
+        .register_command(r#move::init_command_tree(), "worldedit:region.move")
+        .await;
+    context
+        .register_command(stack::init_command_tree(), "worldedit:region.stack")
+        .await;
+
+    context
+        .register_command(
+            naturalize::init_command_tree(),
+            "worldedit:region.naturalize",
+        )
+        .await;
+    context
+        .register_command(overlay::init_command_tree(), "worldedit:region.overlay")
+        .await;
+    context
+        .register_command(smooth::init_command_tree(), "worldedit:region.smooth")
+        .await;
+
+    context
+        .register_command(drain::init_command_tree(), "worldedit:drain")
+        .await;
+    context
+        .register_command(fill::init_command_tree(), "worldedit:fill")
+        .await;
+    context
+        .register_command(fillr::init_command_tree(), "worldedit:fill.recursive")
+        .await;
+    context
+        .register_command(fixlava::init_command_tree(), "worldedit:fixlava")
+        .await;
+    context
+        .register_command(fixwater::init_command_tree(), "worldedit:fixwater")
+        .await;
+    context
+        .register_command(removenear::init_command_tree(), "worldedit:removenear")
+        .await;
+
+    context
+        .register_command(flora::init_command_tree(), "worldedit:region.flora")
+        .await;
+    context
+        .register_command(forest::init_command_tree(), "worldedit:region.forest")
+        .await;
+    context
+        .register_command(green::init_command_tree(), "worldedit:green")
+        .await;
+    context
+        .register_command(snow::init_command_tree(), "worldedit:snow")
+        .await;
+    context
+        .register_command(thaw::init_command_tree(), "worldedit:thaw")
+        .await;
+
+    context
+        .register_command(biomeinfo::init_command_tree(), "worldedit:biome.info")
+        .await;
+    context
+        .register_command(setbiome::init_command_tree(), "worldedit:biome.set")
+        .await;
+
+    context
+        .register_command(butcher::init_command_tree(), "worldedit:butcher")
+        .await;
+    context
+        .register_command(remove::init_command_tree(), "worldedit:remove")
+        .await;
+    context
+        .register_command(copy::init_command_tree(), "worldedit:clipboard.copy")
+        .await;
+    context
+        .register_command(paste::init_command_tree(), "worldedit:clipboard.paste")
+        .await;
+
+    context
+        .register_command(regen::init_command_tree(), "worldedit:regen")
+        .await;
+
+    context
+        .register_command(restore::init_command_tree(), "worldedit:snapshots.restore")
+        .await;
+    context
+        .register_command(snapshot::init_command_tree(), "worldedit:snapshots")
+        .await;
+
+    context
+        .register_command(brush::init_command_tree(), "worldedit:brush")
+        .await;
+    context
+        .register_command(mask::init_command_tree(), "worldedit:brush.options.mask")
+        .await;
+    context
+        .register_command(
+            material::init_command_tree(),
+            "worldedit:brush.options.material",
+        )
+        .await;
+    context
+        .register_command(range::init_command_tree(), "worldedit:brush.options.range")
+        .await;
+    context
+        .register_command(size::init_command_tree(), "worldedit:brush.options.size")
+        .await;
+
+    context
+        .register_command(none::init_command_tree(), "worldedit:tool.none")
+        .await;
+    context
+        .register_command(tool::init_command_tree(), "worldedit:tool")
+        .await;
+
+    context
+        .register_command(superpickaxe::init_command_tree(), "worldedit:superpickaxe")
+        .await;
+
+    context
+        .register_command(jumpto::init_command_tree(), "worldedit:navigation.jumpto")
+        .await;
+    context
+        .register_command(thru::init_command_tree(), "worldedit:navigation.thru")
+        .await;
+    context
+        .register_command(up::init_command_tree(), "worldedit:navigation.up")
+        .await;
+    context
+        .register_command(ascend::init_command_tree(), "worldedit:navigation.ascend")
+        .await;
+    context
+        .register_command(descend::init_command_tree(), "worldedit:navigation.descend")
+        .await;
+    context
+        .register_command(ceil::init_command_tree(), "worldedit:navigation.ceiling")
+        .await;
+    context
+        .register_command(unstuck::init_command_tree(), "worldedit:navigation.unstuck")
+        .await;
+
+    context
+        .register_command(count::init_command_tree(), "worldedit:analysis.count")
+        .await;
+    context
+        .register_command(gmask::init_command_tree(), "worldedit:global-mask")
+        .await;
+
+    context
+        .register_command(chunk::init_command_tree(), "worldedit:chunk")
+        .await;
+    context
+        .register_command(listchunks::init_command_tree(), "worldedit:listchunks")
+        .await;
+    context
+        .register_command(delchunks::init_command_tree(), "worldedit:delchunks")
+        .await;
 }
 
 pub async fn register_permission(context: &Context) {
@@ -53,4 +308,461 @@ pub async fn register_permission(context: &Context) {
         ))
         .await
         .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:generation.shape",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:generation.shape.biome",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.walls",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.faces",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.center",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.hollow",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.line",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.curve",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.move",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.stack",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.naturalize",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.overlay",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.smooth",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:drain",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:fill",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:fill.recursive",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:fixlava",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:fixwater",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:removenear",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.flora",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:region.forest",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:green",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:snow",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:thaw",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:biome.info",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:biome.set",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:butcher",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:remove",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:clipboard.copy",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:clipboard.paste",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:regen",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:snapshots.restore",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:snapshots",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:brush",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:brush.options.mask",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:brush.options.material",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:brush.options.range",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:brush.options.size",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:tool.none",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:tool",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:superpickaxe",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:superpickaxe.area",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:superpickaxe.recursive",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:navigation.jumpto",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:navigation.thru",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:navigation.up",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:navigation.ascend",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:navigation.descend",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:navigation.ceiling",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:navigation.unstuck",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:analysis.count",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:global-mask",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+
+    context
+        .register_permission(Permission::new(
+            "worldedit:chunk",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:listchunks",
+            "",
+            PermissionDefault::Allow,
+        ))
+        .await
+        .unwrap();
+    context
+        .register_permission(Permission::new(
+            "worldedit:delchunks",
+            "",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .await
+        .unwrap();
 }
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::navigation::{floor_above, player_column, teleport};
+
+const NAMES: [&str; 2] = ["ascend", "asc"];
+
+const DESCRIPTION: &str = "Go up a floor";
+
+const ARG_DESC_LEVELS: &str = "# of levels to ascend";
+
+struct AscendExecuter;
+
+#[async_trait]
+impl CommandExecutor for AscendExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let levels = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_LEVELS) {
+            Ok(Ok(levels)) => levels,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Levels must be 1 or greater.".to_string(),
+                ));
+            }
+            Err(_) => 1,
+        };
+
+        let (column, from) = player_column(player).await;
+        let mut current = from;
+        let mut moved = 0;
+        while moved < levels {
+            let Some(next) = floor_above(&column.solid, current) else {
+                break;
+            };
+            current = next;
+            moved += 1;
+        }
+        if moved == 0 {
+            return Err(CommandError::GeneralCommandIssue(
+                "No free spot above you found.".to_string(),
+            ));
+        }
+
+        teleport(player, column.pos(current)).await;
+
+        let message = if moved == 1 {
+            "Ascended a level.".to_string()
+        } else {
+            format!("Ascended {moved} levels.")
+        };
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            argument(
+                ARG_DESC_LEVELS,
+                BoundedNumArgumentConsumer::new()
+                    .min(1)
+                    .name(ARG_DESC_LEVELS),
+            )
+            .execute(AscendExecuter),
+        )
+        .execute(AscendExecuter)
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeSet;
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::literal;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::biome::biome_name;
+use crate::utils::region::read_biomes;
+use crate::utils::trace::target_block;
+
+const NAMES: [&str; 1] = ["/biomeinfo"];
+
+const DESCRIPTION: &str = "Get the biome of the targeted block";
+
+/// How far `-t` looks for a block.
+const TARGET_RANGE: f64 = 300.0;
+
+#[derive(Clone, Copy)]
+enum Source {
+    Selection,
+    /// `-p`: the block the player stands in.
+    Position,
+    /// `-t`: the block the player is looking at.
+    Target,
+}
+
+struct BiomeInfoExecuter(Source);
+
+#[async_trait]
+impl CommandExecutor for BiomeInfoExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let (pos1, pos2) = match self.0 {
+            Source::Selection => {
+                let (mut pos1, mut pos2) =
+                    crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+                crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+                (pos1, pos2)
+            }
+            Source::Position => {
+                let pos = BlockPos(player.position().to_i32());
+                (pos, pos)
+            }
+            Source::Target => {
+                let Some(pos) = target_block(player, TARGET_RANGE).await else {
+                    return Err(CommandError::GeneralCommandIssue(
+                        "No block in sight!".to_string(),
+                    ));
+                };
+                (pos, pos)
+            }
+        };
+
+        let world = player.world().await;
+
+        let mut biomes = BTreeSet::new();
+        read_biomes(&world, pos1, pos2, |_, biome_id| {
+            biomes.insert(biome_id);
+        })
+        .await;
+
+        let names: Vec<_> = biomes.into_iter().map(biome_name).collect();
+        let message = match self.0 {
+            Source::Selection => format!("Biomes: {}", names.join(", ")),
+            Source::Position => format!("Biome at your position: {}", names.join(", ")),
+            Source::Target => format!("Biome at {}: {}", pos1, names.join(", ")),
+        };
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(literal("-p").execute(BiomeInfoExecuter(Source::Position)))
+        .then(literal("-t").execute(BiomeInfoExecuter(Source::Target)))
+        .execute(BiomeInfoExecuter(Source::Selection))
+}
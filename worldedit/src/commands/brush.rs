@@ -0,0 +1,287 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::{NonLeafNodeBuilder, argument, literal};
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::tools::brush::Brush;
+use crate::tools::{MAX_SIZE, Tool, ToolKind, bind_tool};
+use crate::utils::flags::{Flags, literal_with_flags};
+use crate::utils::heightmap::MAX_ITERATIONS;
+use crate::utils::pattern::find_pattern;
+
+const NAMES: [&str; 2] = ["/brush", "/br"];
+
+const DESCRIPTION: &str = "Bind a brush to the item in your hand";
+
+const ARG_DESC_PATTERN: &str = "The pattern of blocks to set";
+pub(super) const ARG_DESC_RADIUS: &str = "The radius of the brush";
+const ARG_DESC_HEIGHT: &str = "The height of the cylinder";
+const ARG_DESC_ITERATIONS: &str = "# of iterations to perform";
+const ARG_DESC_DECAY: &str = "How quickly the splatter thins out towards the edge";
+
+/// `-h`: create hollow shapes instead.
+const SHAPE_FLAGS: [&str; 1] = ["-h"];
+/// `-h`: affect the whole column above the brush.
+const GRAVITY_FLAGS: [&str; 1] = ["-h"];
+/// `-a`: don't paste air from the clipboard.
+const CLIPBOARD_FLAGS: [&str; 1] = ["-a"];
+
+pub(super) fn radius_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(0)
+        .max(MAX_SIZE)
+        .name(ARG_DESC_RADIUS)
+}
+
+fn find_number(
+    args: &ConsumedArgs<'_>,
+    name: &str,
+    default: i32,
+    message: &str,
+) -> Result<i32, CommandError> {
+    match BoundedNumArgumentConsumer::<i32>::find_arg(args, name) {
+        Ok(Ok(number)) => Ok(number),
+        Ok(Err(_)) => Err(CommandError::GeneralCommandIssue(message.to_string())),
+        Err(_) => Ok(default),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum BrushType {
+    Sphere,
+    Cylinder,
+    Smooth,
+    Gravity,
+    Clipboard,
+    Butcher,
+    Extinguish,
+    Splatter,
+}
+
+struct BrushExecuter {
+    brush_type: BrushType,
+    flags: Option<Flags>,
+}
+
+impl BrushExecuter {
+    fn has(&self, flag: &str) -> bool {
+        self.flags.is_some_and(|flags| flags.has(flag))
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for BrushExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let default_radius = match self.brush_type {
+            BrushType::Butcher | BrushType::Extinguish => 5,
+            _ => 2,
+        };
+        let radius = find_number(
+            args,
+            ARG_DESC_RADIUS,
+            default_radius,
+            &format!("Radius must be between 0 and {MAX_SIZE}."),
+        )?;
+
+        let context = crate::pattern_context(player).await;
+        let (name, brush, material) = match self.brush_type {
+            BrushType::Sphere => (
+                "Sphere",
+                Brush::Sphere {
+                    hollow: self.has("-h"),
+                },
+                Some(find_pattern(args, ARG_DESC_PATTERN, &context)?),
+            ),
+            BrushType::Cylinder => (
+                "Cylinder",
+                Brush::Cylinder {
+                    height: find_number(args, ARG_DESC_HEIGHT, 1, "Height must be 1 or greater.")?,
+                    hollow: self.has("-h"),
+                },
+                Some(find_pattern(args, ARG_DESC_PATTERN, &context)?),
+            ),
+            BrushType::Smooth => (
+                "Smooth",
+                Brush::Smooth {
+                    iterations: find_number(
+                        args,
+                        ARG_DESC_ITERATIONS,
+                        4,
+                        &format!("Iterations must be between 1 and {MAX_ITERATIONS}."),
+                    )?,
+                },
+                None,
+            ),
+            BrushType::Gravity => (
+                "Gravity",
+                Brush::Gravity {
+                    full_height: self.has("-h"),
+                },
+                None,
+            ),
+            BrushType::Clipboard => {
+                // Fail early rather than on the first click.
+                crate::fetch_clipboard(&player.get_entity().entity_uuid).await?;
+                (
+                    "Clipboard",
+                    Brush::Clipboard {
+                        skip_air: self.has("-a"),
+                    },
+                    None,
+                )
+            }
+            BrushType::Butcher => ("Butcher", Brush::Butcher, None),
+            BrushType::Extinguish => ("Extinguisher", Brush::Extinguish, None),
+            BrushType::Splatter => (
+                "Splatter",
+                Brush::Splatter {
+                    decay: find_number(args, ARG_DESC_DECAY, 5, "Decay must be between 1 and 10.")?,
+                },
+                Some(find_pattern(args, ARG_DESC_PATTERN, &context)?),
+            ),
+        };
+
+        let item = bind_tool(player, Tool::new(ToolKind::Brush(brush), radius, material)).await?;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} brush bound to {} ({}).",
+                name, item.registry_key, radius
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+fn executer(brush_type: BrushType) -> BrushExecuter {
+    BrushExecuter {
+        brush_type,
+        flags: None,
+    }
+}
+
+fn flagged_executer(brush_type: BrushType) -> impl Fn(Flags) -> BrushExecuter {
+    move |flags| BrushExecuter {
+        brush_type,
+        flags: Some(flags),
+    }
+}
+
+/// `[radius]`, followed by `then` if given.
+fn radius_node(
+    brush_type: BrushType,
+    flags: Option<Flags>,
+    then: Option<NonLeafNodeBuilder>,
+) -> NonLeafNodeBuilder {
+    let mut node = argument(ARG_DESC_RADIUS, radius_consumer());
+    if let Some(then) = then {
+        node = node.then(then);
+    }
+    node.execute(BrushExecuter { brush_type, flags })
+}
+
+fn bounded(name: &'static str, min: i32, max: i32) -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(min)
+        .max(max)
+        .name(name)
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(literal_with_flags(
+            "sphere",
+            &SHAPE_FLAGS,
+            |flags| {
+                Some(
+                    argument(ARG_DESC_PATTERN, SimpleArgConsumer)
+                        .then(radius_node(BrushType::Sphere, Some(flags), None))
+                        .execute(flagged_executer(BrushType::Sphere)(flags)),
+                )
+            },
+            flagged_executer(BrushType::Sphere),
+        ))
+        .then(literal_with_flags(
+            "cylinder",
+            &SHAPE_FLAGS,
+            |flags| {
+                let height = argument(ARG_DESC_HEIGHT, bounded(ARG_DESC_HEIGHT, 1, 256))
+                    .execute(flagged_executer(BrushType::Cylinder)(flags));
+                Some(
+                    argument(ARG_DESC_PATTERN, SimpleArgConsumer)
+                        .then(radius_node(BrushType::Cylinder, Some(flags), Some(height)))
+                        .execute(flagged_executer(BrushType::Cylinder)(flags)),
+                )
+            },
+            flagged_executer(BrushType::Cylinder),
+        ))
+        .then(
+            literal("smooth")
+                .then(radius_node(
+                    BrushType::Smooth,
+                    None,
+                    Some(
+                        argument(
+                            ARG_DESC_ITERATIONS,
+                            bounded(ARG_DESC_ITERATIONS, 1, MAX_ITERATIONS),
+                        )
+                        .execute(executer(BrushType::Smooth)),
+                    ),
+                ))
+                .execute(executer(BrushType::Smooth)),
+        )
+        .then(literal_with_flags(
+            "gravity",
+            &GRAVITY_FLAGS,
+            |flags| Some(radius_node(BrushType::Gravity, Some(flags), None)),
+            flagged_executer(BrushType::Gravity),
+        ))
+        .then(literal_with_flags(
+            "clipboard",
+            &CLIPBOARD_FLAGS,
+            |_| None,
+            flagged_executer(BrushType::Clipboard),
+        ))
+        .then(
+            literal("butcher")
+                .then(radius_node(BrushType::Butcher, None, None))
+                .execute(executer(BrushType::Butcher)),
+        )
+        .then(
+            literal("extinguish")
+                .then(radius_node(BrushType::Extinguish, None, None))
+                .execute(executer(BrushType::Extinguish)),
+        )
+        .then(
+            literal("splatter").then(
+                argument(ARG_DESC_PATTERN, SimpleArgConsumer)
+                    .then(radius_node(
+                        BrushType::Splatter,
+                        None,
+                        Some(
+                            argument(ARG_DESC_DECAY, bounded(ARG_DESC_DECAY, 1, 10))
+                                .execute(executer(BrushType::Splatter)),
+                        ),
+                    ))
+                    .execute(executer(BrushType::Splatter)),
+            ),
+        )
+}
@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::entity::{EntityKind, entities_near, entity_name, remove_entity};
+use crate::utils::flags::{Flags, with_flags};
+
+const NAMES: [&str; 1] = ["/butcher"];
+
+const DESCRIPTION: &str = "Kill all or nearby mobs";
+
+const ARG_DESC_RADIUS: &str = "Radius to kill mobs in, -1 for all loaded mobs";
+
+/// `-p`: also kill pets.
+/// `-n`: also kill NPCs.
+/// `-g`: also kill golems.
+/// `-a`: also kill animals.
+/// `-b`: also kill ambient mobs.
+const FLAGS: [&str; 5] = ["-p", "-n", "-g", "-a", "-b"];
+
+pub(super) fn radius_consumer(name: &'static str) -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new().min(-1).name(name)
+}
+
+/// Reads a radius where -1 (the default) means unlimited.
+pub(super) fn find_radius(
+    args: &ConsumedArgs<'_>,
+    name: &str,
+) -> Result<Option<f64>, CommandError> {
+    match BoundedNumArgumentConsumer::<i32>::find_arg(args, name) {
+        Ok(Ok(-1)) | Err(_) => Ok(None),
+        Ok(Ok(radius)) => Ok(Some(f64::from(radius))),
+        Ok(Err(_)) => Err(CommandError::GeneralCommandIssue(
+            "Radius must be -1 or greater.".to_string(),
+        )),
+    }
+}
+
+struct ButcherExecuter {
+    flags: Flags,
+}
+
+impl ButcherExecuter {
+    fn kills(&self, kind: EntityKind) -> bool {
+        match kind {
+            EntityKind::Hostile => true,
+            EntityKind::Pet => self.flags.has("-p"),
+            EntityKind::Npc => self.flags.has("-n"),
+            EntityKind::Golem => self.flags.has("-g"),
+            EntityKind::Animal => self.flags.has("-a"),
+            EntityKind::Ambient => self.flags.has("-b"),
+            _ => false,
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for ButcherExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let radius = find_radius(args, ARG_DESC_RADIUS)?;
+
+        let world = player.world().await;
+
+        let mut cnt = 0;
+        for entity in entities_near(&world, player.position(), radius).await {
+            if self.kills(EntityKind::of(entity_name(entity.as_ref()))) {
+                remove_entity(entity.as_ref()).await;
+                cnt += 1;
+            }
+        }
+
+        sender
+            .send_message(TextComponent::text(format!("Killed {} mobs.", cnt)))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(
+        CommandTree::new(NAMES, DESCRIPTION),
+        &FLAGS,
+        |flags| {
+            argument(ARG_DESC_RADIUS, radius_consumer(ARG_DESC_RADIUS))
+                .execute(ButcherExecuter { flags })
+        },
+        |flags| ButcherExecuter { flags },
+    )
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::navigation::{ceiling_above, place_platform, player_column, teleport};
+
+const NAMES: [&str; 1] = ["ceil"];
+
+const DESCRIPTION: &str = "Go to the ceiling above you";
+
+const ARG_DESC_CLEARANCE: &str = "# of blocks to leave above your head";
+
+struct CeilExecuter;
+
+#[async_trait]
+impl CommandExecutor for CeilExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let clearance = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_CLEARANCE)
+        {
+            Ok(Ok(clearance)) => clearance as usize,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Clearance must be 0 or greater.".to_string(),
+                ));
+            }
+            Err(_) => 0,
+        };
+
+        let (column, from) = player_column(player).await;
+        // Leave room for the player's head and the clearance below the ceiling.
+        let Some(target) = ceiling_above(&column.solid, from)
+            .and_then(|ceiling| ceiling.checked_sub(2 + clearance))
+            .filter(|target| *target > from)
+        else {
+            return Err(CommandError::GeneralCommandIssue(
+                "No free spot above you found.".to_string(),
+            ));
+        };
+
+        let world = player.world().await;
+        place_platform(&world, column.pos(target - 1)).await;
+        teleport(player, column.pos(target)).await;
+
+        sender.send_message(TextComponent::text("Woosh!")).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            argument(
+                ARG_DESC_CLEARANCE,
+                BoundedNumArgumentConsumer::new()
+                    .min(0)
+                    .name(ARG_DESC_CLEARANCE),
+            )
+            .execute(CeilExecuter),
+        )
+        .execute(CeilExecuter)
+}
@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::mask::masked;
+use crate::utils::pattern::find_pattern;
+use crate::utils::region::edit_blocks;
+
+const NAMES: [&str; 2] = ["/center", "/middle"];
+
+const DESCRIPTION: &str = "Set the center block(s)";
+
+const ARG_DESC: &str = "The pattern of blocks to set";
+
+struct CenterExecuter;
+
+#[async_trait]
+impl CommandExecutor for CenterExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let pattern = find_pattern(args, ARG_DESC, &crate::pattern_context(player).await)?;
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+        let mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        // An axis with an even length has two center blocks.
+        let center_min = BlockPos(Vector3::new(
+            (pos1.0.x + pos2.0.x).div_euclid(2),
+            (pos1.0.y + pos2.0.y).div_euclid(2),
+            (pos1.0.z + pos2.0.z).div_euclid(2),
+        ));
+        let center_max = BlockPos(Vector3::new(
+            (pos1.0.x + pos2.0.x + 1).div_euclid(2),
+            (pos1.0.y + pos2.0.y + 1).div_euclid(2),
+            (pos1.0.z + pos2.0.z + 1).div_euclid(2),
+        ));
+
+        let cnt = edit_blocks(
+            &world,
+            center_min,
+            center_max,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                Some(pattern.apply(block_pos, block_id))
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, SimpleArgConsumer).execute(CenterExecuter))
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::chunked_range::chunk_span;
+use crate::utils::flags::{Flags, with_flags};
+use crate::utils::region::{max_y, min_y};
+use crate::{Selection, SelectionMode};
+
+const NAMES: [&str; 1] = ["/chunk"];
+
+const DESCRIPTION: &str = "Set the selection to your current chunk";
+
+const ARG_DESC_COORDINATES: &str = "The chunk to select, as x,z";
+
+/// `-s`: expand the selection to the chunks it touches.
+/// `-c`: the coordinates are chunk coordinates instead of block coordinates.
+const FLAGS: [&str; 2] = ["-s", "-c"];
+
+struct ChunkExecuter {
+    flags: Flags,
+}
+
+#[async_trait]
+impl CommandExecutor for ChunkExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+        let player_uuid = player.get_entity().entity_uuid;
+
+        let (mut pos1, mut pos2) = if self.flags.has("-s") {
+            crate::fetch_selections(&player_uuid).await?
+        } else {
+            let (x, z) = match args.get(ARG_DESC_COORDINATES) {
+                Some(Arg::Simple(coordinates)) => coordinates
+                    .split_once(',')
+                    .and_then(|(x, z)| Some((x.trim().parse().ok()?, z.trim().parse().ok()?)))
+                    .ok_or_else(|| {
+                        CommandError::GeneralCommandIssue(format!(
+                            "Invalid coordinates '{coordinates}', expected x,z."
+                        ))
+                    })?,
+                _ => {
+                    // Floored, as truncating puts -0.5 into chunk 0 instead of -1.
+                    let pos = player.position();
+                    (pos.x.floor() as i32, pos.z.floor() as i32)
+                }
+            };
+            let pos = if self.flags.has("-c") {
+                BlockPos(Vector3::new(x << 4, 0, z << 4))
+            } else {
+                BlockPos(Vector3::new(x, 0, z))
+            };
+            (pos, pos)
+        };
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let (Some(chunks_x), Some(chunks_z)) = (
+            chunk_span(pos1.0.x..=pos2.0.x),
+            chunk_span(pos1.0.z..=pos2.0.z),
+        ) else {
+            return Err(CommandError::GeneralCommandIssue(
+                "Coordinates out of range.".to_string(),
+            ));
+        };
+
+        let world = player.world().await;
+        let min = BlockPos(Vector3::new(
+            chunks_x.start() << 4,
+            min_y(&world),
+            chunks_z.start() << 4,
+        ));
+        let max = BlockPos(Vector3::new(
+            (chunks_x.end() << 4) + 15,
+            max_y(&world),
+            (chunks_z.end() << 4) + 15,
+        ));
+
+        {
+            let mut selections = crate::selections().write().await;
+            let mut selection = Selection::new(SelectionMode::Cuboid);
+            selection.set_pos1(min);
+            selection.set_pos2(max);
+            selections.insert(player_uuid, selection);
+        }
+
+        let message = if chunks_x.start() == chunks_x.end() && chunks_z.start() == chunks_z.end() {
+            format!("Chunk selected: {}, {}", chunks_x.start(), chunks_z.start())
+        } else {
+            format!(
+                "Chunks selected: ({}, {}) - ({}, {})",
+                chunks_x.start(),
+                chunks_z.start(),
+                chunks_x.end(),
+                chunks_z.end()
+            )
+        };
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(
+        CommandTree::new(NAMES, DESCRIPTION),
+        &FLAGS,
+        |flags| argument(ARG_DESC_COORDINATES, SimpleArgConsumer).execute(ChunkExecuter { flags }),
+        |flags| ChunkExecuter { flags },
+    )
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::literal;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::clipboard::Clipboard;
+
+const NAMES: [&str; 1] = ["/copy"];
+
+const DESCRIPTION: &str = "Copy the selection to the clipboard";
+
+struct CopyExecuter {
+    /// `-e`: also copy entities.
+    entities: bool,
+}
+
+#[async_trait]
+impl CommandExecutor for CopyExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let player_uuid = player.get_entity().entity_uuid;
+        let (mut pos1, mut pos2) = crate::fetch_selections(&player_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+
+        let origin = BlockPos(player.position().to_i32());
+        let clipboard = Clipboard::copy(&world, pos1, pos2, origin, self.entities).await;
+
+        let message = if self.entities {
+            format!(
+                "{} blocks and {} entities were copied.",
+                clipboard.block_count(),
+                clipboard.entity_count()
+            )
+        } else {
+            format!("{} blocks were copied.", clipboard.block_count())
+        };
+
+        crate::clipboards()
+            .write()
+            .await
+            .insert(player_uuid, Arc::new(clipboard));
+
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(literal("-e").execute(CopyExecuter { entities: true }))
+        .execute(CopyExecuter { entities: false })
+}
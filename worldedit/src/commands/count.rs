@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::mask::find_mask;
+use crate::utils::region::read_blocks;
+
+const NAMES: [&str; 1] = ["/count"];
+
+const DESCRIPTION: &str = "Count the blocks in the selection matching a mask";
+
+const ARG_DESC: &str = "The mask of blocks to count";
+
+struct CountExecuter;
+
+#[async_trait]
+impl CommandExecutor for CountExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let mut mask = find_mask(args, ARG_DESC)?;
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+        crate::prepare_mask(player, &mut mask, pos1, pos2).await;
+
+        let buffer = read_blocks(&world, pos1, pos2).await;
+        let mut cnt = 0;
+        for x in pos1.0.x..=pos2.0.x {
+            for y in pos1.0.y..=pos2.0.y {
+                for z in pos1.0.z..=pos2.0.z {
+                    let block_pos = BlockPos(Vector3::new(x, y, z));
+                    if buffer
+                        .get(block_pos)
+                        .is_some_and(|block_id| mask.test(block_pos, block_id))
+                    {
+                        cnt += 1;
+                    }
+                }
+            }
+        }
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks match the mask.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, SimpleArgConsumer).execute(CountExecuter))
+}
@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::{argument, literal};
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::SelectionMode;
+use crate::utils::draw::{MAX_THICKNESS, hollow, thicken};
+use crate::utils::mask::masked;
+use crate::utils::pattern::find_pattern;
+use crate::utils::region::{bounds, edit_positions};
+use crate::utils::spline::KochanekBartels;
+
+const NAMES: [&str; 1] = ["/curve"];
+
+const DESCRIPTION: &str = "Draws a spline through selected points";
+
+const ARG_DESC_PATTERN: &str = "The pattern of blocks to place";
+const ARG_DESC_THICKNESS: &str = "The thickness of the curve";
+
+fn thickness_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(0)
+        .max(MAX_THICKNESS)
+        .name(ARG_DESC_THICKNESS)
+}
+
+struct CurveExecuter {
+    hollow: bool,
+}
+
+#[async_trait]
+impl CommandExecutor for CurveExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let pattern = find_pattern(
+            args,
+            ARG_DESC_PATTERN,
+            &crate::pattern_context(player).await,
+        )?;
+        let thickness = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_THICKNESS)
+        {
+            Ok(Ok(thickness)) => thickness,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(format!(
+                    "Thickness must be between 0 and {MAX_THICKNESS}."
+                )));
+            }
+            Err(_) => 0,
+        };
+
+        let player_uuid = player.get_entity().entity_uuid;
+        let vertices = {
+            let selections = crate::selections().read().await;
+            match selections.get(&player_uuid) {
+                Some(selection) if selection.mode() == SelectionMode::Convex => {
+                    selection.vertices().to_vec()
+                }
+                _ => {
+                    return Err(CommandError::GeneralCommandIssue(
+                        "//curve only works with convex polyhedral selections".to_string(),
+                    ));
+                }
+            }
+        };
+        if vertices.is_empty() {
+            return Err(CommandError::GeneralCommandIssue(
+                "Make a region selection first.".to_string(),
+            ));
+        }
+
+        // Run the spline through the block centers.
+        let nodes = vertices
+            .iter()
+            .map(|vertex| {
+                Vector3::new(
+                    f64::from(vertex.0.x) + 0.5,
+                    f64::from(vertex.0.y) + 0.5,
+                    f64::from(vertex.0.z) + 0.5,
+                )
+            })
+            .collect();
+        let spline = KochanekBartels::new(nodes, 0.0, 0.0, 0.0);
+
+        let mut shape = thicken(&spline.rasterize(), thickness);
+        if self.hollow {
+            shape = hollow(&shape);
+        }
+
+        let world = player.world().await;
+
+        let mask = crate::global_mask(player, bounds(&shape)).await;
+        let cnt = edit_positions(
+            &world,
+            shape,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                Some(pattern.apply(block_pos, block_id))
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(ARG_DESC_PATTERN, SimpleArgConsumer)
+            .then(literal("-h").execute(CurveExecuter { hollow: true }))
+            .then(
+                argument(ARG_DESC_THICKNESS, thickness_consumer())
+                    .then(literal("-h").execute(CurveExecuter { hollow: true }))
+                    .execute(CurveExecuter { hollow: false }),
+            )
+            .execute(CurveExecuter { hollow: false }),
+    )
+}
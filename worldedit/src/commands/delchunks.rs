@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::chunked_range::{chunk_span, region_span};
+
+const NAMES: [&str; 1] = ["/delchunks"];
+
+const DESCRIPTION: &str = "List the region files and chunks to delete to regenerate the selection";
+
+/// Where the list is written, relative to the server directory, as it can be far too
+/// long for the chat.
+const FILE_NAME: &str = "delete_chunks.txt";
+
+struct DelChunksExecuter;
+
+#[async_trait]
+impl CommandExecutor for DelChunksExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let (Some(chunks_x), Some(chunks_z)) = (
+            chunk_span(pos1.0.x..=pos2.0.x),
+            chunk_span(pos1.0.z..=pos2.0.z),
+        ) else {
+            return Ok(());
+        };
+
+        // Regions the selection covers entirely can go as a whole; of the others, only
+        // the selected chunks have to be removed from the region file.
+        let mut files = Vec::new();
+        let mut chunks = Vec::new();
+        for (region_x, full_x) in region_span(&chunks_x) {
+            for (region_z, full_z) in region_span(&chunks_z) {
+                if full_x && full_z {
+                    files.push(format!("r.{region_x}.{region_z}.mca"));
+                    continue;
+                }
+                let xs = (*chunks_x.start()).max(region_x << 5)
+                    ..=(*chunks_x.end()).min((region_x << 5) + 31);
+                let zs = (*chunks_z.start()).max(region_z << 5)
+                    ..=(*chunks_z.end()).min((region_z << 5) + 31);
+                for x in xs {
+                    for z in zs.clone() {
+                        chunks.push(format!("[{x}, {z}] in r.{region_x}.{region_z}.mca"));
+                    }
+                }
+            }
+        }
+
+        let mut content = String::from(
+            "# Delete these with the server stopped; they generate anew when next loaded.\n",
+        );
+        content.push_str("# Region files to delete:\n");
+        for file in &files {
+            content.push_str(file);
+            content.push('\n');
+        }
+        content.push_str("# Chunks to delete from partially selected regions:\n");
+        for chunk in &chunks {
+            content.push_str(chunk);
+            content.push('\n');
+        }
+        tokio::fs::write(FILE_NAME, content).await.map_err(|err| {
+            CommandError::GeneralCommandIssue(format!("Failed to write {FILE_NAME}: {err}"))
+        })?;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Wrote {} region files and {} chunks to delete to {FILE_NAME}.",
+                files.len(),
+                chunks.len()
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).execute(DelChunksExecuter)
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_data::Block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::{block_of, property, with_property};
+use crate::utils::flags::{Flags, with_flags};
+use crate::utils::flood::{flood_fill, read_around, within_radius};
+use crate::utils::mask::masked;
+use crate::utils::region::{NEIGHBOURS, bounds, edit_positions};
+
+const NAMES: [&str; 1] = ["/drain"];
+
+const DESCRIPTION: &str = "Drain a pool";
+
+const ARG_DESC_RADIUS: &str = "The radius to drain";
+
+/// `-w`: also un-waterlog blocks.
+const FLAGS: [&str; 1] = ["-w"];
+
+fn radius_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .name(ARG_DESC_RADIUS)
+}
+
+fn is_liquid(block_id: u16) -> bool {
+    let block = block_of(block_id);
+    block.id == Block::WATER.id || block.id == Block::LAVA.id || block.id == Block::BUBBLE_COLUMN.id
+}
+
+fn is_waterlogged(block_id: u16) -> bool {
+    property(block_id, "waterlogged").is_some_and(|value| value == "true")
+}
+
+struct DrainExecuter {
+    flags: Flags,
+}
+
+#[async_trait]
+impl CommandExecutor for DrainExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let radius = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_RADIUS)? {
+            Ok(radius) => radius,
+            Err(_) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Radius must be 1 or greater.".to_string(),
+                ));
+            }
+        };
+        let waterlogged = self.flags.has("-w");
+
+        let origin = BlockPos(player.position().to_i32());
+
+        let world = player.world().await;
+
+        let buffer = read_around(&world, origin, radius).await;
+        let drainable =
+            |block_id: u16| is_liquid(block_id) || waterlogged && is_waterlogged(block_id);
+
+        // Start from the blocks around the player as well, so standing just above or
+        // next to the liquid works.
+        let mut seeds = vec![origin];
+        seeds.extend(
+            NEIGHBOURS
+                .iter()
+                .map(|(dx, dy, dz)| BlockPos(origin.0 + Vector3::new(*dx, *dy, *dz))),
+        );
+        let positions = flood_fill(
+            seeds,
+            |_| &NEIGHBOURS,
+            |pos| within_radius(origin, pos, radius) && buffer.get(pos).is_some_and(drainable),
+        )
+        .map_err(CommandError::GeneralCommandIssue)?;
+
+        let mask = crate::global_mask(player, bounds(&positions)).await;
+        let cnt = edit_positions(
+            &world,
+            positions,
+            masked(mask.as_ref(), |_, block_id| {
+                if is_liquid(block_id) {
+                    Some(Block::AIR.default_state.id)
+                } else {
+                    with_property(block_id, "waterlogged", "false")
+                }
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(
+        CommandTree::new(NAMES, DESCRIPTION),
+        &FLAGS,
+        |flags| argument(ARG_DESC_RADIUS, radius_consumer()).execute(DrainExecuter { flags }),
+        |flags| DrainExecuter { flags },
+    )
+}
@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::mask::masked;
+use crate::utils::pattern::find_pattern;
+use crate::utils::region::edit_blocks;
+
+const NAMES: [&str; 2] = ["/faces", "/outline"];
+
+const DESCRIPTION: &str = "Build the walls, ceiling, and floor of a selection";
+
+const ARG_DESC: &str = "The pattern of blocks to set";
+
+struct FacesExecuter;
+
+#[async_trait]
+impl CommandExecutor for FacesExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let pattern = find_pattern(args, ARG_DESC, &crate::pattern_context(player).await)?;
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+        let mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        let cnt = edit_blocks(
+            &world,
+            pos1,
+            pos2,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                let (x, y, z) = (block_pos.0.x, block_pos.0.y, block_pos.0.z);
+                let is_face = x == pos1.0.x
+                    || x == pos2.0.x
+                    || y == pos1.0.y
+                    || y == pos2.0.y
+                    || z == pos1.0.z
+                    || z == pos2.0.z;
+                is_face.then(|| pattern.apply(block_pos, block_id))
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, SimpleArgConsumer).execute(FacesExecuter))
+}
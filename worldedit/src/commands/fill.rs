@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::{NonLeafNodeBuilder, argument};
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::is_air;
+use crate::utils::flood::{DOWN, HORIZONTAL_AND_DOWN, flood_fill, read_around, within_radius};
+use crate::utils::mask::masked;
+use crate::utils::pattern::find_pattern;
+use crate::utils::region::{bounds, edit_positions};
+
+const NAMES: [&str; 1] = ["/fill"];
+
+const DESCRIPTION: &str = "Fill a hole";
+
+const ARG_DESC_PATTERN: &str = "The blocks to fill with";
+const ARG_DESC_RADIUS: &str = "The radius to fill in";
+const ARG_DESC_DEPTH: &str = "The depth to fill";
+
+/// Fills the air below the player's level, within a radius.
+///
+/// A plain fill only spreads sideways on the player's level and goes straight down
+/// from there, so overhangs are left alone. A recursive fill spreads sideways on
+/// every level.
+struct FillExecuter {
+    recursive: bool,
+}
+
+#[async_trait]
+impl CommandExecutor for FillExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let pattern = find_pattern(
+            args,
+            ARG_DESC_PATTERN,
+            &crate::pattern_context(player).await,
+        )?;
+        let radius = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_RADIUS)? {
+            Ok(radius) => radius,
+            Err(_) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Radius must be 1 or greater.".to_string(),
+                ));
+            }
+        };
+        let depth = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_DEPTH) {
+            Ok(Ok(depth)) => depth,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Depth must be 1 or greater.".to_string(),
+                ));
+            }
+            Err(_) if self.recursive => i32::MAX,
+            Err(_) => 1,
+        };
+
+        let origin = BlockPos(player.position().to_i32());
+        let lowest = origin.0.y.saturating_sub(depth - 1);
+
+        let world = player.world().await;
+
+        let buffer = read_around(&world, origin, radius).await;
+        let recursive = self.recursive;
+        let positions = flood_fill(
+            [origin],
+            |pos| -> &'static [(i32, i32, i32)] {
+                if recursive || pos.0.y == origin.0.y {
+                    &HORIZONTAL_AND_DOWN
+                } else {
+                    &DOWN
+                }
+            },
+            |pos| {
+                (lowest..=origin.0.y).contains(&pos.0.y)
+                    && within_radius(origin, pos, radius)
+                    && buffer.get(pos).is_some_and(is_air)
+            },
+        )
+        .map_err(CommandError::GeneralCommandIssue)?;
+
+        let mask = crate::global_mask(player, bounds(&positions)).await;
+        let cnt = edit_positions(
+            &world,
+            positions,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                Some(pattern.apply(block_pos, block_id))
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been created.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+fn depth_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .name(ARG_DESC_DEPTH)
+}
+
+fn radius_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .name(ARG_DESC_RADIUS)
+}
+
+/// The `<pattern> <radius> [depth]` arguments shared with `//fillr`.
+pub(super) fn arguments(recursive: bool) -> NonLeafNodeBuilder {
+    argument(ARG_DESC_PATTERN, SimpleArgConsumer).then(
+        argument(ARG_DESC_RADIUS, radius_consumer())
+            .then(argument(ARG_DESC_DEPTH, depth_consumer()).execute(FillExecuter { recursive }))
+            .execute(FillExecuter { recursive }),
+    )
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(arguments(false))
+}
@@ -0,0 +1,11 @@
+use pumpkin::command::tree::CommandTree;
+
+use super::fill::arguments;
+
+const NAMES: [&str; 1] = ["/fillr"];
+
+const DESCRIPTION: &str = "Fill a hole recursively";
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(arguments(true))
+}
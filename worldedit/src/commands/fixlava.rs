@@ -0,0 +1,14 @@
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin_data::Block;
+
+use super::fixwater::{ARG_DESC_RADIUS, FixLiquidExecuter, radius_consumer};
+
+const NAMES: [&str; 1] = ["/fixlava"];
+
+const DESCRIPTION: &str = "Fix lava to be stationary";
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC_RADIUS, radius_consumer()).execute(FixLiquidExecuter(&Block::LAVA)))
+}
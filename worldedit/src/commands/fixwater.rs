@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_data::Block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::{block_of, is_air};
+use crate::utils::flood::{flood_fill, read_around, within_radius};
+use crate::utils::mask::masked;
+use crate::utils::region::{NEIGHBOURS, bounds, edit_positions};
+
+const NAMES: [&str; 1] = ["/fixwater"];
+
+const DESCRIPTION: &str = "Fix water to be stationary";
+
+pub(super) const ARG_DESC_RADIUS: &str = "The radius to fix in";
+
+pub(super) fn radius_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .name(ARG_DESC_RADIUS)
+}
+
+/// Turns all of `fluid` and the air connected to it, at or below the player's level,
+/// into still source blocks.
+pub(super) struct FixLiquidExecuter(pub(super) &'static Block);
+
+#[async_trait]
+impl CommandExecutor for FixLiquidExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let radius = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_RADIUS)? {
+            Ok(radius) => radius,
+            Err(_) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Radius must be 1 or greater.".to_string(),
+                ));
+            }
+        };
+        let fluid = self.0;
+
+        let origin = BlockPos(player.position().to_i32());
+
+        let world = player.world().await;
+
+        let buffer = read_around(&world, origin, radius).await;
+        let positions = flood_fill(
+            [origin],
+            |_| &NEIGHBOURS,
+            |pos| {
+                pos.0.y <= origin.0.y
+                    && within_radius(origin, pos, radius)
+                    && buffer.get(pos).is_some_and(|block_id| {
+                        is_air(block_id) || block_of(block_id).id == fluid.id
+                    })
+            },
+        )
+        .map_err(CommandError::GeneralCommandIssue)?;
+
+        let mask = crate::global_mask(player, bounds(&positions)).await;
+        let cnt = edit_positions(
+            &world,
+            positions,
+            masked(mask.as_ref(), |_, _| Some(fluid.default_state.id)),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(ARG_DESC_RADIUS, radius_consumer()).execute(FixLiquidExecuter(&Block::WATER)),
+    )
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_data::Block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::{block_of, is_air};
+use crate::utils::heightmap::read_heightmap;
+use crate::utils::mask::masked;
+use crate::utils::random::Random;
+use crate::utils::region::{bounds, edit_positions};
+
+const NAMES: [&str; 1] = ["/flora"];
+
+const DESCRIPTION: &str = "Make flora within the region";
+
+const ARG_DESC_DENSITY: &str = "The density of the flora, between 0 and 100";
+
+const FLOWERS: [&Block; 8] = [
+    &Block::DANDELION,
+    &Block::POPPY,
+    &Block::BLUE_ORCHID,
+    &Block::ALLIUM,
+    &Block::AZURE_BLUET,
+    &Block::OXEYE_DAISY,
+    &Block::CORNFLOWER,
+    &Block::LILY_OF_THE_VALLEY,
+];
+
+fn density_consumer() -> BoundedNumArgumentConsumer<f64> {
+    BoundedNumArgumentConsumer::new()
+        .min(0.0)
+        .max(100.0)
+        .name(ARG_DESC_DENSITY)
+}
+
+/// Picks a plant for the given ground block: mostly grass with some flowers on grass,
+/// dead bushes and the odd cactus on sand.
+fn plant_for(ground: u16, random: &mut Random) -> Option<&'static Block> {
+    let ground = block_of(ground);
+    if ground.id == Block::GRASS_BLOCK.id {
+        if random.next_below(10) == 0 {
+            Some(FLOWERS[random.next_below(FLOWERS.len() as u64) as usize])
+        } else {
+            Some(&Block::SHORT_GRASS)
+        }
+    } else if ground.id == Block::SAND.id || ground.id == Block::RED_SAND.id {
+        if random.next_below(8) == 0 {
+            Some(&Block::CACTUS)
+        } else {
+            Some(&Block::DEAD_BUSH)
+        }
+    } else {
+        None
+    }
+}
+
+struct FloraExecuter;
+
+#[async_trait]
+impl CommandExecutor for FloraExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let density = match BoundedNumArgumentConsumer::<f64>::find_arg(args, ARG_DESC_DENSITY) {
+            Ok(Ok(density)) => density,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Density must be between 0 and 100.".to_string(),
+                ));
+            }
+            Err(_) => 5.0,
+        } / 100.0;
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+
+        let heightmap = read_heightmap(&world, pos1, pos2, |_, block_id| !is_air(block_id)).await;
+
+        let mut random = Random::new();
+        let mut plants = HashMap::new();
+        for (x, z, height) in heightmap.columns() {
+            let (Some(height), Some(ground)) = (height, heightmap.state(x, z)) else {
+                continue;
+            };
+            if height >= pos2.0.y || random.next_f64() >= density {
+                continue;
+            }
+            if let Some(plant) = plant_for(ground, &mut random) {
+                plants.insert(BlockPos(Vector3::new(x, height + 1, z)), plant);
+            }
+        }
+
+        let mask = crate::global_mask(player, bounds(plants.keys())).await;
+        let cnt = edit_positions(
+            &world,
+            plants.keys().copied().collect::<Vec<_>>(),
+            masked(mask.as_ref(), |pos, block_id| {
+                let plant = plants.get(&pos)?;
+                is_air(block_id).then_some(plant.default_state.id)
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!("{} flora created.", cnt)))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC_DENSITY, density_consumer()).execute(FloraExecuter))
+        .execute(FloraExecuter)
+}
@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::is_air;
+use crate::utils::heightmap::read_heightmap;
+use crate::utils::random::Random;
+use crate::utils::region::bounds;
+use crate::utils::tree::{TreeBlocks, TreeType, add_tree, is_soil, place_trees};
+
+const NAMES: [&str; 1] = ["/forest"];
+
+const DESCRIPTION: &str = "Make a forest within the region";
+
+const ARG_DESC_TYPE: &str = "The type of tree to place";
+const ARG_DESC_DENSITY: &str = "The density of the forest, between 0 and 100";
+
+fn density_consumer() -> BoundedNumArgumentConsumer<f64> {
+    BoundedNumArgumentConsumer::new()
+        .min(0.0)
+        .max(100.0)
+        .name(ARG_DESC_DENSITY)
+}
+
+struct ForestExecuter;
+
+#[async_trait]
+impl CommandExecutor for ForestExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let tree_type = match args.get(ARG_DESC_TYPE) {
+            Some(Arg::Simple(tree_type)) => {
+                TreeType::parse(tree_type).map_err(CommandError::GeneralCommandIssue)?
+            }
+            _ => Some(TreeType::Oak),
+        };
+        let density = match BoundedNumArgumentConsumer::<f64>::find_arg(args, ARG_DESC_DENSITY) {
+            Ok(Ok(density)) => density,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Density must be between 0 and 100.".to_string(),
+                ));
+            }
+            Err(_) => 5.0,
+        } / 100.0;
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+
+        let heightmap = read_heightmap(&world, pos1, pos2, |_, block_id| !is_air(block_id)).await;
+
+        let mut random = Random::new();
+        let mut blocks = TreeBlocks::new();
+        let mut trees = 0;
+        for (x, z, height) in heightmap.columns() {
+            let Some(height) = height else {
+                continue;
+            };
+            if height >= pos2.0.y
+                || !heightmap.state(x, z).is_some_and(is_soil)
+                || random.next_f64() >= density
+            {
+                continue;
+            }
+            let tree_type = tree_type.unwrap_or_else(|| {
+                TreeType::ALL[random.next_below(TreeType::ALL.len() as u64) as usize]
+            });
+            let base = BlockPos(Vector3::new(x, height + 1, z));
+            add_tree(&mut blocks, tree_type, base, &mut random);
+            trees += 1;
+        }
+
+        let mask = crate::global_mask(player, bounds(blocks.keys())).await;
+        place_trees(&world, &blocks, mask.as_ref()).await;
+
+        sender
+            .send_message(TextComponent::text(format!("{} trees created.", trees)))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            argument(ARG_DESC_TYPE, SimpleArgConsumer)
+                .then(argument(ARG_DESC_DENSITY, density_consumer()).execute(ForestExecuter))
+                .execute(ForestExecuter),
+        )
+        .execute(ForestExecuter)
+}
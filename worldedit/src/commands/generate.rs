@@ -0,0 +1,283 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::message::MsgArgConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::{NonLeafNodeBuilder, argument, literal};
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::expression::Expression;
+use crate::utils::mask::masked;
+use crate::utils::pattern::find_pattern;
+use crate::utils::region::{NEIGHBOURS, edit_blocks};
+
+const NAMES: [&str; 3] = ["/generate", "/gen", "/g"];
+
+const DESCRIPTION: &str = "Generates a shape according to a formula";
+
+const ARG_DESC_PATTERN: &str = "The pattern of blocks to set";
+const ARG_DESC_EXPRESSION: &str = "Expression to test block placement locations and set block type";
+
+/// The most blocks a shape is evaluated for, as every one runs the expression.
+const MAX_VOLUME: usize = 1 << 22;
+
+/// How block coordinates are mapped to the `x`, `y` and `z` expression variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum CoordinateMode {
+    /// The selection is scaled to `-1..=1` on every axis.
+    Normalized,
+    /// `-r`: world coordinates.
+    Raw,
+    /// `-o`: unscaled, relative to the player's position.
+    Offset,
+    /// `-c`: unscaled, relative to the center of the selection.
+    Center,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct GenerateFlags {
+    pub hollow: bool,
+    pub mode: CoordinateMode,
+}
+
+/// Adds `arguments` to `tree` once for every combination of `-h` and `-r|-o|-c`.
+///
+/// The flag literals are added before the bare arguments so a pattern is never
+/// mistaken for a flag.
+pub(super) fn with_flags<F>(tree: CommandTree, arguments: F) -> CommandTree
+where
+    F: Fn(GenerateFlags) -> NonLeafNodeBuilder,
+{
+    let flags = |hollow, mode| GenerateFlags { hollow, mode };
+    let modes = [
+        ("-r", CoordinateMode::Raw),
+        ("-o", CoordinateMode::Offset),
+        ("-c", CoordinateMode::Center),
+    ];
+
+    let mut tree = tree;
+    let mut hollow = literal("-h");
+    for (flag, mode) in modes {
+        hollow = hollow.then(literal(flag).then(arguments(flags(true, mode))));
+        tree = tree.then(
+            literal(flag)
+                .then(literal("-h").then(arguments(flags(true, mode))))
+                .then(arguments(flags(false, mode))),
+        );
+    }
+    tree.then(hollow.then(arguments(flags(true, CoordinateMode::Normalized))))
+        .then(arguments(flags(false, CoordinateMode::Normalized)))
+}
+
+/// The set of blocks of the selection for which an expression holds.
+pub(super) struct Shape {
+    min: Vector3<i32>,
+    size: Vector3<i32>,
+    inside: Vec<bool>,
+}
+
+impl Shape {
+    /// Evaluates `source` for every block between `pos1` and `pos2` (normalized), off
+    /// the async runtime. With `hollow`, only blocks with at least one neighbour
+    /// outside the shape are kept.
+    pub async fn evaluate(
+        source: &str,
+        flags: GenerateFlags,
+        pos1: BlockPos,
+        pos2: BlockPos,
+        player_pos: Vector3<f64>,
+    ) -> Result<Self, CommandError> {
+        let (min, max) = (pos1.0, pos2.0);
+        let volume = [max.x - min.x, max.y - min.y, max.z - min.z]
+            .into_iter()
+            .try_fold(1usize, |volume, len| volume.checked_mul(len as usize + 1))
+            .filter(|volume| *volume <= MAX_VOLUME)
+            .ok_or_else(|| {
+                CommandError::GeneralCommandIssue(format!(
+                    "The selection is too large, at most {MAX_VOLUME} blocks can be generated."
+                ))
+            })?;
+
+        let source = source.to_string();
+        tokio::task::spawn_blocking(move || {
+            Self::evaluate_blocking(&source, flags, pos1, pos2, player_pos, volume)
+        })
+        .await
+        .map_err(|err| CommandError::GeneralCommandIssue(err.to_string()))?
+    }
+
+    fn evaluate_blocking(
+        source: &str,
+        flags: GenerateFlags,
+        pos1: BlockPos,
+        pos2: BlockPos,
+        player_pos: Vector3<f64>,
+        volume: usize,
+    ) -> Result<Self, CommandError> {
+        let mut expression = Expression::compile(source, &["x", "y", "z"])
+            .map_err(|err| CommandError::GeneralCommandIssue(err.to_string()))?;
+
+        let (min, max) = (pos1.0, pos2.0);
+        let center = Vector3::new(
+            f64::from(min.x + max.x) / 2.0,
+            f64::from(min.y + max.y) / 2.0,
+            f64::from(min.z + max.z) / 2.0,
+        );
+        let (origin, scale) = match flags.mode {
+            CoordinateMode::Normalized => {
+                let unit = |max: i32, center: f64| {
+                    let unit = f64::from(max) - center;
+                    if unit == 0.0 { 1.0 } else { unit }
+                };
+                (
+                    center,
+                    Vector3::new(
+                        unit(max.x, center.x),
+                        unit(max.y, center.y),
+                        unit(max.z, center.z),
+                    ),
+                )
+            }
+            CoordinateMode::Raw => (Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)),
+            CoordinateMode::Offset => (
+                Vector3::new(
+                    player_pos.x.floor(),
+                    player_pos.y.floor(),
+                    player_pos.z.floor(),
+                ),
+                Vector3::new(1.0, 1.0, 1.0),
+            ),
+            CoordinateMode::Center => (center, Vector3::new(1.0, 1.0, 1.0)),
+        };
+
+        let size = Vector3::new(max.x - min.x + 1, max.y - min.y + 1, max.z - min.z + 1);
+        let mut inside = Vec::with_capacity(volume);
+        for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                for z in min.z..=max.z {
+                    let value = expression
+                        .evaluate(&[
+                            (f64::from(x) - origin.x) / scale.x,
+                            (f64::from(y) - origin.y) / scale.y,
+                            (f64::from(z) - origin.z) / scale.z,
+                        ])
+                        .map_err(|err| CommandError::GeneralCommandIssue(err.to_string()))?;
+                    inside.push(value > 0.0);
+                }
+            }
+        }
+
+        let mut shape = Self { min, size, inside };
+        if flags.hollow {
+            shape.hollow();
+        }
+        Ok(shape)
+    }
+
+    fn index(&self, pos: Vector3<i32>) -> Option<usize> {
+        let (x, y, z) = (pos.x - self.min.x, pos.y - self.min.y, pos.z - self.min.z);
+        if x < 0 || y < 0 || z < 0 || x >= self.size.x || y >= self.size.y || z >= self.size.z {
+            return None;
+        }
+        let (x, y, z) = (x as usize, y as usize, z as usize);
+        Some((x * self.size.y as usize + y) * self.size.z as usize + z)
+    }
+
+    #[must_use]
+    pub fn contains(&self, pos: BlockPos) -> bool {
+        self.index(pos.0).is_some_and(|index| self.inside[index])
+    }
+
+    fn hollow(&mut self) {
+        let mut surface = vec![false; self.inside.len()];
+        for x in 0..self.size.x {
+            for y in 0..self.size.y {
+                for z in 0..self.size.z {
+                    let pos = Vector3::new(self.min.x + x, self.min.y + y, self.min.z + z);
+                    let Some(index) = self.index(pos).filter(|index| self.inside[*index]) else {
+                        continue;
+                    };
+                    surface[index] = NEIGHBOURS.iter().any(|(dx, dy, dz)| {
+                        !self.contains(BlockPos(Vector3::new(pos.x + dx, pos.y + dy, pos.z + dz)))
+                    });
+                }
+            }
+        }
+        self.inside = surface;
+    }
+}
+
+struct GenerateExecuter {
+    flags: GenerateFlags,
+}
+
+#[async_trait]
+impl CommandExecutor for GenerateExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let pattern = find_pattern(
+            args,
+            ARG_DESC_PATTERN,
+            &crate::pattern_context(player).await,
+        )?;
+        let Some(Arg::Msg(expression)) = args.get(ARG_DESC_EXPRESSION) else {
+            return Err(CommandError::InvalidConsumption(Some(
+                ARG_DESC_EXPRESSION.to_string(),
+            )));
+        };
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let shape = Shape::evaluate(expression, self.flags, pos1, pos2, player.position()).await?;
+
+        let world = player.world().await;
+        let mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        let cnt = edit_blocks(
+            &world,
+            pos1,
+            pos2,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                shape
+                    .contains(block_pos)
+                    .then(|| pattern.apply(block_pos, block_id))
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(CommandTree::new(NAMES, DESCRIPTION), |flags| {
+        argument(ARG_DESC_PATTERN, SimpleArgConsumer)
+            .then(argument(ARG_DESC_EXPRESSION, MsgArgConsumer).execute(GenerateExecuter { flags }))
+    })
+}
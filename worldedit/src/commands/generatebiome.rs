@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::message::MsgArgConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use super::generate::{GenerateFlags, Shape, with_flags};
+use crate::utils::biome::parse_biome;
+use crate::utils::region::edit_biomes;
+
+const NAMES: [&str; 3] = ["/generatebiome", "/genbiome", "/gb"];
+
+const DESCRIPTION: &str = "Sets biome according to a formula";
+
+const ARG_DESC_BIOME: &str = "The biome type to set";
+const ARG_DESC_EXPRESSION: &str = "Expression to test block placement locations and set biome type";
+
+struct GenerateBiomeExecuter {
+    flags: GenerateFlags,
+}
+
+#[async_trait]
+impl CommandExecutor for GenerateBiomeExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let Some(Arg::Simple(biome)) = args.get(ARG_DESC_BIOME) else {
+            return Err(CommandError::InvalidConsumption(Some(
+                ARG_DESC_BIOME.to_string(),
+            )));
+        };
+        let Some(Arg::Msg(expression)) = args.get(ARG_DESC_EXPRESSION) else {
+            return Err(CommandError::InvalidConsumption(Some(
+                ARG_DESC_EXPRESSION.to_string(),
+            )));
+        };
+        let biome = parse_biome(biome).map_err(CommandError::GeneralCommandIssue)?;
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let shape = Shape::evaluate(expression, self.flags, pos1, pos2, player.position()).await?;
+
+        let world = player.world().await;
+
+        let cnt = edit_biomes(&world, pos1, pos2, |block_pos, _| {
+            shape.contains(block_pos).then_some(biome.id)
+        })
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} biome cells have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(CommandTree::new(NAMES, DESCRIPTION), |flags| {
+        argument(ARG_DESC_BIOME, SimpleArgConsumer).then(
+            argument(ARG_DESC_EXPRESSION, MsgArgConsumer).execute(GenerateBiomeExecuter { flags }),
+        )
+    })
+}
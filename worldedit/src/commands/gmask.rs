@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::mask::find_mask;
+
+const NAMES: [&str; 1] = ["/gmask"];
+
+const DESCRIPTION: &str = "Set the global mask, or disable it if none is given";
+
+const ARG_DESC: &str = "The mask of blocks your edits may change";
+
+struct GmaskExecuter;
+
+#[async_trait]
+impl CommandExecutor for GmaskExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+        let player_uuid = player.get_entity().entity_uuid;
+
+        let message = match find_mask(args, ARG_DESC) {
+            Err(CommandError::InvalidConsumption(_)) => {
+                crate::global_masks().write().await.remove(&player_uuid);
+                "Global mask disabled."
+            }
+            mask => {
+                crate::global_masks()
+                    .write()
+                    .await
+                    .insert(player_uuid, mask?);
+                "Global mask set."
+            }
+        };
+
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, SimpleArgConsumer).execute(GmaskExecuter))
+        .execute(GmaskExecuter)
+}
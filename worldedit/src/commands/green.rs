@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_data::Block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+
+use super::snow::{ARG_DESC_RADIUS, find_radius, radius_consumer};
+use crate::utils::block::block_of;
+use crate::utils::flags::{Flags, with_flags};
+use crate::utils::heightmap::read_surface_around;
+use crate::utils::mask::masked;
+use crate::utils::region::{bounds, edit_positions};
+
+const NAMES: [&str; 1] = ["/green"];
+
+const DESCRIPTION: &str = "Converts dirt to grass blocks in the area";
+
+/// `-f`: also convert coarse dirt.
+const FLAGS: [&str; 1] = ["-f"];
+
+struct GreenExecuter {
+    flags: Flags,
+}
+
+#[async_trait]
+impl CommandExecutor for GreenExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let radius = find_radius(args)?;
+        let coarse = self.flags.has("-f");
+        let origin = BlockPos(player.position().to_i32());
+
+        let world = player.world().await;
+
+        let positions: Vec<_> = read_surface_around(&world, origin, radius)
+            .await
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .collect();
+        let mask = crate::global_mask(player, bounds(&positions)).await;
+        let cnt = edit_positions(
+            &world,
+            positions,
+            masked(mask.as_ref(), |_, block_id| {
+                let block = block_of(block_id);
+                (block.id == Block::DIRT.id || coarse && block.id == Block::COARSE_DIRT.id)
+                    .then_some(Block::GRASS_BLOCK.default_state.id)
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!("{} surfaces greened.", cnt)))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(
+        CommandTree::new(NAMES, DESCRIPTION),
+        &FLAGS,
+        |flags| argument(ARG_DESC_RADIUS, radius_consumer()).execute(GreenExecuter { flags }),
+        |flags| GreenExecuter { flags },
+    )
+}
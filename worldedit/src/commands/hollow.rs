@@ -0,0 +1,182 @@
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::is_air;
+use crate::utils::mask::masked;
+use crate::utils::pattern::{Pattern, PatternContext, find_pattern};
+use crate::utils::region::{BlockBuffer, NEIGHBOURS, edit_blocks, read_blocks};
+
+const NAMES: [&str; 1] = ["/hollow"];
+
+const DESCRIPTION: &str = "Hollows out the object contained in this selection";
+
+const ARG_DESC_THICKNESS: &str = "Thickness of the shell to leave";
+const ARG_DESC_PATTERN: &str = "The pattern of blocks to replace the hollowed area with";
+
+fn thickness_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(0)
+        .name(ARG_DESC_THICKNESS)
+}
+
+/// Marks every block that is reachable through air from the boundary of the selection.
+/// Afterwards the marked area is grown by `thickness - 1` layers, so the remaining
+/// unmarked blocks without a marked neighbour form the inside of the object.
+fn find_outside(buffer: &BlockBuffer, thickness: i32) -> Vec<bool> {
+    let (min, max) = (buffer.min(), buffer.max());
+    let mut outside = vec![false; buffer.len()];
+    let mut queue = VecDeque::new();
+
+    for x in min.x..=max.x {
+        for y in min.y..=max.y {
+            for z in min.z..=max.z {
+                let on_boundary = x == min.x
+                    || x == max.x
+                    || y == min.y
+                    || y == max.y
+                    || z == min.z
+                    || z == max.z;
+                if on_boundary {
+                    queue.push_back(BlockPos(Vector3::new(x, y, z)));
+                }
+            }
+        }
+    }
+
+    while let Some(pos) = queue.pop_front() {
+        let Some(index) = buffer.index(pos) else {
+            continue;
+        };
+        if outside[index] || !buffer.get(pos).is_some_and(is_air) {
+            continue;
+        }
+        outside[index] = true;
+        for (dx, dy, dz) in NEIGHBOURS {
+            queue.push_back(BlockPos(pos.0 + Vector3::new(dx, dy, dz)));
+        }
+    }
+
+    for _ in 1..thickness {
+        let mut grown = outside.clone();
+        for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                for z in min.z..=max.z {
+                    let pos = BlockPos(Vector3::new(x, y, z));
+                    if let Some(index) = buffer.index(pos) {
+                        grown[index] |= touches_outside(buffer, &outside, pos);
+                    }
+                }
+            }
+        }
+        outside = grown;
+    }
+
+    outside
+}
+
+/// Whether a neighbour of `pos` is outside, counting everything beyond the selection
+/// as outside.
+fn touches_outside(buffer: &BlockBuffer, outside: &[bool], pos: BlockPos) -> bool {
+    NEIGHBOURS.iter().any(|(dx, dy, dz)| {
+        buffer
+            .index(BlockPos(pos.0 + Vector3::new(*dx, *dy, *dz)))
+            .is_none_or(|index| outside[index])
+    })
+}
+
+struct HollowExecuter;
+
+#[async_trait]
+impl CommandExecutor for HollowExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let thickness = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_THICKNESS)
+        {
+            Ok(Ok(thickness)) => thickness,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Thickness must be 0 or greater.".to_string(),
+                ));
+            }
+            Err(_) => 0,
+        };
+        let pattern = match find_pattern(
+            args,
+            ARG_DESC_PATTERN,
+            &crate::pattern_context(player).await,
+        ) {
+            Err(CommandError::InvalidConsumption(_)) => {
+                Pattern::parse("air", &PatternContext::default())
+                    .map_err(CommandError::GeneralCommandIssue)?
+            }
+            pattern => pattern?,
+        };
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+        let mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        let buffer = read_blocks(&world, pos1, pos2).await;
+        let outside = find_outside(&buffer, thickness);
+
+        let cnt = edit_blocks(
+            &world,
+            pos1,
+            pos2,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                let index = buffer.index(block_pos)?;
+                if outside[index] || touches_outside(&buffer, &outside, block_pos) {
+                    return None;
+                }
+                Some(pattern.apply(block_pos, block_id))
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            argument(ARG_DESC_THICKNESS, thickness_consumer())
+                .then(argument(ARG_DESC_PATTERN, SimpleArgConsumer).execute(HollowExecuter))
+                .execute(HollowExecuter),
+        )
+        .execute(HollowExecuter)
+}
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::navigation::jump_to;
+
+const NAMES: [&str; 2] = ["jumpto", "j"];
+
+const DESCRIPTION: &str = "Teleport to the block you are looking at";
+
+struct JumpToExecuter;
+
+#[async_trait]
+impl CommandExecutor for JumpToExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        jump_to(player)
+            .await
+            .map_err(CommandError::GeneralCommandIssue)?;
+
+        sender.send_message(TextComponent::text("Poof!")).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).execute(JumpToExecuter)
+}
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::{argument, literal};
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::SelectionMode;
+use crate::utils::draw::{MAX_THICKNESS, hollow, line, thicken};
+use crate::utils::mask::masked;
+use crate::utils::pattern::find_pattern;
+use crate::utils::region::{bounds, edit_positions};
+
+const NAMES: [&str; 1] = ["/line"];
+
+const DESCRIPTION: &str = "Draws line segments between cuboid selection corners";
+
+const ARG_DESC_PATTERN: &str = "The pattern of blocks to place";
+const ARG_DESC_THICKNESS: &str = "The thickness of the line";
+
+fn thickness_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(0)
+        .max(MAX_THICKNESS)
+        .name(ARG_DESC_THICKNESS)
+}
+
+struct LineExecuter {
+    hollow: bool,
+}
+
+#[async_trait]
+impl CommandExecutor for LineExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let pattern = find_pattern(
+            args,
+            ARG_DESC_PATTERN,
+            &crate::pattern_context(player).await,
+        )?;
+        let thickness = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_THICKNESS)
+        {
+            Ok(Ok(thickness)) => thickness,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(format!(
+                    "Thickness must be between 0 and {MAX_THICKNESS}."
+                )));
+            }
+            Err(_) => 0,
+        };
+
+        let player_uuid = player.get_entity().entity_uuid;
+        let is_cuboid = crate::selections()
+            .read()
+            .await
+            .get(&player_uuid)
+            .is_none_or(|selection| selection.mode() == SelectionMode::Cuboid);
+        if !is_cuboid {
+            return Err(CommandError::GeneralCommandIssue(
+                "//line only works with cuboid selections".to_string(),
+            ));
+        }
+        // Not normalized: the line runs from pos1 to pos2.
+        let (pos1, pos2) = crate::fetch_selections(&player_uuid).await?;
+
+        let mut shape = thicken(&line(pos1, pos2), thickness);
+        if self.hollow {
+            shape = hollow(&shape);
+        }
+
+        let world = player.world().await;
+
+        let mask = crate::global_mask(player, bounds(&shape)).await;
+        let cnt = edit_positions(
+            &world,
+            shape,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                Some(pattern.apply(block_pos, block_id))
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(ARG_DESC_PATTERN, SimpleArgConsumer)
+            .then(literal("-h").execute(LineExecuter { hollow: true }))
+            .then(
+                argument(ARG_DESC_THICKNESS, thickness_consumer())
+                    .then(literal("-h").execute(LineExecuter { hollow: true }))
+                    .execute(LineExecuter { hollow: false }),
+            )
+            .execute(LineExecuter { hollow: false }),
+    )
+}
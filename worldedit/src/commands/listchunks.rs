@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::chunked_range::chunk_span;
+
+const NAMES: [&str; 1] = ["/listchunks"];
+
+const DESCRIPTION: &str = "List chunks that your selection includes";
+
+/// How many chunks are listed before the rest is only counted.
+const MAX_LISTED: usize = 100;
+
+struct ListChunksExecuter;
+
+#[async_trait]
+impl CommandExecutor for ListChunksExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let (Some(chunks_x), Some(chunks_z)) = (
+            chunk_span(pos1.0.x..=pos2.0.x),
+            chunk_span(pos1.0.z..=pos2.0.z),
+        ) else {
+            return Ok(());
+        };
+        let chunks: Vec<String> = chunks_x
+            .flat_map(|x| chunks_z.clone().map(move |z| format!("[{x}, {z}]")))
+            .collect();
+
+        let mut message = format!(
+            "The selection includes {} chunks: {}",
+            chunks.len(),
+            chunks[..chunks.len().min(MAX_LISTED)].join(" ")
+        );
+        if chunks.len() > MAX_LISTED {
+            message += &format!(" and {} more", chunks.len() - MAX_LISTED);
+        }
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).execute(ListChunksExecuter)
+}
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::tools::modify_tool;
+use crate::utils::mask::find_mask;
+
+const NAMES: [&str; 1] = ["/mask"];
+
+const DESCRIPTION: &str = "Set the brush mask, or disable it if none is given";
+
+const ARG_DESC: &str = "The mask of blocks the tool may change";
+
+struct MaskExecuter;
+
+#[async_trait]
+impl CommandExecutor for MaskExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let mask = match find_mask(args, ARG_DESC) {
+            Err(CommandError::InvalidConsumption(_)) => None,
+            mask => Some(mask?),
+        };
+        let message = if mask.is_some() {
+            "Brush mask set."
+        } else {
+            "Brush mask disabled."
+        };
+
+        modify_tool(player, |tool| tool.mask = mask).await?;
+
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, SimpleArgConsumer).execute(MaskExecuter))
+        .execute(MaskExecuter)
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::tools::modify_tool;
+use crate::utils::pattern::find_pattern;
+
+const NAMES: [&str; 2] = ["/material", "/mat"];
+
+const DESCRIPTION: &str = "Set the brush material";
+
+const ARG_DESC: &str = "The pattern of blocks to set";
+
+struct MaterialExecuter;
+
+#[async_trait]
+impl CommandExecutor for MaterialExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let pattern = find_pattern(args, ARG_DESC, &crate::pattern_context(player).await)?;
+
+        modify_tool(player, |tool| tool.material = Some(pattern)).await?;
+
+        sender
+            .send_message(TextComponent::text("Brush material set."))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, SimpleArgConsumer).execute(MaterialExecuter))
+}
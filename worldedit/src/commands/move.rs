@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::is_air;
+use crate::utils::direction::parse_direction;
+use crate::utils::flags::{Flags, with_flags};
+use crate::utils::mask::masked;
+use crate::utils::pattern::{Pattern, PatternContext, find_pattern};
+use crate::utils::region::{
+    bounds, edit_blocks, place_block_entity, read_block_entities, read_block_entity_positions,
+    read_blocks,
+};
+
+const NAMES: [&str; 1] = ["/move"];
+
+const DESCRIPTION: &str = "Move the contents of the selection";
+
+const ARG_DESC_COUNT: &str = "number of blocks to move";
+const ARG_DESC_DIRECTION: &str = "The direction to move";
+const ARG_DESC_REPLACE: &str = "The pattern of blocks to leave behind";
+
+/// `-s`: shift the selection to the target location.
+/// `-a`: ignore air blocks.
+const FLAGS: [&str; 2] = ["-s", "-a"];
+
+fn count_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .name(ARG_DESC_COUNT)
+}
+
+struct MoveExecuter {
+    flags: Flags,
+}
+
+#[async_trait]
+impl CommandExecutor for MoveExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let count = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_COUNT) {
+            Ok(Ok(count)) => count,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Count must be 1 or greater.".to_string(),
+                ));
+            }
+            Err(_) => 1,
+        };
+        let direction = match args.get(ARG_DESC_DIRECTION) {
+            Some(Arg::Simple(direction)) => *direction,
+            _ => "me",
+        };
+        let replace = match find_pattern(
+            args,
+            ARG_DESC_REPLACE,
+            &crate::pattern_context(player).await,
+        ) {
+            Err(CommandError::InvalidConsumption(_)) => {
+                Pattern::parse("air", &PatternContext::default())
+                    .map_err(CommandError::GeneralCommandIssue)?
+            }
+            pattern => pattern?,
+        };
+
+        let entity = player.get_entity();
+        let direction = parse_direction(direction, entity.yaw.load(), entity.pitch.load())
+            .map_err(CommandError::GeneralCommandIssue)?;
+        let offset = Vector3::new(
+            direction.x * count,
+            direction.y * count,
+            direction.z * count,
+        );
+
+        let (mut pos1, mut pos2) = crate::fetch_selections(&entity.entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let (target1, target2) = (BlockPos(pos1.0 + offset), BlockPos(pos2.0 + offset));
+        let in_target = |pos: BlockPos| {
+            (target1.0.x..=target2.0.x).contains(&pos.0.x)
+                && (target1.0.y..=target2.0.y).contains(&pos.0.y)
+                && (target1.0.z..=target2.0.z).contains(&pos.0.z)
+        };
+        let skip_air = self.flags.has("-a");
+
+        let world = player.world().await;
+        let mask = crate::global_mask(player, bounds([&pos1, &pos2, &target1, &target2])).await;
+
+        // Read everything before writing anything, so overlapping moves are safe.
+        let buffer = read_blocks(&world, pos1, pos2).await;
+        let block_entities = read_block_entities(&world, pos1, pos2).await;
+        let sources: HashSet<_> = block_entities.iter().map(|(pos, _)| *pos).collect();
+        let targets: HashSet<_> = read_block_entity_positions(&world, target1, target2)
+            .await
+            .into_iter()
+            .collect();
+
+        // Only the block entities of blocks the mask lets through are moved, and only
+        // those of blocks that are overwritten are removed.
+        let mut replaced = HashSet::new();
+        let mut placed = HashSet::new();
+        let mut cnt = edit_blocks(
+            &world,
+            pos1,
+            pos2,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                if in_target(block_pos) {
+                    return None;
+                }
+                if sources.contains(&block_pos) {
+                    replaced.insert(block_pos);
+                }
+                Some(replace.apply(block_pos, block_id))
+            }),
+        )
+        .await;
+        cnt += edit_blocks(
+            &world,
+            target1,
+            target2,
+            masked(mask.as_ref(), |block_pos, _| {
+                let from = BlockPos(block_pos.0 - offset);
+                let block_id = buffer.get(from)?;
+                if skip_air && is_air(block_id) {
+                    return None;
+                }
+                if sources.contains(&block_pos) || targets.contains(&block_pos) {
+                    replaced.insert(block_pos);
+                }
+                if sources.contains(&from) {
+                    placed.insert(from);
+                }
+                Some(block_id)
+            }),
+        )
+        .await;
+
+        for pos in &replaced {
+            world.remove_block_entity(pos).await;
+        }
+        for (pos, nbt) in block_entities {
+            if replaced.contains(&pos) && placed.contains(&pos) {
+                place_block_entity(&world, BlockPos(pos.0 + offset), nbt).await;
+            }
+        }
+
+        if self.flags.has("-s") {
+            let mut selections = crate::selections().write().await;
+            if let Some(selection) = selections.get_mut(&entity.entity_uuid) {
+                selection.shift(offset);
+            }
+        }
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been moved.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(
+        CommandTree::new(NAMES, DESCRIPTION),
+        &FLAGS,
+        |flags| {
+            argument(ARG_DESC_COUNT, count_consumer())
+                .then(
+                    argument(ARG_DESC_DIRECTION, SimpleArgConsumer)
+                        .then(
+                            argument(ARG_DESC_REPLACE, SimpleArgConsumer)
+                                .execute(MoveExecuter { flags }),
+                        )
+                        .execute(MoveExecuter { flags }),
+                )
+                .execute(MoveExecuter { flags })
+        },
+        |flags| MoveExecuter { flags },
+    )
+}
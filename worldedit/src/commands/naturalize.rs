@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_data::Block;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::block_of;
+use crate::utils::heightmap::read_heightmap;
+use crate::utils::mask::masked;
+use crate::utils::region::edit_blocks;
+
+const NAMES: [&str; 1] = ["/naturalize"];
+
+const DESCRIPTION: &str = "3 layers of dirt on top then rock below";
+
+/// Only these blocks are rewritten, so ores, caves and builds are left alone.
+fn is_natural(block_id: u16) -> bool {
+    let block = block_of(block_id);
+    block.id == Block::GRASS_BLOCK.id || block.id == Block::DIRT.id || block.id == Block::STONE.id
+}
+
+struct NaturalizeExecuter;
+
+#[async_trait]
+impl CommandExecutor for NaturalizeExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+        let mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        let heightmap =
+            read_heightmap(&world, pos1, pos2, |_, block_id| is_natural(block_id)).await;
+
+        let cnt = edit_blocks(
+            &world,
+            pos1,
+            pos2,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                if !is_natural(block_id) {
+                    return None;
+                }
+                let depth = heightmap.get(block_pos.0.x, block_pos.0.z)? - block_pos.0.y;
+                let block = match depth {
+                    0 => &Block::GRASS_BLOCK,
+                    1..=3 => &Block::DIRT,
+                    _ => &Block::STONE,
+                };
+                Some(block.default_state.id)
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).execute(NaturalizeExecuter)
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::tools::unbind_tool;
+
+const NAMES: [&str; 1] = ["/none"];
+
+const DESCRIPTION: &str = "Unbind a bound tool from your current item";
+
+struct NoneExecuter;
+
+#[async_trait]
+impl CommandExecutor for NoneExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        if !unbind_tool(player).await {
+            return Err(CommandError::GeneralCommandIssue(
+                "No tool is bound to the item in your hand.".to_string(),
+            ));
+        }
+
+        sender
+            .send_message(TextComponent::text("Tool unbound from your current item."))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).execute(NoneExecuter)
+}
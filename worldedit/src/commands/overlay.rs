@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::is_air;
+use crate::utils::heightmap::read_heightmap;
+use crate::utils::mask::masked;
+use crate::utils::pattern::find_pattern;
+use crate::utils::region::{bounds, edit_positions};
+
+const NAMES: [&str; 1] = ["/overlay"];
+
+const DESCRIPTION: &str = "Set a block on top of blocks in the region";
+
+const ARG_DESC: &str = "The pattern of blocks to overlay";
+
+struct OverlayExecuter;
+
+#[async_trait]
+impl CommandExecutor for OverlayExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let pattern = find_pattern(args, ARG_DESC, &crate::pattern_context(player).await)?;
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+
+        let heightmap = read_heightmap(&world, pos1, pos2, |_, block_id| !is_air(block_id)).await;
+        let positions: Vec<_> = heightmap
+            .columns()
+            .filter_map(|(x, z, height)| {
+                let y = height? + 1;
+                (y <= pos2.0.y).then(|| BlockPos(Vector3::new(x, y, z)))
+            })
+            .collect();
+
+        let mask = crate::global_mask(player, bounds(&positions)).await;
+        let cnt = edit_positions(
+            &world,
+            positions,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                Some(pattern.apply(block_pos, block_id))
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, SimpleArgConsumer).execute(OverlayExecuter))
+}
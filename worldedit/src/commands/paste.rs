@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::flags::{Flags, with_only_flags};
+
+const NAMES: [&str; 1] = ["/paste"];
+
+const DESCRIPTION: &str = "Paste the clipboard's contents";
+
+/// `-a`: skip air blocks.
+/// `-e`: also paste entities.
+/// `-o`: paste at the original position instead of relative to the player.
+const FLAGS: [&str; 3] = ["-a", "-e", "-o"];
+
+struct PasteExecuter {
+    flags: Flags,
+}
+
+#[async_trait]
+impl CommandExecutor for PasteExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let clipboard = crate::fetch_clipboard(&player.get_entity().entity_uuid).await?;
+
+        let min = if self.flags.has("-o") {
+            clipboard.source()
+        } else {
+            BlockPos(player.position().to_i32() + clipboard.offset())
+        };
+
+        let world = player.world().await;
+        let size = clipboard.size();
+        let max = BlockPos(min.0 + Vector3::new(size.x - 1, size.y - 1, size.z - 1));
+        let mask = crate::global_mask(player, Some((min, max))).await;
+
+        let cnt = clipboard
+            .paste(
+                &world,
+                min,
+                self.flags.has("-a"),
+                self.flags.has("-e"),
+                mask.as_ref(),
+            )
+            .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been pasted.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_only_flags(CommandTree::new(NAMES, DESCRIPTION), &FLAGS, |flags| {
+        PasteExecuter { flags }
+    })
+}
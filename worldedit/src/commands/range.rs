@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::tools::{DEFAULT_RANGE, modify_tool};
+
+const NAMES: [&str; 1] = ["/range"];
+
+const DESCRIPTION: &str = "Set the brush range";
+
+const ARG_DESC: &str = "How far the tool reaches, in blocks";
+
+struct RangeExecuter;
+
+#[async_trait]
+impl CommandExecutor for RangeExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let range = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC)? {
+            Ok(range) => range,
+            Err(_) => {
+                return Err(CommandError::GeneralCommandIssue(format!(
+                    "Range must be between 1 and {DEFAULT_RANGE}."
+                )));
+            }
+        };
+
+        modify_tool(player, |tool| tool.range = range).await?;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Brush range set to {}.",
+                range
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(
+            ARG_DESC,
+            BoundedNumArgumentConsumer::new()
+                .min(1)
+                .max(DEFAULT_RANGE)
+                .name(ARG_DESC),
+        )
+        .execute(RangeExecuter),
+    )
+}
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector2::Vector2;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::biome::parse_biome;
+use crate::utils::chunked_range::{ChunkedRange, chunk_span};
+use crate::utils::flags::{Flags, with_flags};
+use crate::utils::mask::masked;
+use crate::utils::regen::{Generator, parse_seed};
+use crate::utils::region::{edit_biomes, edit_blocks, max_y, min_y, read_block_entity_positions};
+
+const NAMES: [&str; 1] = ["/regen"];
+
+const DESCRIPTION: &str = "Regenerates the contents of the selection";
+
+const ARG_DESC_SEED: &str = "The seed to regenerate with, otherwise uses world seed";
+const ARG_DESC_BIOME: &str = "The biome type to set in the regenerated area";
+
+/// `-c`: regenerate the whole chunks the selection touches, over the full height.
+const FLAGS: [&str; 1] = ["-c"];
+
+/// The 4x4x4 biome cell containing a block.
+fn biome_cell(pos: BlockPos) -> (i32, i32, i32) {
+    (pos.0.x >> 2, pos.0.y >> 2, pos.0.z >> 2)
+}
+
+struct RegenExecuter {
+    flags: Flags,
+}
+
+#[async_trait]
+impl CommandExecutor for RegenExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let seed = match args.get(ARG_DESC_SEED) {
+            Some(Arg::Simple(seed)) => Some(parse_seed(seed)),
+            _ => None,
+        };
+        let biome = match args.get(ARG_DESC_BIOME) {
+            Some(Arg::Simple(biome)) => {
+                Some(parse_biome(biome).map_err(CommandError::GeneralCommandIssue)?)
+            }
+            _ => None,
+        };
+
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+        if self.flags.has("-c") {
+            let (Some(chunks_x), Some(chunks_z)) = (
+                chunk_span(pos1.0.x..=pos2.0.x),
+                chunk_span(pos1.0.z..=pos2.0.z),
+            ) else {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Coordinates out of range.".to_string(),
+                ));
+            };
+            pos1 = BlockPos(Vector3::new(
+                chunks_x.start() << 4,
+                min_y(&world),
+                chunks_z.start() << 4,
+            ));
+            pos2 = BlockPos(Vector3::new(
+                (chunks_x.end() << 4) + 15,
+                max_y(&world),
+                (chunks_z.end() << 4) + 15,
+            ));
+        }
+        let mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        // Generate one chunk at a time and copy back only the part inside the selection,
+        // so the surrounding world stays untouched.
+        let generator = Generator::new(&world, seed);
+        let mut cnt = 0;
+        for (chunk_x, x_range) in ChunkedRange::new(pos1.0.x..=pos2.0.x) {
+            for (chunk_z, z_range) in ChunkedRange::new(pos1.0.z..=pos2.0.z) {
+                let generated = generator
+                    .generate(Vector2::new(chunk_x, chunk_z))
+                    .await
+                    .map_err(|err| CommandError::GeneralCommandIssue(err.to_string()))?;
+                let min = BlockPos(Vector3::new(
+                    (chunk_x << 4) + x_range.start(),
+                    pos1.0.y,
+                    (chunk_z << 4) + z_range.start(),
+                ));
+                let max = BlockPos(Vector3::new(
+                    (chunk_x << 4) + x_range.end(),
+                    pos2.0.y,
+                    (chunk_z << 4) + z_range.end(),
+                ));
+                let block_entities: HashSet<_> = read_block_entity_positions(&world, min, max)
+                    .await
+                    .into_iter()
+                    .collect();
+                // Chests and the like that are replaced must not keep their contents.
+                let mut replaced = Vec::new();
+                // The biome cells holding a block the mask lets through.
+                let mut cells = HashSet::new();
+                cnt += edit_blocks(
+                    &world,
+                    min,
+                    max,
+                    masked(mask.as_ref(), |block_pos, block_id| {
+                        cells.insert(biome_cell(block_pos));
+                        let state_id = generated.block(block_pos)?;
+                        if state_id != block_id && block_entities.contains(&block_pos) {
+                            replaced.push(block_pos);
+                        }
+                        Some(state_id)
+                    }),
+                )
+                .await;
+                for pos in &replaced {
+                    world.remove_block_entity(pos).await;
+                }
+                edit_biomes(&world, min, max, |block_pos, _| {
+                    if mask.is_some() && !cells.contains(&biome_cell(block_pos)) {
+                        return None;
+                    }
+                    match biome {
+                        Some(biome) => Some(biome.id),
+                        None => generated.biome(block_pos),
+                    }
+                })
+                .await;
+            }
+        }
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Region regenerated. {} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(
+        CommandTree::new(NAMES, DESCRIPTION),
+        &FLAGS,
+        |flags| {
+            argument(ARG_DESC_SEED, SimpleArgConsumer)
+                .then(argument(ARG_DESC_BIOME, SimpleArgConsumer).execute(RegenExecuter { flags }))
+                .execute(RegenExecuter { flags })
+        },
+        |flags| RegenExecuter { flags },
+    )
+}
@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use super::butcher::{find_radius, radius_consumer};
+use crate::utils::entity::{EntityKind, entities_near, entity_name, remove_entity};
+
+const NAMES: [&str; 3] = ["/remove", "/rem", "/rement"];
+
+const DESCRIPTION: &str = "Remove all entities of a type";
+
+const ARG_DESC_TYPE: &str = "The type of entity to remove";
+const ARG_DESC_RADIUS: &str = "The radius to remove from, -1 for all";
+
+/// Parses the entity type argument into the kinds it removes.
+fn parse_kinds(input: &str) -> Result<&'static [EntityKind], String> {
+    Ok(match input.to_lowercase().as_str() {
+        "items" => &[EntityKind::Item],
+        "projectiles" | "arrows" => &[EntityKind::Projectile],
+        "xp" => &[EntityKind::Experience],
+        "paintings" => &[EntityKind::Painting],
+        "itemframes" => &[EntityKind::ItemFrame],
+        "boats" => &[EntityKind::Boat],
+        "minecarts" | "carts" => &[EntityKind::Minecart],
+        "tnt" => &[EntityKind::Tnt],
+        "armorstands" => &[EntityKind::ArmorStand],
+        "all" => &[
+            EntityKind::Item,
+            EntityKind::Projectile,
+            EntityKind::Experience,
+            EntityKind::Painting,
+            EntityKind::ItemFrame,
+            EntityKind::Boat,
+            EntityKind::Minecart,
+            EntityKind::Tnt,
+        ],
+        _ => {
+            return Err(format!(
+                "Unknown entity type '{input}'. Use items, projectiles, xp, paintings, \
+                 itemframes, boats, minecarts, tnt, armorstands or all."
+            ));
+        }
+    })
+}
+
+struct RemoveExecuter;
+
+#[async_trait]
+impl CommandExecutor for RemoveExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let Some(Arg::Simple(kinds)) = args.get(ARG_DESC_TYPE) else {
+            return Err(CommandError::InvalidConsumption(Some(
+                ARG_DESC_TYPE.to_string(),
+            )));
+        };
+        let kinds = parse_kinds(kinds).map_err(CommandError::GeneralCommandIssue)?;
+        let radius = find_radius(args, ARG_DESC_RADIUS)?;
+
+        let world = player.world().await;
+
+        let mut cnt = 0;
+        for entity in entities_near(&world, player.position(), radius).await {
+            if kinds.contains(&EntityKind::of(entity_name(entity.as_ref()))) {
+                remove_entity(entity.as_ref()).await;
+                cnt += 1;
+            }
+        }
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} entities have been removed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(ARG_DESC_TYPE, SimpleArgConsumer).then(
+            argument(ARG_DESC_RADIUS, radius_consumer(ARG_DESC_RADIUS)).execute(RemoveExecuter),
+        ),
+    )
+}
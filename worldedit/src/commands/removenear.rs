@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_data::Block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::mask::{find_mask, masked};
+use crate::utils::region::edit_blocks;
+
+const NAMES: [&str; 1] = ["/removenear"];
+
+const DESCRIPTION: &str = "Remove blocks near you";
+
+const ARG_DESC_MASK: &str = "The mask of blocks to remove";
+const ARG_DESC_RADIUS: &str = "The radius of the cube to remove from";
+
+/// The radius used when none is given.
+const DEFAULT_RADIUS: i32 = 50;
+
+fn radius_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .name(ARG_DESC_RADIUS)
+}
+
+struct RemoveNearExecuter;
+
+#[async_trait]
+impl CommandExecutor for RemoveNearExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let mut mask = find_mask(args, ARG_DESC_MASK)?;
+        let radius = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_RADIUS) {
+            Ok(Ok(radius)) => radius,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Radius must be 1 or greater.".to_string(),
+                ));
+            }
+            Err(_) => DEFAULT_RADIUS,
+        };
+
+        let center = player.position().to_i32();
+        let offset = Vector3::new(radius, radius, radius);
+        let (pos1, pos2) = (BlockPos(center - offset), BlockPos(center + offset));
+
+        let world = player.world().await;
+        crate::prepare_mask(player, &mut mask, pos1, pos2).await;
+        let global_mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        let cnt = edit_blocks(
+            &world,
+            pos1,
+            pos2,
+            masked(global_mask.as_ref(), |block_pos, block_id| {
+                mask.test(block_pos, block_id)
+                    .then_some(Block::AIR.default_state.id)
+            }),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been removed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(ARG_DESC_MASK, SimpleArgConsumer)
+            .execute(RemoveNearExecuter)
+            .then(argument(ARG_DESC_RADIUS, radius_consumer()).execute(RemoveNearExecuter)),
+    )
+}
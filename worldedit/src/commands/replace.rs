@@ -2,19 +2,18 @@ use async_trait::async_trait;
 use pumpkin::command::CommandExecutor;
 use pumpkin::command::CommandSender;
 use pumpkin::command::args::ConsumedArgs;
-use pumpkin::command::args::FindArg;
-use pumpkin::command::args::block::BlockArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
 use pumpkin::command::dispatcher::CommandError;
 use pumpkin::command::tree::CommandTree;
 use pumpkin::command::tree::builder::argument;
 use pumpkin::entity::EntityBase;
 use pumpkin::server::Server;
-use pumpkin_util::math::position::BlockPos;
-use pumpkin_util::math::vector3::Vector3;
 use pumpkin_util::text::TextComponent;
-use pumpkin_world::world::BlockFlags;
 
 use crate::normalization_selection;
+use crate::utils::mask::{find_mask, masked};
+use crate::utils::pattern::find_pattern;
+use crate::utils::region::edit_blocks;
 
 const NAMES: [&str; 3] = ["/replace", "/re", "/rep"];
 
@@ -23,10 +22,10 @@ const DESCRIPTION: &str = "Replace all blocks in the selection with another";
 const ARG_DESC_FROM: &str = "The mask representing blocks to replace";
 const ARG_DESC_TO: &str = "The pattern of blocks to set";
 
-struct SetExecuter;
+struct ReplaceExecuter;
 
 #[async_trait]
-impl CommandExecutor for SetExecuter {
+impl CommandExecutor for ReplaceExecuter {
     async fn execute<'a>(
         &self,
         sender: &mut CommandSender,
@@ -36,36 +35,29 @@ impl CommandExecutor for SetExecuter {
         let Some(player) = sender.as_player() else {
             return Err(CommandError::PermissionDenied);
         };
+        let player_uuid = player.get_entity().entity_uuid;
 
-        let block_from = BlockArgumentConsumer::find_arg(args, ARG_DESC_FROM)?;
-        let block_to = BlockArgumentConsumer::find_arg(args, ARG_DESC_TO)?;
+        let mut from = find_mask(args, ARG_DESC_FROM)?;
+        let to = find_pattern(args, ARG_DESC_TO, &crate::pattern_context(player).await)?;
 
-        let (mut pos1, mut pos2) =
-            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+        let (mut pos1, mut pos2) = crate::fetch_selections(&player_uuid).await?;
 
         normalization_selection(&mut pos1.0, &mut pos2.0);
 
         let world = player.world().await;
-
-        let (x1, x2) = (pos1.0.x, pos2.0.x);
-        let (z1, z2) = (pos1.0.z, pos2.0.z);
-        let (y1, y2) = (pos1.0.y, pos2.0.y);
-
-        let mut cnt = 0;
-
-        for x in x1..=x2 {
-            for y in y1..=y2 {
-                for z in z1..=z2 {
-                    let block_position = BlockPos(Vector3 { x, y, z });
-                    if world.get_block_state(&block_position).await.id != block_from.id {
-                        world
-                            .set_block_state(&block_position, block_to.id, BlockFlags::FORCE_STATE)
-                            .await;
-                        cnt += 1;
-                    }
-                }
-            }
-        }
+        crate::prepare_mask(player, &mut from, pos1, pos2).await;
+        let mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        let cnt = edit_blocks(
+            &world,
+            pos1,
+            pos2,
+            masked(mask.as_ref(), |block_pos, block_id| {
+                from.test(block_pos, block_id)
+                    .then(|| to.apply(block_pos, block_id))
+            }),
+        )
+        .await;
 
         sender
             .send_message(TextComponent::text(format!(
@@ -80,7 +72,7 @@ impl CommandExecutor for SetExecuter {
 
 pub fn init_command_tree() -> CommandTree {
     CommandTree::new(NAMES, DESCRIPTION).then(
-        argument(ARG_DESC_FROM, BlockArgumentConsumer)
-            .then(argument(ARG_DESC_TO, BlockArgumentConsumer).execute(SetExecuter)),
+        argument(ARG_DESC_FROM, SimpleArgConsumer)
+            .then(argument(ARG_DESC_TO, SimpleArgConsumer).execute(ReplaceExecuter)),
     )
 }
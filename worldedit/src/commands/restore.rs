@@ -0,0 +1,192 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::anvil::{DecodedChunk, read_chunk, region_file_name};
+use crate::utils::biome::parse_biome;
+use crate::utils::block::state_from_properties;
+use crate::utils::chunked_range::ChunkedRange;
+use crate::utils::mask::masked;
+use crate::utils::region::{edit_blocks, place_block_entity, read_block_entity_positions};
+use crate::utils::snapshot::{Snapshot, region_dir};
+
+const NAMES: [&str; 1] = ["/restore"];
+
+const DESCRIPTION: &str = "Restore the selection from a snapshot";
+
+const ARG_DESC: &str = "The snapshot to restore from, otherwise the chosen or latest one";
+
+/// Reads and decodes the given chunks from a snapshot. Chunks missing from it are
+/// left out, as are blocks and biomes the server doesn't know.
+fn read_chunks(
+    snapshot: &Snapshot,
+    region_dir: &str,
+    chunks: &[(i32, i32)],
+) -> Result<HashMap<(i32, i32), DecodedChunk>, String> {
+    let files: Vec<String> = chunks
+        .iter()
+        .map(|(x, z)| region_file_name(*x, *z))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let regions = snapshot.read_regions(region_dir, &files)?;
+
+    let mut decoded = HashMap::new();
+    for &(x, z) in chunks {
+        let Some(region) = regions.get(&region_file_name(x, z)) else {
+            continue;
+        };
+        let Some(chunk) = read_chunk(region, x, z)? else {
+            continue;
+        };
+        let chunk = DecodedChunk::decode(&chunk, state_from_properties, |name| {
+            parse_biome(name).ok().map(|biome| biome.id)
+        })?;
+        decoded.insert((x, z), chunk);
+    }
+    Ok(decoded)
+}
+
+struct RestoreExecuter;
+
+#[async_trait]
+impl CommandExecutor for RestoreExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let name = match args.get(ARG_DESC) {
+            Some(Arg::Simple(name)) => Some(*name),
+            _ => None,
+        };
+
+        let player_uuid = player.get_entity().entity_uuid;
+        let (mut pos1, mut pos2) = crate::fetch_selections(&player_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let snapshot = super::snapshot::find_snapshot(&player_uuid, name).await?;
+        let world = player.world().await;
+        let mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        let x_ranges: Vec<_> = ChunkedRange::new(pos1.0.x..=pos2.0.x).collect();
+        let z_ranges: Vec<_> = ChunkedRange::new(pos1.0.z..=pos2.0.z).collect();
+        let chunks: Vec<(i32, i32)> = x_ranges
+            .iter()
+            .flat_map(|(x, _)| z_ranges.iter().map(move |(z, _)| (*x, *z)))
+            .collect();
+
+        // Reading archives and decoding chunks is blocking work.
+        let decoded = {
+            let snapshot = snapshot.clone();
+            let region_dir = region_dir(&world);
+            let chunks = chunks.clone();
+            tokio::task::spawn_blocking(move || read_chunks(&snapshot, region_dir, &chunks))
+                .await
+                .map_err(|err| CommandError::GeneralCommandIssue(err.to_string()))?
+                .map_err(|err| {
+                    CommandError::GeneralCommandIssue(format!(
+                        "Failed to read snapshot {}: {err}",
+                        snapshot.name
+                    ))
+                })?
+        };
+        if decoded.is_empty() {
+            return Err(CommandError::GeneralCommandIssue(format!(
+                "No chunks of the selection could be found in snapshot {}.",
+                snapshot.name
+            )));
+        }
+
+        let mut cnt = 0;
+        for (chunk_x, x_range) in &x_ranges {
+            for (chunk_z, z_range) in &z_ranges {
+                let Some(chunk) = decoded.get(&(*chunk_x, *chunk_z)) else {
+                    continue;
+                };
+                let min = BlockPos(Vector3::new(
+                    (chunk_x << 4) + x_range.start(),
+                    pos1.0.y,
+                    (chunk_z << 4) + z_range.start(),
+                ));
+                let max = BlockPos(Vector3::new(
+                    (chunk_x << 4) + x_range.end(),
+                    pos2.0.y,
+                    (chunk_z << 4) + z_range.end(),
+                ));
+                let live: HashSet<_> = read_block_entity_positions(&world, min, max)
+                    .await
+                    .into_iter()
+                    .collect();
+                // Restored blocks get the block entities of the snapshot back, instead of
+                // keeping the current ones.
+                let mut restored = Vec::new();
+                cnt += edit_blocks(
+                    &world,
+                    min,
+                    max,
+                    masked(mask.as_ref(), |block_pos, _| {
+                        let Vector3 { x, y, z } = block_pos.0;
+                        let state_id = chunk.block(x, y, z)?;
+                        if live.contains(&block_pos) || chunk.block_entity(x, y, z).is_some() {
+                            restored.push(block_pos);
+                        }
+                        Some(state_id)
+                    }),
+                )
+                .await;
+                for pos in restored {
+                    if live.contains(&pos) {
+                        world.remove_block_entity(&pos).await;
+                    }
+                    if let Some(nbt) = chunk.block_entity(pos.0.x, pos.0.y, pos.0.z) {
+                        place_block_entity(&world, pos, nbt.clone()).await;
+                    }
+                }
+            }
+        }
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Restored from snapshot {}. {} blocks have been changed.",
+                snapshot.name, cnt
+            )))
+            .await;
+        let missing = chunks.len() - decoded.len();
+        if missing > 0 {
+            sender
+                .send_message(TextComponent::text(format!(
+                    "{} chunks were missing from the snapshot and left untouched.",
+                    missing
+                )))
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC, SimpleArgConsumer).execute(RestoreExecuter))
+        .execute(RestoreExecuter)
+}
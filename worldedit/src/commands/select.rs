@@ -4,10 +4,13 @@ use pumpkin::command::CommandSender;
 use pumpkin::command::args::ConsumedArgs;
 use pumpkin::command::dispatcher::CommandError;
 use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::literal;
 use pumpkin::entity::EntityBase;
 use pumpkin::server::Server;
 use pumpkin_util::text::TextComponent;
 
+use crate::{Selection, SelectionMode};
+
 const NAMES: [&str; 4] = ["/sel", ";", "/desel", "/deselect"];
 
 const DESCRIPTION: &str = "Choose a region selector";
@@ -31,7 +34,43 @@ impl CommandExecutor for SelectExecuter {
         let player_uuid = player.get_entity().entity_uuid;
         {
             let mut selections = crate::selections().write().await;
-            selections.remove(&player_uuid);
+            if let Some(selection) = selections.get_mut(&player_uuid) {
+                // Keep the selector the player chose.
+                *selection = Selection::new(selection.mode());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct SelectModeExecuter(SelectionMode);
+
+#[async_trait]
+impl CommandExecutor for SelectModeExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+        let message = match self.0 {
+            SelectionMode::Cuboid => "Cuboid: left click for point 1, right click for point 2",
+            SelectionMode::Convex => {
+                "Convex polyhedral selector: Left click=First vertex, right click to add more."
+            }
+        };
+        sender
+            .send_message(TextComponent::text(message.to_string()))
+            .await;
+
+        let player_uuid = player.get_entity().entity_uuid;
+        {
+            let mut selections = crate::selections().write().await;
+            selections.insert(player_uuid, Selection::new(self.0));
         }
 
         Ok(())
@@ -39,5 +78,8 @@ impl CommandExecutor for SelectExecuter {
 }
 
 pub fn init_command_tree() -> CommandTree {
-    CommandTree::new(NAMES, DESCRIPTION).execute(SelectExecuter)
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(literal("cuboid").execute(SelectModeExecuter(SelectionMode::Cuboid)))
+        .then(literal("convex").execute(SelectModeExecuter(SelectionMode::Convex)))
+        .execute(SelectExecuter)
 }
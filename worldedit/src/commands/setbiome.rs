@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::biome::parse_biome;
+use crate::utils::flags::{Flags, with_flags};
+use crate::utils::region::edit_biomes;
+
+const NAMES: [&str; 1] = ["/setbiome"];
+
+const DESCRIPTION: &str = "Sets the biome of your current block or region";
+
+const ARG_DESC: &str = "Biome type";
+
+/// `-p`: only set the biome at the player's position.
+const FLAGS: [&str; 1] = ["-p"];
+
+struct SetBiomeExecuter {
+    flags: Flags,
+}
+
+#[async_trait]
+impl CommandExecutor for SetBiomeExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let Some(Arg::Simple(biome)) = args.get(ARG_DESC) else {
+            return Err(CommandError::InvalidConsumption(Some(ARG_DESC.to_string())));
+        };
+        let biome = parse_biome(biome).map_err(CommandError::GeneralCommandIssue)?;
+
+        let (pos1, pos2) = if self.flags.has("-p") {
+            let pos = BlockPos(player.position().to_i32());
+            (pos, pos)
+        } else {
+            let (mut pos1, mut pos2) =
+                crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+            crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+            (pos1, pos2)
+        };
+
+        let world = player.world().await;
+
+        let cnt = edit_biomes(&world, pos1, pos2, |_, _| Some(biome.id)).await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} biome cells have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(
+        CommandTree::new(NAMES, DESCRIPTION),
+        &FLAGS,
+        |flags| argument(ARG_DESC, SimpleArgConsumer).execute(SetBiomeExecuter { flags }),
+        |flags| SetBiomeExecuter { flags },
+    )
+}
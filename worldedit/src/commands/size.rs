@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use super::brush::{ARG_DESC_RADIUS, radius_consumer};
+use crate::tools::{MAX_SIZE, modify_tool};
+
+const NAMES: [&str; 1] = ["/size"];
+
+const DESCRIPTION: &str = "Set the brush size";
+
+struct SizeExecuter;
+
+#[async_trait]
+impl CommandExecutor for SizeExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let size = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_RADIUS)? {
+            Ok(size) => size,
+            Err(_) => {
+                return Err(CommandError::GeneralCommandIssue(format!(
+                    "Size must be between 0 and {MAX_SIZE}."
+                )));
+            }
+        };
+
+        modify_tool(player, |tool| tool.size = size).await?;
+
+        sender
+            .send_message(TextComponent::text(format!("Brush size set to {}.", size)))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC_RADIUS, radius_consumer()).execute(SizeExecuter))
+}
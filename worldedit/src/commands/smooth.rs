@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::is_air;
+use crate::utils::heightmap::{MAX_ITERATIONS, smooth_terrain};
+use crate::utils::mask::find_mask;
+
+const NAMES: [&str; 1] = ["/smooth"];
+
+const DESCRIPTION: &str = "Smooth the elevation in the selection";
+
+const ARG_DESC_ITERATIONS: &str = "# of iterations to perform";
+const ARG_DESC_MASK: &str = "The mask of blocks to use as the height map";
+
+fn iterations_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .max(MAX_ITERATIONS)
+        .name(ARG_DESC_ITERATIONS)
+}
+
+struct SmoothExecuter;
+
+#[async_trait]
+impl CommandExecutor for SmoothExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let iterations =
+            match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_ITERATIONS) {
+                Ok(Ok(iterations)) => iterations,
+                Ok(Err(_)) => {
+                    return Err(CommandError::GeneralCommandIssue(format!(
+                        "Iterations must be between 1 and {MAX_ITERATIONS}."
+                    )));
+                }
+                Err(_) => 1,
+            };
+        let mut mask = match find_mask(args, ARG_DESC_MASK) {
+            Err(CommandError::InvalidConsumption(_)) => None,
+            mask => Some(mask?),
+        };
+        let (mut pos1, mut pos2) =
+            crate::fetch_selections(&player.get_entity().entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        let world = player.world().await;
+        if let Some(mask) = &mut mask {
+            crate::prepare_mask(player, mask, pos1, pos2).await;
+        }
+        let matches = |block_pos: BlockPos, block_id: u16| match &mask {
+            Some(mask) => mask.test(block_pos, block_id),
+            None => !is_air(block_id),
+        };
+        let global_mask = crate::global_mask(player, Some((pos1, pos2))).await;
+
+        let cnt = smooth_terrain(
+            &world,
+            pos1,
+            pos2,
+            iterations,
+            matches,
+            global_mask.as_ref(),
+        )
+        .await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Terrain's height map smoothed. {} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            argument(ARG_DESC_ITERATIONS, iterations_consumer())
+                .then(argument(ARG_DESC_MASK, SimpleArgConsumer).execute(SmoothExecuter))
+                .execute(SmoothExecuter),
+        )
+        .execute(SmoothExecuter)
+}
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::{argument, literal};
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+use tokio::sync::RwLock;
+
+use crate::utils::snapshot::{Snapshot, list_snapshots, parse_date};
+
+const NAMES: [&str; 1] = ["/snapshot"];
+
+const DESCRIPTION: &str = "Snapshot commands for restoring backups";
+
+const ARG_DESC_NUMBER: &str = "The number of snapshots to list";
+const ARG_DESC_NAME: &str = "The name of the snapshot, or 'latest'";
+const ARG_DESC_INDEX: &str = "The index of the snapshot in //snapshot list";
+const ARG_DESC_DATE: &str = "A date like 2024-01-31 or 2024-01-31T12:30";
+
+type SnapshotChoices = HashMap<uuid::Uuid, String>;
+
+static SNAPSHOT_CHOICES: OnceLock<RwLock<SnapshotChoices>> = OnceLock::new();
+
+/// The snapshot each player picked; players without one restore from the latest.
+fn snapshot_choices() -> &'static RwLock<SnapshotChoices> {
+    SNAPSHOT_CHOICES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Lists the snapshots in the configured directory, newest first.
+async fn snapshots() -> Result<Vec<Snapshot>, CommandError> {
+    let dir = crate::config().snapshot_directory.clone();
+    let mut snapshots = tokio::task::spawn_blocking(move || list_snapshots(&dir))
+        .await
+        .map_err(|err| CommandError::GeneralCommandIssue(err.to_string()))?
+        .map_err(CommandError::GeneralCommandIssue)?;
+    snapshots.reverse();
+    Ok(snapshots)
+}
+
+fn by_name(snapshots: Vec<Snapshot>, name: &str) -> Result<Snapshot, CommandError> {
+    snapshots
+        .into_iter()
+        .find(|snapshot| snapshot.name == name)
+        .ok_or_else(|| {
+            CommandError::GeneralCommandIssue(format!("That snapshot does not exist: {name}"))
+        })
+}
+
+/// Returns the snapshot `name`, or else the one the player picked, or else the latest.
+pub(super) async fn find_snapshot(
+    player_uuid: &uuid::Uuid,
+    name: Option<&str>,
+) -> Result<Snapshot, CommandError> {
+    let snapshots = snapshots().await?;
+    let chosen = snapshot_choices().read().await.get(player_uuid).cloned();
+    match name.map(str::to_string).or(chosen) {
+        Some(name) if name != "latest" => by_name(snapshots, &name),
+        _ => snapshots.into_iter().next().ok_or_else(|| {
+            CommandError::GeneralCommandIssue("No snapshots are available.".to_string())
+        }),
+    }
+}
+
+async fn choose(
+    sender: &mut CommandSender,
+    snapshot: Option<Snapshot>,
+) -> Result<(), CommandError> {
+    let Some(player) = sender.as_player() else {
+        return Err(CommandError::PermissionDenied);
+    };
+    let Some(snapshot) = snapshot else {
+        return Err(CommandError::GeneralCommandIssue(
+            "Couldn't find a snapshot.".to_string(),
+        ));
+    };
+
+    snapshot_choices()
+        .write()
+        .await
+        .insert(player.get_entity().entity_uuid, snapshot.name.clone());
+
+    sender
+        .send_message(TextComponent::text(format!(
+            "Snapshot set to: {}",
+            snapshot.name
+        )))
+        .await;
+
+    Ok(())
+}
+
+fn find_date(args: &ConsumedArgs<'_>) -> Result<u64, CommandError> {
+    let Some(Arg::Simple(date)) = args.get(ARG_DESC_DATE) else {
+        return Err(CommandError::InvalidConsumption(Some(
+            ARG_DESC_DATE.to_string(),
+        )));
+    };
+    parse_date(date).ok_or_else(|| {
+        CommandError::GeneralCommandIssue(format!("Could not detect the date inputted: {date}"))
+    })
+}
+
+struct ListExecuter;
+
+#[async_trait]
+impl CommandExecutor for ListExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let number = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_NUMBER) {
+            Ok(Ok(number)) => number as usize,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Number must be 1 or greater.".to_string(),
+                ));
+            }
+            Err(_) => 5,
+        };
+
+        let snapshots = snapshots().await?;
+        if snapshots.is_empty() {
+            return Err(CommandError::GeneralCommandIssue(
+                "No snapshots are available.".to_string(),
+            ));
+        }
+
+        sender
+            .send_message(TextComponent::text("Snapshots (newest first):"))
+            .await;
+        for (i, snapshot) in snapshots.iter().take(number).enumerate() {
+            sender
+                .send_message(TextComponent::text(format!("{}. {}", i + 1, snapshot.name)))
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+struct UseExecuter;
+
+#[async_trait]
+impl CommandExecutor for UseExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(Arg::Simple(name)) = args.get(ARG_DESC_NAME) else {
+            return Err(CommandError::InvalidConsumption(Some(
+                ARG_DESC_NAME.to_string(),
+            )));
+        };
+
+        let snapshots = snapshots().await?;
+        let snapshot = if *name == "latest" {
+            snapshots.into_iter().next()
+        } else {
+            Some(by_name(snapshots, name)?)
+        };
+        choose(sender, snapshot).await
+    }
+}
+
+struct SelExecuter;
+
+#[async_trait]
+impl CommandExecutor for SelExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let index = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_INDEX)? {
+            Ok(index) => index as usize,
+            Err(_) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Invalid index, must be 1 or greater.".to_string(),
+                ));
+            }
+        };
+
+        let snapshot = snapshots().await?.into_iter().nth(index - 1);
+        if snapshot.is_none() {
+            return Err(CommandError::GeneralCommandIssue(
+                "Invalid index, must be a number from //snapshot list.".to_string(),
+            ));
+        }
+        choose(sender, snapshot).await
+    }
+}
+
+/// Picks the newest snapshot before a date, or with `after` the oldest one after it.
+struct DateExecuter {
+    after: bool,
+}
+
+#[async_trait]
+impl CommandExecutor for DateExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let date = find_date(args)?;
+
+        let snapshots = snapshots().await?;
+        let snapshot = if self.after {
+            snapshots
+                .into_iter()
+                .rev()
+                .find(|snapshot| snapshot.time > date)
+        } else {
+            snapshots.into_iter().find(|snapshot| snapshot.time < date)
+        };
+        choose(sender, snapshot).await
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            literal("list")
+                .then(
+                    argument(
+                        ARG_DESC_NUMBER,
+                        BoundedNumArgumentConsumer::<i32>::new()
+                            .min(1)
+                            .name(ARG_DESC_NUMBER),
+                    )
+                    .execute(ListExecuter),
+                )
+                .execute(ListExecuter),
+        )
+        .then(literal("use").then(argument(ARG_DESC_NAME, SimpleArgConsumer).execute(UseExecuter)))
+        .then(
+            literal("sel").then(
+                argument(
+                    ARG_DESC_INDEX,
+                    BoundedNumArgumentConsumer::<i32>::new()
+                        .min(1)
+                        .name(ARG_DESC_INDEX),
+                )
+                .execute(SelExecuter),
+            ),
+        )
+        .then(literal("before").then(
+            argument(ARG_DESC_DATE, SimpleArgConsumer).execute(DateExecuter { after: false }),
+        ))
+        .then(
+            literal("after").then(
+                argument(ARG_DESC_DATE, SimpleArgConsumer).execute(DateExecuter { after: true }),
+            ),
+        )
+}
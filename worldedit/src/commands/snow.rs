@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin::world::World;
+use pumpkin_data::Block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::{block_of, is_solid, property, with_property};
+use crate::utils::heightmap::read_surface_around;
+use crate::utils::mask::{Mask, masked};
+use crate::utils::region::{bounds, edit_positions};
+
+const NAMES: [&str; 1] = ["/snow"];
+
+const DESCRIPTION: &str = "Simulates snow";
+
+pub(super) const ARG_DESC_RADIUS: &str = "The radius of the cylinder to affect";
+
+pub(super) fn radius_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .name(ARG_DESC_RADIUS)
+}
+
+/// Reads the radius, defaulting to 10 like WorldEdit.
+pub(super) fn find_radius(args: &ConsumedArgs<'_>) -> Result<i32, CommandError> {
+    match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_RADIUS) {
+        Ok(Ok(radius)) => Ok(radius),
+        Ok(Err(_)) => Err(CommandError::GeneralCommandIssue(
+            "Radius must be 1 or greater.".to_string(),
+        )),
+        Err(_) => Ok(10),
+    }
+}
+
+/// What happens to a block at the surface; `Snowy` toggles the property of grass,
+/// podzol and mycelium below a snow layer.
+pub(super) enum SurfaceChange {
+    Set(u16),
+    Snowy(&'static str),
+}
+
+/// Applies the changes where `mask` matches, returning the number of changed blocks.
+pub(super) async fn apply_changes(
+    world: &World,
+    changes: HashMap<BlockPos, SurfaceChange>,
+    mask: Option<&Mask>,
+) -> usize {
+    edit_positions(
+        world,
+        changes.keys().copied().collect::<Vec<_>>(),
+        masked(mask, |pos, block_id| match changes.get(&pos)? {
+            SurfaceChange::Set(state_id) => Some(*state_id),
+            SurfaceChange::Snowy(snowy) => with_property(block_id, "snowy", snowy),
+        }),
+    )
+    .await
+}
+
+struct SnowExecuter;
+
+#[async_trait]
+impl CommandExecutor for SnowExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let radius = find_radius(args)?;
+        let origin = BlockPos(player.position().to_i32());
+
+        let world = player.world().await;
+
+        // Still water freezes, solid ground gets a layer of snow and turns snowy.
+        let mut changes = HashMap::new();
+        for (pos, block_id) in read_surface_around(&world, origin, radius).await {
+            let block = block_of(block_id);
+            if block.id == Block::WATER.id {
+                if property(block_id, "level").is_some_and(|level| level == "0") {
+                    changes.insert(pos, SurfaceChange::Set(Block::ICE.default_state.id));
+                }
+            } else if block.id != Block::ICE.id && is_solid(block_id) {
+                changes.insert(pos, SurfaceChange::Snowy("true"));
+                changes.insert(
+                    BlockPos(pos.0 + Vector3::new(0, 1, 0)),
+                    SurfaceChange::Set(Block::SNOW.default_state.id),
+                );
+            }
+        }
+
+        let mask = crate::global_mask(player, bounds(changes.keys())).await;
+        let cnt = apply_changes(&world, changes, mask.as_ref()).await;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} surfaces covered. Let it snow~",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC_RADIUS, radius_consumer()).execute(SnowExecuter))
+        .execute(SnowExecuter)
+}
@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::block::is_air;
+use crate::utils::direction::parse_direction;
+use crate::utils::flags::{Flags, with_flags};
+use crate::utils::mask::masked;
+use crate::utils::region::{edit_blocks, place_block_entity, read_block_entities, read_blocks};
+
+const NAMES: [&str; 1] = ["/stack"];
+
+const DESCRIPTION: &str = "Repeat the contents of the selection";
+
+const ARG_DESC_COUNT: &str = "number of copies to stack";
+const ARG_DESC_DIRECTION: &str = "The direction to stack";
+
+/// `-s`: shift the selection to the last stacked copy.
+/// `-a`: ignore air blocks.
+const FLAGS: [&str; 2] = ["-s", "-a"];
+
+fn count_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .name(ARG_DESC_COUNT)
+}
+
+struct StackExecuter {
+    flags: Flags,
+}
+
+#[async_trait]
+impl CommandExecutor for StackExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let count = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_COUNT) {
+            Ok(Ok(count)) => count,
+            Ok(Err(_)) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Count must be 1 or greater.".to_string(),
+                ));
+            }
+            Err(_) => 1,
+        };
+        let direction = match args.get(ARG_DESC_DIRECTION) {
+            Some(Arg::Simple(direction)) => *direction,
+            _ => "me",
+        };
+        let entity = player.get_entity();
+        let direction = parse_direction(direction, entity.yaw.load(), entity.pitch.load())
+            .map_err(CommandError::GeneralCommandIssue)?;
+
+        let (mut pos1, mut pos2) = crate::fetch_selections(&entity.entity_uuid).await?;
+
+        crate::normalization_selection(&mut pos1.0, &mut pos2.0);
+
+        // Each copy is placed right next to the previous one.
+        let size = Vector3::new(
+            pos2.0.x - pos1.0.x + 1,
+            pos2.0.y - pos1.0.y + 1,
+            pos2.0.z - pos1.0.z + 1,
+        );
+        let step = Vector3::new(
+            direction.x * size.x,
+            direction.y * size.y,
+            direction.z * size.z,
+        );
+        let skip_air = self.flags.has("-a");
+
+        let world = player.world().await;
+
+        let buffer = read_blocks(&world, pos1, pos2).await;
+        let block_entities = read_block_entities(&world, pos1, pos2).await;
+
+        let mut cnt = 0;
+        let mut offset = Vector3::new(0, 0, 0);
+        for _ in 0..count {
+            offset = offset + step;
+            let (copy1, copy2) = (BlockPos(pos1.0 + offset), BlockPos(pos2.0 + offset));
+            let mask = crate::global_mask(player, Some((copy1, copy2))).await;
+            cnt += edit_blocks(
+                &world,
+                copy1,
+                copy2,
+                masked(mask.as_ref(), |block_pos, _| {
+                    let block_id = buffer.get(BlockPos(block_pos.0 - offset))?;
+                    (!(skip_air && is_air(block_id))).then_some(block_id)
+                }),
+            )
+            .await;
+
+            for (pos, nbt) in &block_entities {
+                place_block_entity(&world, BlockPos(pos.0 + offset), nbt.clone()).await;
+            }
+        }
+
+        if self.flags.has("-s") {
+            let mut selections = crate::selections().write().await;
+            if let Some(selection) = selections.get_mut(&entity.entity_uuid) {
+                selection.shift(offset);
+            }
+        }
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} blocks have been changed.",
+                cnt
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    with_flags(
+        CommandTree::new(NAMES, DESCRIPTION),
+        &FLAGS,
+        |flags| {
+            argument(ARG_DESC_COUNT, count_consumer())
+                .then(
+                    argument(ARG_DESC_DIRECTION, SimpleArgConsumer)
+                        .execute(StackExecuter { flags }),
+                )
+                .execute(StackExecuter { flags })
+        },
+        |flags| StackExecuter { flags },
+    )
+}
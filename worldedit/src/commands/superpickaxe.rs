@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::tools::superpickaxe::{Mode, set_super_pickaxe, toggle_super_pickaxe};
+use crate::utils::flags::{Flags, literal_with_flags};
+
+const NAMES: [&str; 2] = ["/superpickaxe", "/sp"];
+
+const DESCRIPTION: &str = "Toggle the super pickaxe or select its mode";
+
+const ARG_DESC_RANGE: &str = "The range of the super pickaxe";
+
+/// `-d`: broken blocks drop their items.
+const FLAGS: [&str; 1] = ["-d"];
+
+/// The largest range the area and recursive modes may be given.
+const MAX_RANGE: i32 = 10;
+
+#[derive(Clone, Copy)]
+enum ModeType {
+    Single,
+    Area,
+    Recursive,
+}
+
+struct ToggleExecuter;
+
+#[async_trait]
+impl CommandExecutor for ToggleExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let message = if toggle_super_pickaxe(player).await {
+            "Super pickaxe enabled. Left click with a pickaxe."
+        } else {
+            "Super pickaxe disabled."
+        };
+        sender.send_message(TextComponent::text(message)).await;
+
+        Ok(())
+    }
+}
+
+struct ModeExecuter {
+    mode_type: ModeType,
+    flags: Flags,
+}
+
+#[async_trait]
+impl CommandExecutor for ModeExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+        let mode = match self.mode_type {
+            ModeType::Single => Mode::Single,
+            ModeType::Area | ModeType::Recursive => {
+                let range = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_RANGE)?
+                {
+                    Ok(range) => range,
+                    Err(_) => {
+                        return Err(CommandError::GeneralCommandIssue(format!(
+                            "Range must be between 1 and {MAX_RANGE}."
+                        )));
+                    }
+                };
+                match self.mode_type {
+                    ModeType::Area => Mode::Area(range),
+                    _ => Mode::Recursive(range),
+                }
+            }
+        };
+
+        if !sender.has_permission(mode.permission()).await {
+            return Err(CommandError::PermissionDenied);
+        }
+
+        set_super_pickaxe(player, mode, self.flags.has("-d")).await;
+
+        let name = match mode {
+            Mode::Single => "Single block",
+            Mode::Area(_) => "Area",
+            Mode::Recursive(_) => "Recursive",
+        };
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} super pickaxe mode equipped. Left click with a pickaxe.",
+                name
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+fn range_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .max(MAX_RANGE)
+        .name(ARG_DESC_RANGE)
+}
+
+fn executer(mode_type: ModeType) -> impl Fn(Flags) -> ModeExecuter {
+    move |flags| ModeExecuter { mode_type, flags }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(literal_with_flags(
+            "single",
+            &FLAGS,
+            |_| None,
+            executer(ModeType::Single),
+        ))
+        .then(literal_with_flags(
+            "area",
+            &FLAGS,
+            |flags| {
+                Some(
+                    argument(ARG_DESC_RANGE, range_consumer())
+                        .execute(executer(ModeType::Area)(flags)),
+                )
+            },
+            executer(ModeType::Area),
+        ))
+        .then(literal_with_flags(
+            "recursive",
+            &FLAGS,
+            |flags| {
+                Some(
+                    argument(ARG_DESC_RANGE, range_consumer())
+                        .execute(executer(ModeType::Recursive)(flags)),
+                )
+            },
+            executer(ModeType::Recursive),
+        ))
+        .execute(ToggleExecuter)
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::entity::EntityBase;
+use pumpkin::server::Server;
+use pumpkin_data::Block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+
+use super::snow::{ARG_DESC_RADIUS, SurfaceChange, apply_changes, find_radius, radius_consumer};
+use crate::utils::block::block_of;
+use crate::utils::heightmap::read_surface_around;
+use crate::utils::region::bounds;
+
+const NAMES: [&str; 1] = ["/thaw"];
+
+const DESCRIPTION: &str = "Thaws the area";
+
+struct ThawExecuter;
+
+#[async_trait]
+impl CommandExecutor for ThawExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let radius = find_radius(args)?;
+        let origin = BlockPos(player.position().to_i32());
+
+        let world = player.world().await;
+
+        // Ice melts, snow layers disappear and the ground below stops being snowy.
+        let mut changes = HashMap::new();
+        for (pos, block_id) in read_surface_around(&world, origin, radius).await {
+            let block = block_of(block_id);
+            if block.id == Block::ICE.id {
+                changes.insert(pos, SurfaceChange::Set(Block::WATER.default_state.id));
+            } else if block.id == Block::SNOW.id {
+                changes.insert(pos, SurfaceChange::Set(Block::AIR.default_state.id));
+                changes.insert(
+                    BlockPos(pos.0 - Vector3::new(0, 1, 0)),
+                    SurfaceChange::Snowy("false"),
+                );
+            }
+        }
+
+        let mask = crate::global_mask(player, bounds(changes.keys())).await;
+        let cnt = apply_changes(&world, changes, mask.as_ref()).await;
+
+        sender
+            .send_message(TextComponent::text(format!("{} surfaces thawed.", cnt)))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_DESC_RADIUS, radius_consumer()).execute(ThawExecuter))
+        .execute(ThawExecuter)
+}
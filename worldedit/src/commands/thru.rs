@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::navigation::pass_through;
+
+const NAMES: [&str; 1] = ["thru"];
+
+const DESCRIPTION: &str = "Pass through the wall in front of you";
+
+struct ThruExecuter;
+
+#[async_trait]
+impl CommandExecutor for ThruExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        pass_through(player)
+            .await
+            .map_err(CommandError::GeneralCommandIssue)?;
+
+        sender.send_message(TextComponent::text("Whoosh!")).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).execute(ThruExecuter)
+}
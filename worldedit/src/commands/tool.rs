@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::Arg;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::args::simple::SimpleArgConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::{argument, literal};
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::tools::{MAX_SIZE, Tool, ToolKind, bind_tool};
+use crate::utils::pattern::find_pattern;
+use crate::utils::tree::TreeType;
+
+const NAMES: [&str; 1] = ["/tool"];
+
+const DESCRIPTION: &str = "Bind a tool to the item in your hand";
+
+const ARG_DESC_PATTERN: &str = "The pattern of blocks to set";
+const ARG_DESC_TYPE: &str = "The type of tree to place";
+const ARG_DESC_RANGE: &str = "The range of the flood fill";
+
+#[derive(Clone, Copy)]
+enum ToolType {
+    Info,
+    Replacer,
+    Tree,
+    FloodFill,
+    DelTree,
+}
+
+struct ToolExecuter(ToolType);
+
+#[async_trait]
+impl CommandExecutor for ToolExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let (name, tool) = match self.0 {
+            ToolType::Info => ("Info", Tool::new(ToolKind::Info, 0, None)),
+            ToolType::Replacer => {
+                let pattern = match find_pattern(
+                    args,
+                    ARG_DESC_PATTERN,
+                    &crate::pattern_context(player).await,
+                ) {
+                    Err(CommandError::InvalidConsumption(_)) => None,
+                    pattern => Some(pattern?),
+                };
+                ("Block replacer", Tool::new(ToolKind::Replacer, 0, pattern))
+            }
+            ToolType::Tree => {
+                let tree_type = match args.get(ARG_DESC_TYPE) {
+                    Some(Arg::Simple(tree_type)) => {
+                        TreeType::parse(tree_type).map_err(CommandError::GeneralCommandIssue)?
+                    }
+                    _ => Some(TreeType::Oak),
+                };
+                ("Tree", Tool::new(ToolKind::Tree(tree_type), 0, None))
+            }
+            ToolType::FloodFill => {
+                let pattern = find_pattern(
+                    args,
+                    ARG_DESC_PATTERN,
+                    &crate::pattern_context(player).await,
+                )?;
+                let range = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_RANGE)?
+                {
+                    Ok(range) => range,
+                    Err(_) => {
+                        return Err(CommandError::GeneralCommandIssue(format!(
+                            "Range must be between 1 and {MAX_SIZE}."
+                        )));
+                    }
+                };
+                (
+                    "Flood fill",
+                    Tool::new(ToolKind::FloodFill, range, Some(pattern)),
+                )
+            }
+            ToolType::DelTree => (
+                "Floating tree remover",
+                Tool::new(ToolKind::DelTree, 0, None),
+            ),
+        };
+
+        let item = bind_tool(player, tool).await?;
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "{} tool bound to {}.",
+                name, item.registry_key
+            )))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(literal("info").execute(ToolExecuter(ToolType::Info)))
+        .then(
+            literal("repl")
+                .then(
+                    argument(ARG_DESC_PATTERN, SimpleArgConsumer)
+                        .execute(ToolExecuter(ToolType::Replacer)),
+                )
+                .execute(ToolExecuter(ToolType::Replacer)),
+        )
+        .then(
+            literal("tree")
+                .then(
+                    argument(ARG_DESC_TYPE, SimpleArgConsumer)
+                        .execute(ToolExecuter(ToolType::Tree)),
+                )
+                .execute(ToolExecuter(ToolType::Tree)),
+        )
+        .then(
+            literal("floodfill").then(
+                argument(ARG_DESC_PATTERN, SimpleArgConsumer).then(
+                    argument(
+                        ARG_DESC_RANGE,
+                        BoundedNumArgumentConsumer::new()
+                            .min(1)
+                            .max(MAX_SIZE)
+                            .name(ARG_DESC_RANGE),
+                    )
+                    .execute(ToolExecuter(ToolType::FloodFill)),
+                ),
+            ),
+        )
+        .then(literal("deltree").execute(ToolExecuter(ToolType::DelTree)))
+}
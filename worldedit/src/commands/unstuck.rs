@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::navigation::{free_above, player_column, teleport};
+
+const NAMES: [&str; 2] = ["unstuck", "!"];
+
+const DESCRIPTION: &str = "Escape from being stuck inside a block";
+
+struct UnstuckExecuter;
+
+#[async_trait]
+impl CommandExecutor for UnstuckExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        _args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let (column, from) = player_column(player).await;
+        let Some(target) = free_above(&column.solid, from) else {
+            return Err(CommandError::GeneralCommandIssue(
+                "No free spot above you found.".to_string(),
+            ));
+        };
+        if target != from {
+            teleport(player, column.pos(target)).await;
+        }
+
+        sender
+            .send_message(TextComponent::text("There you go!"))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).execute(UnstuckExecuter)
+}
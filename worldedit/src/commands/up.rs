@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::CommandSender;
+use pumpkin::command::args::ConsumedArgs;
+use pumpkin::command::args::FindArg;
+use pumpkin::command::args::bounded_num::BoundedNumArgumentConsumer;
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::argument;
+use pumpkin::server::Server;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::navigation::{place_platform, player_column, teleport};
+
+const NAMES: [&str; 1] = ["up"];
+
+const DESCRIPTION: &str = "Go upwards some distance, placing glass below you";
+
+const ARG_DESC_DISTANCE: &str = "Distance to go upwards";
+
+struct UpExecuter;
+
+#[async_trait]
+impl CommandExecutor for UpExecuter {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let Some(player) = sender.as_player() else {
+            return Err(CommandError::PermissionDenied);
+        };
+
+        let distance = match BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_DESC_DISTANCE)? {
+            Ok(distance) => distance as usize,
+            Err(_) => {
+                return Err(CommandError::GeneralCommandIssue(
+                    "Distance must be 1 or greater.".to_string(),
+                ));
+            }
+        };
+
+        let (column, from) = player_column(player).await;
+        let target = from + distance;
+        if target + 1 >= column.solid.len() {
+            return Err(CommandError::GeneralCommandIssue(
+                "You would leave the world.".to_string(),
+            ));
+        }
+        // The player moves straight up, so nothing may be in the way of their head.
+        if column.solid[from..=target + 1].iter().any(|solid| *solid) {
+            return Err(CommandError::GeneralCommandIssue(
+                "You would hit something above you.".to_string(),
+            ));
+        }
+
+        let world = player.world().await;
+        place_platform(&world, column.pos(target - 1)).await;
+        teleport(player, column.pos(target)).await;
+
+        sender.send_message(TextComponent::text("Woosh!")).await;
+
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(
+            ARG_DESC_DISTANCE,
+            BoundedNumArgumentConsumer::new()
+                .min(1)
+                .name(ARG_DESC_DISTANCE),
+        )
+        .execute(UpExecuter),
+    )
+}
@@ -0,0 +1,45 @@
+use std::path::{Path, PathBuf};
+
+use common::config::{properties, read_or_create};
+
+const FILE_NAME: &str = "config.properties";
+
+const DEFAULT: &str = "\
+# Directory holding world backups for //snapshot and //restore, relative to the
+# server directory. Each backup is a world folder or a .zip/.tar.gz archive of one.
+snapshots.directory=backups
+";
+
+/// Settings read from `config.properties` in the plugin's data folder.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub snapshot_directory: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::parse(DEFAULT)
+    }
+}
+
+impl Config {
+    /// Parses `key=value` lines, ignoring blank lines, `#` comments and unknown keys.
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        let mut config = Self {
+            snapshot_directory: PathBuf::from("backups"),
+        };
+        for (key, value) in properties(content) {
+            match key {
+                "snapshots.directory" => config.snapshot_directory = PathBuf::from(value),
+                key => log::warn!("Unknown config key {key}"),
+            }
+        }
+        config
+    }
+
+    /// Loads the config from `data_folder`, writing the defaults if it doesn't exist.
+    pub fn load(data_folder: &Path) -> Self {
+        Self::parse(&read_or_create(data_folder, FILE_NAME, DEFAULT))
+    }
+}
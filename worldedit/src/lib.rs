@@ -1,12 +1,14 @@
 use std::{
     collections::HashMap,
+    path::Path,
     sync::{Arc, OnceLock},
 };
 
 use async_trait::async_trait;
+use config::Config;
 use pumpkin::{
     command::dispatcher::CommandError,
-    entity::EntityBase,
+    entity::{EntityBase, player::Player},
     plugin::{
         Context, EventHandler, EventPriority,
         player::player_interact_event::{InteractAction, PlayerInteractEvent},
@@ -20,10 +22,22 @@ use pumpkin_util::{
     text::TextComponent,
 };
 use tokio::sync::RwLock;
+use utils::clipboard::Clipboard;
+use utils::mask::Mask;
+use utils::navigation;
+use utils::pattern::PatternContext;
 
 pub mod utils;
 
 mod commands;
+mod config;
+mod tools;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+fn config() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
 
 type Selections = HashMap<uuid::Uuid, Selection>;
 
@@ -35,7 +49,7 @@ fn selections() -> &'static RwLock<Selections> {
 
 async fn fetch_selections(player_uuid: &uuid::Uuid) -> Result<(BlockPos, BlockPos), CommandError> {
     let selections = crate::selections().read().await;
-    if let Some(&selection) = selections.get(player_uuid) {
+    if let Some(selection) = selections.get(player_uuid) {
         if let Some((p1, p2)) = selection.get() {
             Ok((p1, p2))
         } else {
@@ -50,6 +64,74 @@ async fn fetch_selections(player_uuid: &uuid::Uuid) -> Result<(BlockPos, BlockPo
     }
 }
 
+type Clipboards = HashMap<uuid::Uuid, Arc<Clipboard>>;
+
+static CLIPBOARDS: OnceLock<RwLock<Clipboards>> = OnceLock::new();
+
+fn clipboards() -> &'static RwLock<Clipboards> {
+    CLIPBOARDS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+async fn fetch_clipboard(player_uuid: &uuid::Uuid) -> Result<Arc<Clipboard>, CommandError> {
+    crate::clipboards()
+        .read()
+        .await
+        .get(player_uuid)
+        .cloned()
+        .ok_or_else(|| {
+            CommandError::GeneralCommandIssue(
+                "Your clipboard is empty. Use //copy first.".to_string(),
+            )
+        })
+}
+
+type GlobalMasks = HashMap<uuid::Uuid, Mask>;
+
+static GLOBAL_MASKS: OnceLock<RwLock<GlobalMasks>> = OnceLock::new();
+
+fn global_masks() -> &'static RwLock<GlobalMasks> {
+    GLOBAL_MASKS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Prepares `mask` for testing the blocks between `pos1` and `pos2` (normalized) in
+/// the player's world.
+async fn prepare_mask(player: &Player, mask: &mut Mask, pos1: BlockPos, pos2: BlockPos) {
+    let selection = crate::selections()
+        .read()
+        .await
+        .get(&player.get_entity().entity_uuid)
+        .and_then(Selection::get);
+    mask.prepare(&player.world().await, selection, pos1, pos2)
+        .await;
+}
+
+/// Returns the mask set with `//gmask`, which every edit of the player honours,
+/// prepared for an edit of the blocks within `bounds`.
+async fn global_mask(player: &Player, bounds: Option<(BlockPos, BlockPos)>) -> Option<Mask> {
+    let mut mask = crate::global_masks()
+        .read()
+        .await
+        .get(&player.get_entity().entity_uuid)
+        .cloned()?;
+    if let Some((pos1, pos2)) = bounds {
+        prepare_mask(player, &mut mask, pos1, pos2).await;
+    }
+    Some(mask)
+}
+
+/// What the patterns the player gives may refer to: their clipboard and selection.
+async fn pattern_context(player: &Player) -> PatternContext {
+    let player_uuid = player.get_entity().entity_uuid;
+    PatternContext {
+        clipboard: crate::clipboards().read().await.get(&player_uuid).cloned(),
+        selection: crate::selections()
+            .read()
+            .await
+            .get(&player_uuid)
+            .and_then(Selection::get),
+    }
+}
+
 fn normalization_selection<T: PartialOrd>(pos1: &mut Vector3<T>, pos2: &mut Vector3<T>) {
     if pos1.x > pos2.x {
         std::mem::swap(&mut pos1.x, &mut pos2.x);
@@ -68,11 +150,17 @@ struct WandHandler;
 #[async_trait]
 impl EventHandler<PlayerInteractEvent> for WandHandler {
     async fn handle_blocking(&self, _server: &Arc<Server>, event: &mut PlayerInteractEvent) {
+        let item = event.item.lock().await.item;
+        if item == &Item::COMPASS {
+            navigate(event).await;
+            return;
+        }
+
         let Some(pos) = event.clicked_pos else {
             return;
         };
 
-        if event.item.lock().await.item != &Item::WOODEN_AXE {
+        if item != &Item::WOODEN_AXE {
             return;
         }
 
@@ -103,10 +191,45 @@ impl EventHandler<PlayerInteractEvent> for WandHandler {
     }
 }
 
+/// A left click with a compass jumps to the block in sight, a right click passes
+/// through the wall in front. Without the permission of the matching command, the
+/// click goes through untouched.
+async fn navigate(event: &mut PlayerInteractEvent) {
+    let left = matches!(
+        event.action,
+        InteractAction::LeftClickBlock | InteractAction::LeftClickAir
+    );
+    let permission = if left {
+        "worldedit:navigation.jumpto"
+    } else {
+        "worldedit:navigation.thru"
+    };
+    if !event.player.has_permission(permission).await {
+        return;
+    }
+
+    let result = if left {
+        navigation::jump_to(&event.player).await
+    } else {
+        navigation::pass_through(&event.player).await
+    };
+
+    event.cancelled = true;
+
+    if let Err(message) = result {
+        event
+            .player
+            .send_system_message(&TextComponent::text(message))
+            .await;
+    }
+}
+
 #[plugin_method]
 async fn on_load(&mut self, context: &Context) -> Result<(), String> {
     pumpkin::init_log!();
 
+    let _ = CONFIG.set(Config::load(Path::new(&context.get_data_folder())));
+
     log::debug!("Registering commands...");
     commands::register_permission(context).await;
     commands::register_commmand(context).await;
@@ -115,16 +238,44 @@ async fn on_load(&mut self, context: &Context) -> Result<(), String> {
     context
         .register_event(Arc::new(WandHandler), EventPriority::Lowest, true)
         .await;
+    context
+        .register_event(Arc::new(tools::ToolHandler), EventPriority::Lowest, true)
+        .await;
 
     Ok(())
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+/// The kind of region a [`Selection`] describes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// The cuboid spanned by `pos1` and `pos2`.
+    #[default]
+    Cuboid,
+    /// A list of vertices; `pos1` starts a new list and `pos2` appends to it.
+    /// Region commands operate on the bounding box of the vertices.
+    Convex,
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct Selection {
+    mode: SelectionMode,
     pos1: Option<BlockPos>,
     pos2: Option<BlockPos>,
+    vertices: Vec<BlockPos>,
 }
 impl Selection {
+    #[must_use]
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
     #[must_use]
     pub fn pos1(&self) -> Option<BlockPos> {
         self.pos1
@@ -133,20 +284,58 @@ impl Selection {
     pub fn pos2(&self) -> Option<BlockPos> {
         self.pos2
     }
+    /// The vertices of a convex selection, in the order they were added.
+    #[must_use]
+    pub fn vertices(&self) -> &[BlockPos] {
+        &self.vertices
+    }
 
     pub fn set_pos1(&mut self, pos: BlockPos) {
         self.pos1 = Some(pos);
+        if self.mode == SelectionMode::Convex {
+            self.vertices = vec![pos];
+        }
     }
     pub fn set_pos2(&mut self, pos: BlockPos) {
         self.pos2 = Some(pos);
+        if self.mode == SelectionMode::Convex && !self.vertices.contains(&pos) {
+            self.vertices.push(pos);
+        }
     }
 
+    /// Moves every point of the selection by `offset`.
+    pub fn shift(&mut self, offset: Vector3<i32>) {
+        self.pos1 = self.pos1.map(|pos| BlockPos(pos.0 + offset));
+        self.pos2 = self.pos2.map(|pos| BlockPos(pos.0 + offset));
+        for vertex in &mut self.vertices {
+            vertex.0 = vertex.0 + offset;
+        }
+    }
+
+    /// Returns two opposite corners of the selected region.
     #[must_use]
     pub fn get(&self) -> Option<(BlockPos, BlockPos)> {
-        if let (Some(pos1), Some(pos2)) = (self.pos1, self.pos2) {
-            Some((pos1, pos2))
-        } else {
-            None
+        match self.mode {
+            SelectionMode::Cuboid => {
+                if let (Some(pos1), Some(pos2)) = (self.pos1, self.pos2) {
+                    Some((pos1, pos2))
+                } else {
+                    None
+                }
+            }
+            SelectionMode::Convex => {
+                let (&first, rest) = self.vertices.split_first()?;
+                let (mut min, mut max) = (first, first);
+                for vertex in rest {
+                    min.0.x = min.0.x.min(vertex.0.x);
+                    min.0.y = min.0.y.min(vertex.0.y);
+                    min.0.z = min.0.z.min(vertex.0.z);
+                    max.0.x = max.0.x.max(vertex.0.x);
+                    max.0.y = max.0.y.max(vertex.0.y);
+                    max.0.z = max.0.z.max(vertex.0.z);
+                }
+                Some((min, max))
+            }
         }
     }
 }
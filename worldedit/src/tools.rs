@@ -0,0 +1,300 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock},
+};
+
+use async_trait::async_trait;
+use pumpkin::{
+    command::dispatcher::CommandError,
+    entity::{EntityBase, player::Player},
+    plugin::{
+        EventHandler,
+        player::player_interact_event::{InteractAction, PlayerInteractEvent},
+    },
+    server::Server,
+};
+use pumpkin_api_macros::with_runtime;
+use pumpkin_data::item::Item;
+use pumpkin_util::{math::position::BlockPos, text::TextComponent};
+use tokio::sync::RwLock;
+
+use crate::utils::block::state_string;
+use crate::utils::mask::Mask;
+use crate::utils::pattern::Pattern;
+use crate::utils::region::edit_positions;
+use crate::utils::trace::target_block;
+use crate::utils::tree::TreeType;
+
+pub mod brush;
+pub mod deltree;
+pub mod floodfill;
+pub mod info;
+pub mod superpickaxe;
+pub mod tree;
+
+use brush::Brush;
+use superpickaxe::use_super_pickaxe;
+
+/// How far tools reach by default, in blocks.
+pub const DEFAULT_RANGE: i32 = 300;
+
+/// The largest size a brush may be given.
+pub const MAX_SIZE: i32 = 50;
+
+/// A tool bound to an item, used by clicking with that item in hand.
+#[derive(Debug, Clone)]
+pub struct Tool {
+    pub kind: ToolKind,
+    /// Restricts the blocks the tool may change, set with `//mask`.
+    pub mask: Option<Mask>,
+    /// What the tool places, set with `//material`.
+    pub material: Option<Pattern>,
+    /// The radius of a brush, set with `//size`.
+    pub size: i32,
+    /// How far the tool reaches, set with `//range`.
+    pub range: i32,
+}
+
+#[derive(Debug, Clone)]
+pub enum ToolKind {
+    Brush(Brush),
+    /// Shows the state, light and biome of the clicked block.
+    Info,
+    /// A left click copies the clicked block into the material, a right click
+    /// replaces the clicked block with it.
+    Replacer,
+    /// Grows a tree on the clicked block, of a random type if `None`.
+    Tree(Option<TreeType>),
+    /// Fills the clicked block and the connected blocks of the same state.
+    FloodFill,
+    /// Removes floating trees.
+    DelTree,
+}
+
+impl Tool {
+    #[must_use]
+    pub fn new(kind: ToolKind, size: i32, material: Option<Pattern>) -> Self {
+        Self {
+            kind,
+            mask: None,
+            material,
+            size,
+            range: DEFAULT_RANGE,
+        }
+    }
+
+    /// Whether the tool's mask lets it change the block at `pos` in state `state_id`.
+    #[must_use]
+    pub fn allows(&self, pos: BlockPos, state_id: u16) -> bool {
+        self.mask
+            .as_ref()
+            .is_none_or(|mask| mask.test(pos, state_id))
+    }
+
+    /// A copy of the tool with its mask prepared for changing the blocks between `pos1`
+    /// and `pos2`.
+    pub async fn prepared(&self, player: &Player, pos1: BlockPos, pos2: BlockPos) -> Self {
+        let mut tool = self.clone();
+        if let Some(mask) = &mut tool.mask {
+            crate::prepare_mask(player, mask, pos1, pos2).await;
+        }
+        tool
+    }
+
+    /// The block a click acts on: the clicked block, or else the one in sight.
+    async fn target(&self, player: &Player, clicked: Option<BlockPos>) -> Result<BlockPos, String> {
+        match clicked {
+            Some(pos) => Ok(pos),
+            None => target_block(player, f64::from(self.range))
+                .await
+                .ok_or_else(|| "No block in sight!".to_string()),
+        }
+    }
+
+    /// Uses the tool with a left click on `clicked`. Returns `None` if the tool has no
+    /// left click action, so the click goes through.
+    async fn primary(
+        &self,
+        player: &Arc<Player>,
+        clicked: Option<BlockPos>,
+    ) -> Option<Result<(), String>> {
+        match &self.kind {
+            ToolKind::Replacer => {
+                let pos = clicked?;
+                let state_id = player.world().await.get_block_state_id(&pos).await;
+                let item = held_item(player).await;
+                if let Some(tool) = tools()
+                    .write()
+                    .await
+                    .get_mut(&player.get_entity().entity_uuid)
+                    .and_then(|tools| tools.get_mut(&item.id))
+                {
+                    tool.material = Some(Pattern::Block(state_id));
+                }
+                player
+                    .send_system_message(&TextComponent::text(format!(
+                        "Replacer tool switched to: {}",
+                        state_string(state_id)
+                    )))
+                    .await;
+                Some(Ok(()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Uses the tool with a right click, on `clicked` if a block was clicked.
+    async fn secondary(
+        &self,
+        player: &Arc<Player>,
+        clicked: Option<BlockPos>,
+    ) -> Result<(), String> {
+        match &self.kind {
+            ToolKind::Brush(brush) => brush.apply(self, player).await,
+            ToolKind::Info => info::show_info(player, self.target(player, clicked).await?).await,
+            ToolKind::Replacer => {
+                let target = self.target(player, clicked).await?;
+                let Some(material) = &self.material else {
+                    return Err("Left click a block first to pick the replacement.".to_string());
+                };
+                let tool = self.prepared(player, target, target).await;
+                let world = player.world().await;
+                edit_positions(&world, [target], |block_pos, block_id| {
+                    tool.allows(block_pos, block_id)
+                        .then(|| material.apply(block_pos, block_id))
+                })
+                .await;
+                Ok(())
+            }
+            ToolKind::Tree(tree_type) => {
+                tree::grow_tree(
+                    self,
+                    *tree_type,
+                    player,
+                    self.target(player, clicked).await?,
+                )
+                .await
+            }
+            ToolKind::FloodFill => {
+                floodfill::flood_fill_at(self, player, self.target(player, clicked).await?).await
+            }
+            ToolKind::DelTree => {
+                deltree::delete_tree(self, player, self.target(player, clicked).await?).await
+            }
+        }
+    }
+}
+
+type Tools = HashMap<uuid::Uuid, HashMap<u16, Tool>>;
+
+static TOOLS: OnceLock<RwLock<Tools>> = OnceLock::new();
+
+fn tools() -> &'static RwLock<Tools> {
+    TOOLS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Returns the item the player holds in their main hand.
+pub async fn held_item(player: &Player) -> &'static Item {
+    player.inventory().held_item().lock().await.item
+}
+
+/// Binds `tool` to the item the player holds.
+pub async fn bind_tool(player: &Player, tool: Tool) -> Result<&'static Item, CommandError> {
+    let item = held_item(player).await;
+    if item == &Item::AIR {
+        return Err(CommandError::GeneralCommandIssue(
+            "You can't bind tools to your hand, hold an item first.".to_string(),
+        ));
+    }
+    if item == &Item::WOODEN_AXE {
+        return Err(CommandError::GeneralCommandIssue(
+            "The wooden axe is already the selection wand.".to_string(),
+        ));
+    }
+
+    tools()
+        .write()
+        .await
+        .entry(player.get_entity().entity_uuid)
+        .or_default()
+        .insert(item.id, tool);
+    Ok(item)
+}
+
+/// Unbinds the tool from the item the player holds, returning whether there was one.
+pub async fn unbind_tool(player: &Player) -> bool {
+    let item = held_item(player).await;
+    tools()
+        .write()
+        .await
+        .get_mut(&player.get_entity().entity_uuid)
+        .and_then(|tools| tools.remove(&item.id))
+        .is_some()
+}
+
+/// Changes the tool bound to the item the player holds.
+pub async fn modify_tool<F>(player: &Player, f: F) -> Result<(), CommandError>
+where
+    F: FnOnce(&mut Tool),
+{
+    let item = held_item(player).await;
+    let mut tools = tools().write().await;
+    let Some(tool) = tools
+        .get_mut(&player.get_entity().entity_uuid)
+        .and_then(|tools| tools.get_mut(&item.id))
+    else {
+        return Err(CommandError::GeneralCommandIssue(
+            "No tool is bound to the item in your hand.".to_string(),
+        ));
+    };
+    f(tool);
+    Ok(())
+}
+
+pub struct ToolHandler;
+
+#[with_runtime(global)]
+#[async_trait]
+impl EventHandler<PlayerInteractEvent> for ToolHandler {
+    async fn handle_blocking(&self, _server: &Arc<Server>, event: &mut PlayerInteractEvent) {
+        let item = event.item.lock().await.item;
+        let player_uuid = event.player.get_entity().entity_uuid;
+        let mut tool = tools()
+            .read()
+            .await
+            .get(&player_uuid)
+            .and_then(|tools| tools.get(&item.id))
+            .cloned();
+        // Tools honour the global mask on top of their own.
+        let global_mask = crate::global_mask(&event.player, None).await;
+        if let Some(tool) = &mut tool {
+            tool.mask = Mask::intersect(tool.mask.take(), global_mask.clone());
+        }
+
+        let result = match (tool, event.action) {
+            (Some(tool), InteractAction::LeftClickBlock | InteractAction::LeftClickAir) => {
+                tool.primary(&event.player, event.clicked_pos).await
+            }
+            (Some(tool), InteractAction::RightClickBlock | InteractAction::RightClickAir) => {
+                Some(tool.secondary(&event.player, event.clicked_pos).await)
+            }
+            (None, InteractAction::LeftClickBlock) => match event.clicked_pos {
+                Some(pos) => use_super_pickaxe(&event.player, item, pos, global_mask).await,
+                None => None,
+            },
+            (None, _) => None,
+        };
+        let Some(result) = result else {
+            return;
+        };
+
+        event.cancelled = true;
+
+        if let Err(message) = result {
+            event
+                .player
+                .send_system_message(&TextComponent::text(message))
+                .await;
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use std::sync::Arc;
+
+use pumpkin::entity::EntityBase;
+use pumpkin::entity::player::Player;
+use pumpkin::world::World;
+use pumpkin_data::Block;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::Tool;
+use crate::utils::block::{block_of, is_air};
+use crate::utils::draw::{hollow, thicken};
+use crate::utils::entity::{EntityKind, entities_near, entity_name, remove_entity};
+use crate::utils::flood::read_around;
+use crate::utils::heightmap::smooth_terrain;
+use crate::utils::random::Random;
+use crate::utils::region::{NEIGHBOURS, edit_blocks, edit_positions, max_y, min_y, read_blocks};
+use crate::utils::trace::target_block;
+
+/// What a brush does around the block it is pointed at. The radius, material and
+/// mask are kept by the [`Tool`] so they can be changed after binding.
+#[derive(Debug, Clone)]
+pub enum Brush {
+    /// A ball of the material.
+    Sphere { hollow: bool },
+    /// A cylinder of the material, standing on the target block.
+    Cylinder { height: i32, hollow: bool },
+    /// Smooths the terrain like `//smooth`.
+    Smooth { iterations: i32 },
+    /// Lets blocks fall down until they rest on something. With `full_height`, the
+    /// whole column above the brush falls as well.
+    Gravity { full_height: bool },
+    /// Pastes the player's clipboard at the target block, as `//paste` would at that
+    /// position.
+    Clipboard { skip_air: bool },
+    /// Kills hostile mobs.
+    Butcher,
+    /// Puts out fire.
+    Extinguish,
+    /// Paints the surface with the material, sparser towards the edge. Higher
+    /// `decay` thins out the edge faster.
+    Splatter { decay: i32 },
+}
+
+impl Brush {
+    /// Applies the brush at the block the player is looking at.
+    pub async fn apply(&self, tool: &Tool, player: &Arc<Player>) -> Result<(), String> {
+        let Some(target) = target_block(player, f64::from(tool.range)).await else {
+            return Err("No block in sight!".to_string());
+        };
+        let world = player.world().await;
+        let radius = tool.size;
+
+        let (mut pos1, mut pos2) = cube(&world, target, radius);
+        match self {
+            Self::Cylinder { height, .. } => {
+                pos1.0.y = target.0.y;
+                pos2.0.y = target.0.y + height - 1;
+            }
+            Self::Gravity { full_height: true } => pos2.0.y = max_y(&world),
+            _ => {}
+        }
+        let tool = &tool.prepared(player, pos1, pos2).await;
+
+        match self {
+            Self::Sphere { hollow: is_hollow } => {
+                let mut shape = thicken(&[target], radius);
+                if *is_hollow {
+                    shape = hollow(&shape);
+                }
+                paint(tool, &world, shape).await?;
+            }
+            Self::Cylinder {
+                height,
+                hollow: is_hollow,
+            } => {
+                let in_disk = |dx: i32, dz: i32| dx * dx + dz * dz <= radius * radius;
+                let mut shape = HashSet::new();
+                for dx in -radius..=radius {
+                    for dz in -radius..=radius {
+                        if !in_disk(dx, dz) {
+                            continue;
+                        }
+                        let edge = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                            .iter()
+                            .any(|(ox, oz)| !in_disk(dx + ox, dz + oz));
+                        if *is_hollow && !edge {
+                            continue;
+                        }
+                        for dy in 0..*height {
+                            shape.insert(BlockPos(target.0 + Vector3::new(dx, dy, dz)));
+                        }
+                    }
+                }
+                paint(tool, &world, shape).await?;
+            }
+            Self::Smooth { iterations } => {
+                smooth_terrain(
+                    &world,
+                    pos1,
+                    pos2,
+                    *iterations,
+                    |block_pos, block_id| match &tool.mask {
+                        Some(mask) => mask.test(block_pos, block_id),
+                        None => !is_air(block_id),
+                    },
+                    tool.mask.as_ref(),
+                )
+                .await;
+            }
+            Self::Gravity { .. } => {
+                let buffer = read_blocks(&world, pos1, pos2).await;
+
+                // Per column, the blocks the mask allows from the bottom up, with all air
+                // moved to the top. Blocks the mask protects neither fall nor get covered.
+                let mut changes = HashMap::new();
+                for x in pos1.0.x..=pos2.0.x {
+                    for z in pos1.0.z..=pos2.0.z {
+                        let allowed: Vec<(BlockPos, u16)> = (pos1.0.y..=pos2.0.y)
+                            .filter_map(|y| {
+                                let block_pos = BlockPos(Vector3::new(x, y, z));
+                                let block_id = buffer.get(block_pos)?;
+                                tool.allows(block_pos, block_id)
+                                    .then_some((block_pos, block_id))
+                            })
+                            .collect();
+                        let fallen = allowed
+                            .iter()
+                            .map(|(_, block_id)| *block_id)
+                            .filter(|block_id| !is_air(*block_id))
+                            .chain(iter::repeat(0));
+                        for ((block_pos, block_id), new) in allowed.iter().zip(fallen) {
+                            if new != *block_id {
+                                changes.insert(*block_pos, new);
+                            }
+                        }
+                    }
+                }
+
+                edit_blocks(&world, pos1, pos2, |block_pos, _| {
+                    changes.get(&block_pos).copied()
+                })
+                .await;
+            }
+            Self::Clipboard { skip_air } => {
+                let clipboard = crate::clipboards()
+                    .read()
+                    .await
+                    .get(&player.get_entity().entity_uuid)
+                    .cloned()
+                    .ok_or("Your clipboard is empty. Use //copy first.")?;
+                let min = BlockPos(target.0 + clipboard.offset());
+                let size = clipboard.size();
+                let max = BlockPos(min.0 + Vector3::new(size.x - 1, size.y - 1, size.z - 1));
+                let tool = tool.prepared(player, min, max).await;
+                clipboard
+                    .paste(&world, min, *skip_air, false, tool.mask.as_ref())
+                    .await;
+            }
+            Self::Butcher => {
+                let center = Vector3::new(
+                    f64::from(target.0.x) + 0.5,
+                    f64::from(target.0.y) + 0.5,
+                    f64::from(target.0.z) + 0.5,
+                );
+                for entity in entities_near(&world, center, Some(f64::from(radius))).await {
+                    if EntityKind::of(entity_name(entity.as_ref())) == EntityKind::Hostile {
+                        remove_entity(entity.as_ref()).await;
+                    }
+                }
+            }
+            Self::Extinguish => {
+                edit_positions(&world, thicken(&[target], radius), |block_pos, block_id| {
+                    let block = block_of(block_id);
+                    let fire = block.id == Block::FIRE.id || block.id == Block::SOUL_FIRE.id;
+                    (fire && tool.allows(block_pos, block_id))
+                        .then_some(Block::AIR.default_state.id)
+                })
+                .await;
+            }
+            Self::Splatter { decay } => {
+                let buffer = read_around(&world, target, radius).await;
+                let mut random = Random::new();
+                let shape: HashSet<BlockPos> = thicken(&[target], radius)
+                    .into_iter()
+                    .filter(|block_pos| {
+                        let surface = buffer.get(*block_pos).is_some_and(|id| !is_air(id))
+                            && NEIGHBOURS.iter().any(|(dx, dy, dz)| {
+                                buffer
+                                    .get(BlockPos(block_pos.0 + Vector3::new(*dx, *dy, *dz)))
+                                    .is_some_and(is_air)
+                            });
+                        if !surface {
+                            return false;
+                        }
+                        let offset = block_pos.0 - target.0;
+                        let distance = f64::from(
+                            offset.x * offset.x + offset.y * offset.y + offset.z * offset.z,
+                        )
+                        .sqrt()
+                            / f64::from(radius.max(1));
+                        random.next_f64() * f64::from(*decay) * distance < 1.0
+                    })
+                    .collect();
+                paint(tool, &world, shape).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The cube of the given radius around `center`, clamped to the world's height.
+fn cube(world: &World, center: BlockPos, radius: i32) -> (BlockPos, BlockPos) {
+    let pos1 = Vector3::new(
+        center.0.x - radius,
+        (center.0.y - radius).max(min_y(world)),
+        center.0.z - radius,
+    );
+    let pos2 = Vector3::new(
+        center.0.x + radius,
+        (center.0.y + radius).min(max_y(world)),
+        center.0.z + radius,
+    );
+    (BlockPos(pos1), BlockPos(pos2))
+}
+
+/// Places the tool's material at every position of `shape` its mask allows.
+async fn paint(tool: &Tool, world: &World, shape: HashSet<BlockPos>) -> Result<(), String> {
+    let Some(material) = &tool.material else {
+        return Err("This brush has no material, set one with //material.".to_string());
+    };
+    edit_positions(world, shape, |block_pos, block_id| {
+        tool.allows(block_pos, block_id)
+            .then(|| material.apply(block_pos, block_id))
+    })
+    .await;
+    Ok(())
+}
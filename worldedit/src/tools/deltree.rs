@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use pumpkin::entity::player::Player;
+use pumpkin_data::Block;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::Tool;
+use crate::utils::block::{block_of, is_air};
+use crate::utils::flood::{flood_fill, read_around, within_radius};
+use crate::utils::region::{NEIGHBOURS, bounds, edit_positions};
+
+/// How far from the clicked block a tree may reach.
+const RADIUS: i32 = 32;
+
+/// Whether the state belongs to a tree: logs, wood, leaves and vines.
+fn is_tree(state_id: u16) -> bool {
+    let block = block_of(state_id);
+    block.name.ends_with("_log")
+        || block.name.ends_with("_wood")
+        || block.name.ends_with("_leaves")
+        || block.id == Block::VINE.id
+}
+
+/// Removes the tree `target` belongs to, as long as it doesn't rest on the ground.
+pub async fn delete_tree(
+    tool: &Tool,
+    player: &Arc<Player>,
+    target: BlockPos,
+) -> Result<(), String> {
+    let world = player.world().await;
+    let buffer = read_around(&world, target, RADIUS).await;
+    if !buffer.get(target).is_some_and(is_tree) {
+        return Err("That's not a tree.".to_string());
+    }
+
+    let positions = flood_fill(
+        [target],
+        |_| &NEIGHBOURS,
+        |pos| within_radius(target, pos, RADIUS) && buffer.get(pos).is_some_and(is_tree),
+    )?;
+
+    let grounded = positions.iter().any(|pos| {
+        buffer
+            .get(BlockPos(pos.0 + Vector3::new(0, -1, 0)))
+            .is_some_and(|below| !is_air(below) && !is_tree(below))
+    });
+    if grounded {
+        return Err("That's not a floating tree.".to_string());
+    }
+
+    let tool = match bounds(&positions) {
+        Some((pos1, pos2)) => tool.prepared(player, pos1, pos2).await,
+        None => return Ok(()),
+    };
+    edit_positions(&world, positions, |block_pos, block_id| {
+        tool.allows(block_pos, block_id)
+            .then_some(Block::AIR.default_state.id)
+    })
+    .await;
+    Ok(())
+}
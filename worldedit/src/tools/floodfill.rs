@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use pumpkin::entity::player::Player;
+use pumpkin_util::math::position::BlockPos;
+
+use super::Tool;
+use crate::utils::flood::{flood_fill, read_around, within_radius};
+use crate::utils::region::{NEIGHBOURS, bounds, edit_positions};
+
+/// Replaces `target` and every block of the same state connected to it, within the
+/// tool's size, with the tool's material.
+pub async fn flood_fill_at(
+    tool: &Tool,
+    player: &Arc<Player>,
+    target: BlockPos,
+) -> Result<(), String> {
+    let Some(material) = &tool.material else {
+        return Err("This tool has no material, set one with //material.".to_string());
+    };
+    let world = player.world().await;
+    let buffer = read_around(&world, target, tool.size).await;
+    let Some(initial) = buffer.get(target) else {
+        return Ok(());
+    };
+
+    let positions = flood_fill(
+        [target],
+        |_| &NEIGHBOURS,
+        |pos| within_radius(target, pos, tool.size) && buffer.get(pos) == Some(initial),
+    )?;
+
+    let tool = match bounds(&positions) {
+        Some((pos1, pos2)) => tool.prepared(player, pos1, pos2).await,
+        None => return Ok(()),
+    };
+    edit_positions(&world, positions, |block_pos, block_id| {
+        tool.allows(block_pos, block_id)
+            .then(|| material.apply(block_pos, block_id))
+    })
+    .await;
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use pumpkin::entity::player::Player;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+
+use crate::utils::biome::biome_name;
+use crate::utils::block::state_string;
+use crate::utils::region::{read_biomes, read_light};
+
+/// Tells the player the state, light levels and biome of `target`.
+pub async fn show_info(player: &Arc<Player>, target: BlockPos) -> Result<(), String> {
+    let world = player.world().await;
+    let state_id = world.get_block_state_id(&target).await;
+
+    let mut biome = None;
+    read_biomes(&world, target, target, |_, biome_id| biome = Some(biome_id)).await;
+
+    let mut lines = vec![format!("@{}: {}", target, state_string(state_id))];
+    if let Some((block, sky)) = read_light(&world, target).await {
+        lines.push(format!("Light: {} (block), {} (sky)", block, sky));
+    }
+    if let Some(biome) = biome {
+        lines.push(format!("Biome: {}", biome_name(biome)));
+    }
+    for line in lines {
+        player.send_system_message(&TextComponent::text(line)).await;
+    }
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use pumpkin::entity::EntityBase;
+use pumpkin::entity::player::Player;
+use pumpkin_data::Block;
+use pumpkin_data::item::Item;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+use tokio::sync::RwLock;
+
+use crate::utils::block::{block_of, is_breakable};
+use crate::utils::flood::{flood_fill, read_around, within_radius};
+use crate::utils::mask::Mask;
+use crate::utils::region::{NEIGHBOURS, break_blocks, edit_positions};
+
+/// Which blocks a super pickaxe breaks around the clicked one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Only the clicked block.
+    Single,
+    /// Every block of the clicked type in the cube of the given range.
+    Area(i32),
+    /// The blocks of the clicked type connected to it, within the given range.
+    Recursive(i32),
+}
+
+impl Mode {
+    /// The permission needed to select and to use this mode.
+    #[must_use]
+    pub fn permission(self) -> &'static str {
+        match self {
+            Self::Single => "worldedit:superpickaxe",
+            Self::Area(_) => "worldedit:superpickaxe.area",
+            Self::Recursive(_) => "worldedit:superpickaxe.recursive",
+        }
+    }
+}
+
+/// A player's super pickaxe, used by left clicking with any pickaxe.
+///
+/// There is no edit history yet, so broken blocks can't be undone.
+#[derive(Debug, Clone, Copy)]
+pub struct SuperPickaxe {
+    pub mode: Mode,
+    /// Whether broken blocks drop their items.
+    pub drops: bool,
+    pub enabled: bool,
+}
+
+type SuperPickaxes = HashMap<uuid::Uuid, SuperPickaxe>;
+
+static SUPER_PICKAXES: OnceLock<RwLock<SuperPickaxes>> = OnceLock::new();
+
+fn super_pickaxes() -> &'static RwLock<SuperPickaxes> {
+    SUPER_PICKAXES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Sets the player's super pickaxe and enables it.
+pub async fn set_super_pickaxe(player: &Player, mode: Mode, drops: bool) {
+    super_pickaxes().write().await.insert(
+        player.get_entity().entity_uuid,
+        SuperPickaxe {
+            mode,
+            drops,
+            enabled: true,
+        },
+    );
+}
+
+/// Toggles the player's super pickaxe, starting in single mode, and returns whether
+/// it is now enabled.
+pub async fn toggle_super_pickaxe(player: &Player) -> bool {
+    let mut super_pickaxes = super_pickaxes().write().await;
+    let super_pickaxe = super_pickaxes
+        .entry(player.get_entity().entity_uuid)
+        .or_insert(SuperPickaxe {
+            mode: Mode::Single,
+            drops: false,
+            enabled: false,
+        });
+    super_pickaxe.enabled = !super_pickaxe.enabled;
+    super_pickaxe.enabled
+}
+
+fn is_pickaxe(item: &Item) -> bool {
+    item.registry_key.ends_with("_pickaxe")
+}
+
+/// Breaks the blocks `mask` matches around `target` if the player holds a pickaxe
+/// with their super pickaxe enabled, and may still use its mode. Returns `None`
+/// otherwise, so the click goes through.
+pub async fn use_super_pickaxe(
+    player: &Arc<Player>,
+    item: &Item,
+    target: BlockPos,
+    mut mask: Option<Mask>,
+) -> Option<Result<(), String>> {
+    if !is_pickaxe(item) {
+        return None;
+    }
+    let super_pickaxe = super_pickaxes()
+        .read()
+        .await
+        .get(&player.get_entity().entity_uuid)
+        .copied()
+        .filter(|super_pickaxe| super_pickaxe.enabled)?;
+    // Permissions may have been revoked since the mode was selected.
+    if !player.has_permission(super_pickaxe.mode.permission()).await {
+        return None;
+    }
+
+    let range = match super_pickaxe.mode {
+        Mode::Single => 0,
+        Mode::Area(range) | Mode::Recursive(range) => range,
+    };
+    if let Some(mask) = &mut mask {
+        let range = Vector3::new(range, range, range);
+        crate::prepare_mask(
+            player,
+            mask,
+            BlockPos(target.0 - range),
+            BlockPos(target.0 + range),
+        )
+        .await;
+    }
+    let mask = mask.as_ref();
+
+    let world = player.world().await;
+    let initial = world.get_block_state_id(&target).await;
+    if !is_breakable(initial) || mask.is_some_and(|mask| !mask.test(target, initial)) {
+        return Some(Ok(()));
+    }
+    let same_type = |block_id: u16| block_of(block_id).id == block_of(initial).id;
+    let allowed = |pos: BlockPos, block_id: u16| {
+        same_type(block_id) && mask.is_none_or(|mask| mask.test(pos, block_id))
+    };
+
+    let positions = match super_pickaxe.mode {
+        Mode::Single => vec![target],
+        Mode::Area(range) => {
+            let buffer = read_around(&world, target, range).await;
+            let (min, max) = (buffer.min(), buffer.max());
+            let mut positions = Vec::new();
+            for x in min.x..=max.x {
+                for y in min.y..=max.y {
+                    for z in min.z..=max.z {
+                        let pos = BlockPos(Vector3::new(x, y, z));
+                        if buffer
+                            .get(pos)
+                            .is_some_and(|block_id| allowed(pos, block_id))
+                        {
+                            positions.push(pos);
+                        }
+                    }
+                }
+            }
+            positions
+        }
+        Mode::Recursive(range) => {
+            let buffer = read_around(&world, target, range).await;
+            match flood_fill(
+                [target],
+                |_| &NEIGHBOURS,
+                |pos| {
+                    within_radius(target, pos, range)
+                        && buffer
+                            .get(pos)
+                            .is_some_and(|block_id| allowed(pos, block_id))
+                },
+            ) {
+                Ok(positions) => positions,
+                Err(message) => return Some(Err(message)),
+            }
+        }
+    };
+
+    if super_pickaxe.drops {
+        break_blocks(&world, positions, player).await;
+    } else {
+        edit_positions(&world, positions, |_, block_id| {
+            is_breakable(block_id).then_some(Block::AIR.default_state.id)
+        })
+        .await;
+    }
+    Some(Ok(()))
+}
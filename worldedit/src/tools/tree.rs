@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use pumpkin::entity::player::Player;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::Tool;
+use crate::utils::random::Random;
+use crate::utils::region::bounds;
+use crate::utils::tree::{TreeBlocks, TreeType, add_tree, is_soil, place_trees};
+
+/// Grows a tree on `target`, of a random type if `tree_type` is `None`.
+pub async fn grow_tree(
+    tool: &Tool,
+    tree_type: Option<TreeType>,
+    player: &Arc<Player>,
+    target: BlockPos,
+) -> Result<(), String> {
+    let world = player.world().await;
+    if !is_soil(world.get_block_state_id(&target).await) {
+        return Err("A tree can't go there.".to_string());
+    }
+
+    let mut random = Random::new();
+    let tree_type = tree_type
+        .unwrap_or_else(|| TreeType::ALL[random.next_below(TreeType::ALL.len() as u64) as usize]);
+    let mut blocks = TreeBlocks::new();
+    add_tree(
+        &mut blocks,
+        tree_type,
+        BlockPos(target.0 + Vector3::new(0, 1, 0)),
+        &mut random,
+    );
+    if let Some((pos1, pos2)) = bounds(blocks.keys()) {
+        let tool = tool.prepared(player, pos1, pos2).await;
+        place_trees(&world, &blocks, tool.mask.as_ref()).await;
+    }
+    Ok(())
+}
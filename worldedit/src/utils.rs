@@ -1 +1,22 @@
-pub mod chunked_range;
+pub use common::{block, chunked_range, trace};
+
+pub mod anvil;
+pub mod biome;
+pub mod clipboard;
+pub mod direction;
+pub mod draw;
+pub mod entity;
+pub mod expression;
+pub mod flags;
+pub mod flood;
+pub mod heightmap;
+pub mod mask;
+pub mod navigation;
+pub mod noise;
+pub mod pattern;
+pub mod random;
+pub mod regen;
+pub mod region;
+pub mod snapshot;
+pub mod spline;
+pub mod tree;
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+
+use flate2::read::{GzDecoder, ZlibDecoder};
+use pumpkin_nbt::Nbt;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_nbt::deserializer::NbtReadHelper;
+use pumpkin_nbt::tag::NbtTag;
+
+/// Reads an uncompressed NBT document with a named root compound.
+///
+/// Backups are never loaded as a world, so their chunks are read as plain NBT
+/// rather than through the server's chunk loading.
+pub fn read_nbt(bytes: &[u8]) -> Result<NbtCompound, String> {
+    Nbt::read(&mut NbtReadHelper::new(Cursor::new(bytes)))
+        .map(|nbt| nbt.root_tag)
+        .map_err(|err| format!("Invalid chunk NBT: {err}"))
+}
+
+/// Returns the file name of the region containing the given chunk.
+#[must_use]
+pub fn region_file_name(chunk_x: i32, chunk_z: i32) -> String {
+    format!("r.{}.{}.mca", chunk_x >> 5, chunk_z >> 5)
+}
+
+/// Reads the NBT of a chunk from the contents of its region file, or `None` if the
+/// chunk was never generated.
+pub fn read_chunk(
+    region: &[u8],
+    chunk_x: i32,
+    chunk_z: i32,
+) -> Result<Option<NbtCompound>, String> {
+    let index = ((chunk_x & 31) + (chunk_z & 31) * 32) as usize * 4;
+    let Some(location) = region.get(index..index + 4) else {
+        return Err("Region file is truncated.".to_string());
+    };
+    let location = u32::from_be_bytes(location.try_into().unwrap());
+    let offset = (location >> 8) as usize * 4096;
+    if location == 0 {
+        return Ok(None);
+    }
+
+    let Some(header) = region.get(offset..offset + 5) else {
+        return Err("Region file is truncated.".to_string());
+    };
+    let length = u32::from_be_bytes(header[..4].try_into().unwrap()) as usize;
+    let compression = header[4];
+    let Some(data) = region.get(offset + 5..offset + 4 + length) else {
+        return Err("Region file is truncated.".to_string());
+    };
+
+    let mut bytes = Vec::new();
+    let result = match compression {
+        1 => GzDecoder::new(data).read_to_end(&mut bytes),
+        2 => ZlibDecoder::new(data).read_to_end(&mut bytes),
+        3 => {
+            bytes.extend_from_slice(data);
+            Ok(bytes.len())
+        }
+        _ => return Err(format!("Unsupported chunk compression {compression}.")),
+    };
+    result.map_err(|err| format!("Failed to decompress chunk: {err}"))?;
+    read_nbt(&bytes).map(Some)
+}
+
+/// Unpacks `count` values of `bits` bits each from longs, where values never span
+/// two longs (the format since 1.16).
+#[must_use]
+pub fn unpack(data: &[i64], bits: usize, count: usize) -> Vec<usize> {
+    if bits == 0 {
+        return vec![0; count];
+    }
+    let per_long = 64 / bits;
+    let mask = (1u64 << bits) - 1;
+    (0..count)
+        .map(|i| {
+            let long = data.get(i / per_long).copied().unwrap_or(0) as u64;
+            ((long >> ((i % per_long) * bits)) & mask) as usize
+        })
+        .collect()
+}
+
+/// Number of bits needed to index a palette, but at least `min` for non-trivial ones.
+fn palette_bits(len: usize, min: usize) -> usize {
+    if len <= 1 {
+        return 0;
+    }
+    ((usize::BITS - (len - 1).leading_zeros()) as usize).max(min)
+}
+
+/// The blocks, biomes and block entities of a chunk read from a backup, as server
+/// state and biome ids.
+pub struct DecodedChunk {
+    /// Sections by their Y index, with 4096 states (y, z, x order) and 64 biomes.
+    /// Entries the server doesn't know are `None`.
+    sections: HashMap<i32, (Vec<Option<u16>>, Vec<Option<u8>>)>,
+    /// Block entities by their absolute coordinates.
+    block_entities: HashMap<(i32, i32, i32), NbtCompound>,
+}
+
+impl DecodedChunk {
+    /// Decodes the sections of a chunk. `block_state` resolves a block name with its
+    /// properties and `biome` a biome name; unknown ones are left out.
+    pub fn decode<B, M>(chunk: &NbtCompound, block_state: B, biome: M) -> Result<Self, String>
+    where
+        B: Fn(&str, &[(String, String)]) -> Option<u16>,
+        M: Fn(&str) -> Option<u8>,
+    {
+        // Chunks before 1.18 nest everything in a "Level" compound.
+        let sections = chunk
+            .get_list("sections")
+            .or_else(|| chunk.get_compound("Level")?.get_list("sections"))
+            .ok_or("Chunk has no sections, it may be from before 1.18.")?;
+
+        let mut decoded = HashMap::new();
+        for section in sections.iter().filter_map(NbtTag::extract_compound) {
+            let Some(y) = section.get_byte("Y") else {
+                continue;
+            };
+            let Some(states) = section.get_compound("block_states") else {
+                continue;
+            };
+            let palette: Vec<Option<u16>> = states
+                .get_list("palette")
+                .map(|palette| palette.iter().filter_map(NbtTag::extract_compound))
+                .into_iter()
+                .flatten()
+                .map(|entry| {
+                    let name = entry.get_string("Name").map_or("", |name| &name[..]);
+                    let name = name.strip_prefix("minecraft:").unwrap_or(name);
+                    let properties: Vec<_> = entry
+                        .get_compound("Properties")
+                        .map(|properties| {
+                            properties
+                                .child_tags
+                                .iter()
+                                .filter_map(|(key, value)| {
+                                    Some((key.clone(), value.extract_string()?.to_string()))
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    block_state(name, &properties)
+                })
+                .collect();
+            let data = states
+                .get_long_array("data")
+                .map_or(&[][..], |data| &data[..]);
+            let blocks = unpack(data, palette_bits(palette.len(), 4), 4096)
+                .into_iter()
+                .map(|index| palette.get(index).copied().flatten())
+                .collect();
+
+            let biomes = section.get_compound("biomes");
+            let biome_palette: Vec<Option<u8>> = biomes
+                .and_then(|biomes| biomes.get_list("palette"))
+                .map(|palette| palette.iter().filter_map(NbtTag::extract_string))
+                .into_iter()
+                .flatten()
+                .map(|name| biome(name.strip_prefix("minecraft:").unwrap_or(name)))
+                .collect();
+            let biome_data = biomes
+                .and_then(|biomes| biomes.get_long_array("data"))
+                .map_or(&[][..], |data| &data[..]);
+            let biomes = unpack(biome_data, palette_bits(biome_palette.len(), 1), 64)
+                .into_iter()
+                .map(|index| biome_palette.get(index).copied().flatten())
+                .collect();
+
+            decoded.insert(i32::from(y), (blocks, biomes));
+        }
+
+        let block_entities = chunk
+            .get_list("block_entities")
+            .map(|block_entities| block_entities.iter().filter_map(NbtTag::extract_compound))
+            .into_iter()
+            .flatten()
+            .filter_map(|nbt| {
+                let pos = (nbt.get_int("x")?, nbt.get_int("y")?, nbt.get_int("z")?);
+                Some((pos, nbt.clone()))
+            })
+            .collect();
+        Ok(Self {
+            sections: decoded,
+            block_entities,
+        })
+    }
+
+    /// Returns the state at the given coordinates, where `x` and `z` are taken modulo
+    /// 16, or `None` if the section is missing or the block unknown.
+    #[must_use]
+    pub fn block(&self, x: i32, y: i32, z: i32) -> Option<u16> {
+        let (blocks, _) = self.sections.get(&(y >> 4))?;
+        blocks
+            .get((((y & 15) * 16 + (z & 15)) * 16 + (x & 15)) as usize)
+            .copied()
+            .flatten()
+    }
+
+    /// Returns the biome of the cell containing the given coordinates.
+    #[must_use]
+    pub fn biome(&self, x: i32, y: i32, z: i32) -> Option<u8> {
+        let (_, biomes) = self.sections.get(&(y >> 4))?;
+        biomes
+            .get(((((y & 15) >> 2) * 4 + ((z & 15) >> 2)) * 4 + ((x & 15) >> 2)) as usize)
+            .copied()
+            .flatten()
+    }
+
+    /// Returns the NBT of the block entity at the given absolute coordinates.
+    #[must_use]
+    pub fn block_entity(&self, x: i32, y: i32, z: i32) -> Option<&NbtCompound> {
+        self.block_entities.get(&(x, y, z))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodedChunk, read_chunk, unpack};
+
+    fn named(tag_type: u8, name: &str) -> Vec<u8> {
+        let mut bytes = vec![tag_type];
+        bytes.extend((name.len() as u16).to_be_bytes());
+        bytes.extend(name.as_bytes());
+        bytes
+    }
+
+    fn string(name: &str, value: &str) -> Vec<u8> {
+        let mut bytes = named(8, name);
+        bytes.extend((value.len() as u16).to_be_bytes());
+        bytes.extend(value.as_bytes());
+        bytes
+    }
+
+    /// A chunk with a single section at Y = -1 holding stone at the origin and air
+    /// elsewhere, all in the plains biome, and a chest at the origin.
+    fn chunk_nbt() -> Vec<u8> {
+        let mut bytes = named(10, "");
+        bytes.extend(named(9, "sections"));
+        bytes.push(10);
+        bytes.extend(1i32.to_be_bytes());
+        bytes.extend(named(1, "Y"));
+        bytes.push(0xFF);
+        bytes.extend(named(10, "block_states"));
+        bytes.extend(named(9, "palette"));
+        bytes.push(10);
+        bytes.extend(2i32.to_be_bytes());
+        bytes.extend(string("Name", "minecraft:air"));
+        bytes.push(0);
+        bytes.extend(string("Name", "minecraft:stone"));
+        bytes.push(0);
+        bytes.extend(named(12, "data"));
+        bytes.extend(256i32.to_be_bytes());
+        bytes.extend(1i64.to_be_bytes());
+        bytes.extend([0; 255 * 8]);
+        bytes.push(0);
+        bytes.extend(named(10, "biomes"));
+        bytes.extend(named(9, "palette"));
+        bytes.push(8);
+        bytes.extend(1i32.to_be_bytes());
+        bytes.extend(6u16.to_be_bytes());
+        bytes.extend(b"plains");
+        bytes.push(0);
+        bytes.push(0);
+        bytes.extend(named(9, "block_entities"));
+        bytes.push(10);
+        bytes.extend(1i32.to_be_bytes());
+        bytes.extend(string("id", "minecraft:chest"));
+        for (name, value) in [("x", 16i32), ("y", -16), ("z", 32)] {
+            bytes.extend(named(3, name));
+            bytes.extend(value.to_be_bytes());
+        }
+        bytes.push(0);
+        bytes.push(0);
+        bytes
+    }
+
+    #[test]
+    fn test_unpack() {
+        let data = [0b0011_0010_0001_i64, 0b0100];
+        assert_eq!(unpack(&data, 4, 3), vec![1, 2, 3]);
+        // Five 12-bit values fit into a long; the sixth starts the next one.
+        assert_eq!(unpack(&data, 12, 6)[5], 4);
+    }
+
+    #[test]
+    fn test_read_chunk() {
+        let nbt = chunk_nbt();
+        let mut region = vec![0; 8192];
+        // Chunk (1, 2) lives at index 1 + 2 * 32 and is stored uncompressed.
+        let index = (1 + 2 * 32) * 4;
+        region[index..index + 4].copy_from_slice(&((2u32 << 8) | 1).to_be_bytes());
+        region.extend(((nbt.len() + 1) as u32).to_be_bytes());
+        region.push(3);
+        region.extend(&nbt);
+
+        assert_eq!(read_chunk(&region, 0, 0), Ok(None));
+        let chunk = read_chunk(&region, 33, -30).unwrap().unwrap();
+        assert!(chunk.get_list("sections").is_some());
+
+        let decoded = DecodedChunk::decode(
+            &chunk,
+            |name, _| (name == "stone").then_some(1),
+            |name| (name == "plains").then_some(7),
+        )
+        .unwrap();
+        assert_eq!(decoded.block(16, -16, 32), Some(1));
+        // Air is unknown here, so it must not be restored as anything.
+        assert_eq!(decoded.block(17, -16, 32), None);
+        assert_eq!(decoded.block(0, 0, 0), None);
+        assert_eq!(decoded.biome(3, -1, 15), Some(7));
+        let chest = decoded.block_entity(16, -16, 32).unwrap();
+        assert_eq!(
+            chest.get_string("id").map(|id| &id[..]),
+            Some("minecraft:chest")
+        );
+        assert!(decoded.block_entity(17, -16, 32).is_none());
+    }
+}
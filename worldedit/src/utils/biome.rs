@@ -0,0 +1,16 @@
+use pumpkin_data::chunk::Biome;
+
+/// Looks up a biome by its registry name, with or without the `minecraft:` namespace.
+pub fn parse_biome(name: &str) -> Result<&'static Biome, String> {
+    let key = name.strip_prefix("minecraft:").unwrap_or(name);
+    Biome::from_name(key).ok_or_else(|| format!("Unknown biome '{name}'."))
+}
+
+/// Returns the registry name of a biome id, for display.
+#[must_use]
+pub fn biome_name(id: u8) -> String {
+    Biome::from_id(id).map_or_else(
+        || format!("unknown ({id})"),
+        |biome| format!("minecraft:{}", biome.registry_id),
+    )
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use pumpkin::world::World;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::block::is_air;
+use super::entity::{entity_name, spawn_entity, world_entities, write_entity};
+use super::mask::{Mask, masked};
+use super::region::{
+    BlockBuffer, edit_blocks, place_block_entity, read_block_entities, read_blocks,
+};
+
+/// A copied region, stored relative to the block the player stood on when copying.
+///
+/// There is no schematic support yet, so a clipboard only lives in memory.
+pub struct Clipboard {
+    /// Offset from the copy origin to the minimum corner of the copied region.
+    offset: Vector3<i32>,
+    blocks: BlockBuffer,
+    /// Block entities, relative to the minimum corner.
+    block_entities: Vec<(Vector3<i32>, NbtCompound)>,
+    /// Entity type names and states, positioned relative to the minimum corner.
+    entities: Vec<(&'static str, Vector3<f64>, NbtCompound)>,
+}
+
+impl Clipboard {
+    /// Copies the cuboid spanned by `pos1` and `pos2` (which must be normalized),
+    /// relative to `origin`. Entities are only copied if `with_entities` is set.
+    pub async fn copy(
+        world: &World,
+        pos1: BlockPos,
+        pos2: BlockPos,
+        origin: BlockPos,
+        with_entities: bool,
+    ) -> Self {
+        let min = pos1.0;
+        let blocks = read_blocks(world, pos1, pos2).await;
+        let block_entities = read_block_entities(world, pos1, pos2)
+            .await
+            .into_iter()
+            .map(|(pos, nbt)| (pos.0 - min, nbt))
+            .collect();
+
+        let mut entities = Vec::new();
+        if with_entities {
+            for entity in world_entities(world).await {
+                let pos = entity.get_entity().pos.load();
+                let inside = (f64::from(pos1.0.x)..f64::from(pos2.0.x + 1)).contains(&pos.x)
+                    && (f64::from(pos1.0.y)..f64::from(pos2.0.y + 1)).contains(&pos.y)
+                    && (f64::from(pos1.0.z)..f64::from(pos2.0.z + 1)).contains(&pos.z);
+                if inside {
+                    let relative = Vector3::new(
+                        pos.x - f64::from(min.x),
+                        pos.y - f64::from(min.y),
+                        pos.z - f64::from(min.z),
+                    );
+                    let nbt = write_entity(entity.as_ref()).await;
+                    entities.push((entity_name(entity.as_ref()), relative, nbt));
+                }
+            }
+        }
+
+        Self {
+            offset: min - origin.0,
+            blocks,
+            block_entities,
+            entities,
+        }
+    }
+
+    /// The size of the copied region.
+    #[must_use]
+    pub fn size(&self) -> Vector3<i32> {
+        self.blocks.size()
+    }
+
+    /// Offset from the copy origin to the minimum corner of the copied region.
+    #[must_use]
+    pub fn offset(&self) -> Vector3<i32> {
+        self.offset
+    }
+
+    /// The minimum corner of the region the clipboard was copied from.
+    #[must_use]
+    pub fn source(&self) -> BlockPos {
+        BlockPos(self.blocks.min())
+    }
+
+    #[must_use]
+    pub fn block_count(&self) -> usize {
+        self.blocks.len()
+    }
+
+    #[must_use]
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns the copied state at `relative`, an offset from the minimum corner.
+    #[must_use]
+    pub fn get(&self, relative: Vector3<i32>) -> Option<u16> {
+        self.blocks.get(BlockPos(self.blocks.min() + relative))
+    }
+
+    /// Pastes the clipboard with its minimum corner at `min`, over the blocks `mask`
+    /// matches, and returns the number of changed blocks.
+    pub async fn paste(
+        &self,
+        world: &Arc<World>,
+        min: BlockPos,
+        skip_air: bool,
+        with_entities: bool,
+        mask: Option<&Mask>,
+    ) -> usize {
+        let size = self.size();
+        let max = BlockPos(min.0 + Vector3::new(size.x - 1, size.y - 1, size.z - 1));
+        let cnt = edit_blocks(
+            world,
+            min,
+            max,
+            masked(mask, |block_pos, _| {
+                let block_id = self.get(block_pos.0 - min.0)?;
+                (!(skip_air && is_air(block_id))).then_some(block_id)
+            }),
+        )
+        .await;
+
+        for (relative, nbt) in &self.block_entities {
+            place_block_entity(world, BlockPos(min.0 + *relative), nbt.clone()).await;
+        }
+
+        if with_entities {
+            for (name, relative, nbt) in &self.entities {
+                let pos = Vector3::new(
+                    f64::from(min.0.x) + relative.x,
+                    f64::from(min.0.y) + relative.y,
+                    f64::from(min.0.z) + relative.z,
+                );
+                spawn_entity(world, name, pos, nbt).await;
+            }
+        }
+
+        cnt
+    }
+}
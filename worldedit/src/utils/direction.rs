@@ -0,0 +1,48 @@
+use pumpkin_util::math::vector3::Vector3;
+
+/// Returns the axis-aligned direction a player with the given rotation is looking at.
+///
+/// Looking steeply up or down counts as up or down, anything else is snapped to the
+/// nearest horizontal direction.
+#[must_use]
+pub fn facing(yaw: f32, pitch: f32) -> Vector3<i32> {
+    if pitch > 67.5 {
+        return Vector3::new(0, -1, 0);
+    }
+    if pitch < -67.5 {
+        return Vector3::new(0, 1, 0);
+    }
+    horizontal_facing(yaw)
+}
+
+/// Returns the horizontal direction a player with the given yaw is looking at.
+#[must_use]
+pub fn horizontal_facing(yaw: f32) -> Vector3<i32> {
+    // A yaw of 0 faces south (+z) and angles grow clockwise, seen from above.
+    match ((yaw.rem_euclid(360.0) + 45.0) / 90.0) as i32 % 4 {
+        0 => Vector3::new(0, 0, 1),
+        1 => Vector3::new(-1, 0, 0),
+        2 => Vector3::new(0, 0, -1),
+        _ => Vector3::new(1, 0, 0),
+    }
+}
+
+/// Parses a direction argument such as `north`, `u` or `me` into a unit vector.
+/// Relative directions (`me`, `back`, `left`, `right`) use the player's rotation.
+pub fn parse_direction(input: &str, yaw: f32, pitch: f32) -> Result<Vector3<i32>, String> {
+    let forward = facing(yaw, pitch);
+    let horizontal = horizontal_facing(yaw);
+    Ok(match input.to_ascii_lowercase().as_str() {
+        "north" | "n" => Vector3::new(0, 0, -1),
+        "south" | "s" => Vector3::new(0, 0, 1),
+        "east" | "e" => Vector3::new(1, 0, 0),
+        "west" | "w" => Vector3::new(-1, 0, 0),
+        "up" | "u" => Vector3::new(0, 1, 0),
+        "down" | "d" => Vector3::new(0, -1, 0),
+        "me" | "forward" | "f" => forward,
+        "back" | "b" => Vector3::new(-forward.x, -forward.y, -forward.z),
+        "left" | "l" => Vector3::new(horizontal.z, 0, -horizontal.x),
+        "right" | "r" => Vector3::new(-horizontal.z, 0, horizontal.x),
+        _ => return Err(format!("Unknown direction '{input}'.")),
+    })
+}
@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+pub use common::draw::line;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::region::NEIGHBOURS;
+
+/// The largest radius lines and curves may be thickened by, as every point becomes a
+/// ball.
+pub const MAX_THICKNESS: i32 = 10;
+
+/// Replaces every point with a ball of the given radius.
+#[must_use]
+pub fn thicken(points: &[BlockPos], radius: i32) -> HashSet<BlockPos> {
+    let mut result = HashSet::new();
+    for point in points {
+        for dx in -radius..=radius {
+            for dy in -radius..=radius {
+                for dz in -radius..=radius {
+                    if dx * dx + dy * dy + dz * dz <= radius * radius {
+                        result.insert(BlockPos(point.0 + Vector3::new(dx, dy, dz)));
+                    }
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Keeps only the blocks of `shape` that have a face-adjacent neighbour outside of it.
+#[must_use]
+pub fn hollow(shape: &HashSet<BlockPos>) -> HashSet<BlockPos> {
+    shape
+        .iter()
+        .filter(|pos| {
+            NEIGHBOURS
+                .iter()
+                .any(|(dx, dy, dz)| !shape.contains(&BlockPos(pos.0 + Vector3::new(*dx, *dy, *dz))))
+        })
+        .copied()
+        .collect()
+}
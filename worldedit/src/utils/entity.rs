@@ -0,0 +1,251 @@
+use std::sync::Arc;
+
+use pumpkin::entity::r#type::from_type;
+use pumpkin::entity::{EntityBase, NBTStorage};
+use pumpkin::world::World;
+use pumpkin_data::entity::EntityType;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::math::vector3::Vector3;
+
+/// A rough grouping of entity types, used to decide what `//butcher` and `//remove`
+/// may touch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityKind {
+    Hostile,
+    /// Tameable animals. Whether one is actually tamed is not checked, so they are
+    /// only killed when pets are asked for.
+    Pet,
+    Npc,
+    Golem,
+    Animal,
+    Ambient,
+    Item,
+    Projectile,
+    Experience,
+    Painting,
+    ItemFrame,
+    Boat,
+    Minecart,
+    Tnt,
+    ArmorStand,
+    /// Everything else, like players, markers and displays. Types missing from the
+    /// lists below end up here, so they are never killed by mistake.
+    Other,
+}
+
+const HOSTILES: [&str; 40] = [
+    "zombie",
+    "husk",
+    "drowned",
+    "zombie_villager",
+    "zombified_piglin",
+    "skeleton",
+    "stray",
+    "bogged",
+    "wither_skeleton",
+    "creeper",
+    "spider",
+    "cave_spider",
+    "enderman",
+    "endermite",
+    "silverfish",
+    "slime",
+    "magma_cube",
+    "ghast",
+    "blaze",
+    "witch",
+    "pillager",
+    "vindicator",
+    "evoker",
+    "illusioner",
+    "ravager",
+    "vex",
+    "phantom",
+    "guardian",
+    "elder_guardian",
+    "shulker",
+    "hoglin",
+    "zoglin",
+    "piglin",
+    "piglin_brute",
+    "warden",
+    "breeze",
+    "creaking",
+    "wither",
+    "ender_dragon",
+    "giant",
+];
+const PETS: [&str; 12] = [
+    "wolf",
+    "cat",
+    "parrot",
+    "horse",
+    "donkey",
+    "mule",
+    "llama",
+    "trader_llama",
+    "camel",
+    "skeleton_horse",
+    "zombie_horse",
+    "allay",
+];
+const NPCS: [&str; 2] = ["villager", "wandering_trader"];
+const GOLEMS: [&str; 3] = ["iron_golem", "snow_golem", "copper_golem"];
+const ANIMALS: [&str; 27] = [
+    "pig",
+    "cow",
+    "sheep",
+    "chicken",
+    "rabbit",
+    "mooshroom",
+    "ocelot",
+    "fox",
+    "panda",
+    "polar_bear",
+    "goat",
+    "bee",
+    "turtle",
+    "frog",
+    "tadpole",
+    "axolotl",
+    "sniffer",
+    "armadillo",
+    "strider",
+    "dolphin",
+    "squid",
+    "glow_squid",
+    "cod",
+    "salmon",
+    "pufferfish",
+    "tropical_fish",
+    "happy_ghast",
+];
+const AMBIENT: [&str; 1] = ["bat"];
+const PROJECTILES: [&str; 19] = [
+    "arrow",
+    "spectral_arrow",
+    "trident",
+    "snowball",
+    "egg",
+    "ender_pearl",
+    "experience_bottle",
+    "potion",
+    "splash_potion",
+    "lingering_potion",
+    "fireball",
+    "small_fireball",
+    "dragon_fireball",
+    "wither_skull",
+    "shulker_bullet",
+    "llama_spit",
+    "firework_rocket",
+    "wind_charge",
+    "breeze_wind_charge",
+];
+
+impl EntityKind {
+    /// Classifies an entity type by its registry name, without namespace.
+    #[must_use]
+    pub fn of(name: &str) -> Self {
+        match name {
+            "item" => Self::Item,
+            "experience_orb" => Self::Experience,
+            "painting" => Self::Painting,
+            "item_frame" | "glow_item_frame" => Self::ItemFrame,
+            "tnt" => Self::Tnt,
+            "armor_stand" => Self::ArmorStand,
+            _ if name.ends_with("minecart") => Self::Minecart,
+            _ if name.ends_with("boat") || name.ends_with("raft") => Self::Boat,
+            _ if HOSTILES.contains(&name) => Self::Hostile,
+            _ if PETS.contains(&name) => Self::Pet,
+            _ if NPCS.contains(&name) => Self::Npc,
+            _ if GOLEMS.contains(&name) => Self::Golem,
+            _ if ANIMALS.contains(&name) => Self::Animal,
+            _ if AMBIENT.contains(&name) => Self::Ambient,
+            _ if PROJECTILES.contains(&name) => Self::Projectile,
+            _ => Self::Other,
+        }
+    }
+
+    /// Whether this kind is a mob, as opposed to items, vehicles and decorations.
+    #[must_use]
+    pub fn is_mob(self) -> bool {
+        matches!(
+            self,
+            Self::Hostile | Self::Pet | Self::Npc | Self::Golem | Self::Animal | Self::Ambient
+        )
+    }
+}
+
+/// Returns the registry name of an entity's type, without namespace.
+#[must_use]
+pub fn entity_name(entity: &dyn EntityBase) -> &'static str {
+    entity.get_entity().entity_type.resource_name
+}
+
+/// Returns all entities of the world, except players.
+pub async fn world_entities(world: &World) -> Vec<Arc<dyn EntityBase>> {
+    world.entities.read().await.values().cloned().collect()
+}
+
+/// Returns the entities within `radius` of `center`, or all of them if `radius` is
+/// `None`.
+pub async fn entities_near(
+    world: &World,
+    center: Vector3<f64>,
+    radius: Option<f64>,
+) -> Vec<Arc<dyn EntityBase>> {
+    let mut entities = world_entities(world).await;
+    if let Some(radius) = radius {
+        entities.retain(|entity| {
+            let pos = entity.get_entity().pos.load();
+            let (dx, dy, dz) = (pos.x - center.x, pos.y - center.y, pos.z - center.z);
+            dx * dx + dy * dy + dz * dz <= radius * radius
+        });
+    }
+    entities
+}
+
+/// Removes an entity from its world without dropping anything.
+pub async fn remove_entity(entity: &dyn EntityBase) {
+    entity.get_entity().remove().await;
+}
+
+/// Serializes an entity so it can be spawned again with [`spawn_entity`].
+pub async fn write_entity(entity: &dyn EntityBase) -> NbtCompound {
+    let mut nbt = NbtCompound::new();
+    entity.get_entity().write_nbt(&mut nbt).await;
+    nbt
+}
+
+/// Spawns a new entity of the type `name` at `pos`, restoring the state written by
+/// [`write_entity`]. Unknown types are skipped.
+pub async fn spawn_entity(world: &Arc<World>, name: &str, pos: Vector3<f64>, nbt: &NbtCompound) {
+    let Some(entity_type) = EntityType::from_name(name) else {
+        return;
+    };
+    let entity = from_type(entity_type, pos, world, uuid::Uuid::new_v4()).await;
+    entity.get_entity().read_nbt(nbt).await;
+    // The stored position belongs to the copied entity.
+    entity.get_entity().set_pos(pos);
+    world.spawn_entity(entity).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityKind;
+
+    #[test]
+    fn test_entity_kind() {
+        assert_eq!(EntityKind::of("zombie"), EntityKind::Hostile);
+        assert_eq!(EntityKind::of("wolf"), EntityKind::Pet);
+        assert_eq!(EntityKind::of("oak_chest_boat"), EntityKind::Boat);
+        assert_eq!(EntityKind::of("bamboo_raft"), EntityKind::Boat);
+        assert_eq!(EntityKind::of("hopper_minecart"), EntityKind::Minecart);
+        assert_eq!(EntityKind::of("glow_item_frame"), EntityKind::ItemFrame);
+        assert!(!EntityKind::of("armor_stand").is_mob());
+        assert!(!EntityKind::of("marker").is_mob());
+        assert_eq!(EntityKind::of("player"), EntityKind::Other);
+        assert_eq!(EntityKind::of("some_new_mob"), EntityKind::Other);
+    }
+}
@@ -0,0 +1,965 @@
+//! A small expression language modelled after the one WorldEdit uses for
+//! `//generate`, expression masks and friends.
+//!
+//! Every value is an `f64`; a value is considered `true` when it is greater than zero.
+//! Besides the usual arithmetic, comparison and logical operators, the language supports
+//! assignments (`=`, `+=`, ...), the ternary operator, `if`/`else`, `while`, `do`/`while`,
+//! C-style and ranged (`for (i = 1, 10)`) `for` loops, `break`, `continue` and `return`.
+//!
+//! # Example
+//!
+//! ```
+//! let mut expression = Expression::compile("x * x + y * y < 1", &["x", "y"]).unwrap();
+//! assert_eq!(expression.evaluate(&[0.5, 0.5]), Ok(1.0));
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use super::{
+    noise::{Perlin, voronoi},
+    random::Random,
+};
+
+/// Upper bound on the iterations of a single loop, protecting the server from
+/// expressions that never terminate.
+const MAX_LOOP_ITERATIONS: usize = 256;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpressionError {
+    message: String,
+    position: usize,
+}
+
+impl ExpressionError {
+    fn new(message: impl Into<String>, position: usize) -> Self {
+        Self {
+            message: message.into(),
+            position,
+        }
+    }
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at position {})", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+/// A compiled expression together with the state needed to evaluate it.
+pub struct Expression {
+    root: Node,
+    runtime: Runtime,
+    inputs: usize,
+}
+
+impl Expression {
+    /// Parses `source`, binding `inputs` (e.g. `["x", "y", "z"]`) to the first
+    /// variable slots so they can be supplied to [`Expression::evaluate`].
+    pub fn compile(source: &str, inputs: &[&str]) -> Result<Self, ExpressionError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens,
+            index: 0,
+            end: source.len(),
+            variables: inputs.iter().map(|name| (*name).to_string()).collect(),
+        };
+        let root = parser.parse_program()?;
+        Ok(Self {
+            root,
+            runtime: Runtime {
+                slots: vec![0.0; parser.variables.len()],
+                random: Random::new(),
+                perlin: HashMap::new(),
+            },
+            inputs: inputs.len(),
+        })
+    }
+
+    /// Evaluates the expression with the given input values, in the order the
+    /// inputs were passed to [`Expression::compile`]. Other variables start at zero.
+    pub fn evaluate(&mut self, inputs: &[f64]) -> Result<f64, ExpressionError> {
+        debug_assert_eq!(inputs.len(), self.inputs);
+        self.runtime.slots.fill(0.0);
+        self.runtime.slots[..self.inputs].copy_from_slice(inputs);
+        match self.root.eval(&mut self.runtime) {
+            Ok(value) | Err(Flow::Return(value)) => Ok(value),
+            Err(Flow::Break | Flow::Continue) => Err(ExpressionError::new(
+                "break or continue outside of a loop",
+                0,
+            )),
+            Err(Flow::Error(message)) => Err(ExpressionError::new(message, 0)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Symbol(&'static str),
+}
+
+const SYMBOLS: [&str; 32] = [
+    "<=", ">=", "==", "!=", "~=", "&&", "||", "+=", "-=", "*=", "/=", "%=", "^=", "+", "-", "*",
+    "/", "%", "^", "<", ">", "=", "!", "~", "(", ")", "{", "}", ",", ";", "?", ":",
+];
+
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, ExpressionError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit()
+            || (c == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+        {
+            let start = i;
+            while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+                i += 1;
+            }
+            if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+                let mut j = i + 1;
+                if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+                    j += 1;
+                }
+                if j < bytes.len() && bytes[j].is_ascii_digit() {
+                    i = j;
+                    while i < bytes.len() && bytes[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            let number = source[start..i]
+                .parse()
+                .map_err(|_| ExpressionError::new("Invalid number", start))?;
+            tokens.push((Token::Number(number), start));
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            let start = i;
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            tokens.push((Token::Ident(source[start..i].to_string()), start));
+        } else if let Some(symbol) = SYMBOLS
+            .iter()
+            .find(|symbol| source[i..].starts_with(**symbol))
+        {
+            tokens.push((Token::Symbol(symbol), i));
+            i += symbol.len();
+        } else {
+            return Err(ExpressionError::new(
+                format!(
+                    "Unexpected character '{}'",
+                    source[i..].chars().next().unwrap_or('?')
+                ),
+                i,
+            ));
+        }
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+    Near,
+    And,
+    Or,
+}
+
+impl BinaryOp {
+    fn apply(self, a: f64, b: f64) -> f64 {
+        match self {
+            Self::Add => a + b,
+            Self::Sub => a - b,
+            Self::Mul => a * b,
+            Self::Div => a / b,
+            Self::Rem => a % b,
+            Self::Pow => a.powf(b),
+            Self::Lt => bool_value(a < b),
+            Self::Le => bool_value(a <= b),
+            Self::Gt => bool_value(a > b),
+            Self::Ge => bool_value(a >= b),
+            Self::Eq => bool_value(a == b),
+            Self::Ne => bool_value(a != b),
+            Self::Near => bool_value((a - b).abs() <= 1e-7 * a.abs().max(b.abs()).max(1.0)),
+            Self::And => bool_value(a > 0.0 && b > 0.0),
+            Self::Or => bool_value(a > 0.0 || b > 0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum UnaryOp {
+    Neg,
+    Not,
+    Complement,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Function {
+    Abs,
+    Acos,
+    Asin,
+    Atan,
+    Atan2,
+    Cbrt,
+    Ceil,
+    Cos,
+    Cosh,
+    Exp,
+    Floor,
+    Ln,
+    Log,
+    Log10,
+    Max,
+    Min,
+    Rint,
+    Round,
+    Sin,
+    Sinh,
+    Sqrt,
+    Tan,
+    Tanh,
+    Rand,
+    RandInt,
+    Perlin,
+    Voronoi,
+    RidgedMf,
+}
+
+/// Name, function and accepted argument counts.
+const FUNCTIONS: [(&str, Function, usize, usize); 28] = [
+    ("abs", Function::Abs, 1, 1),
+    ("acos", Function::Acos, 1, 1),
+    ("asin", Function::Asin, 1, 1),
+    ("atan", Function::Atan, 1, 1),
+    ("atan2", Function::Atan2, 2, 2),
+    ("cbrt", Function::Cbrt, 1, 1),
+    ("ceil", Function::Ceil, 1, 1),
+    ("cos", Function::Cos, 1, 1),
+    ("cosh", Function::Cosh, 1, 1),
+    ("exp", Function::Exp, 1, 1),
+    ("floor", Function::Floor, 1, 1),
+    ("ln", Function::Ln, 1, 1),
+    ("log", Function::Log, 1, 1),
+    ("log10", Function::Log10, 1, 1),
+    ("max", Function::Max, 2, 3),
+    ("min", Function::Min, 2, 3),
+    ("rint", Function::Rint, 1, 1),
+    ("round", Function::Round, 1, 1),
+    ("sin", Function::Sin, 1, 1),
+    ("sinh", Function::Sinh, 1, 1),
+    ("sqrt", Function::Sqrt, 1, 1),
+    ("tan", Function::Tan, 1, 1),
+    ("tanh", Function::Tanh, 1, 1),
+    ("rand", Function::Rand, 0, 0),
+    ("randint", Function::RandInt, 1, 1),
+    ("perlin", Function::Perlin, 7, 7),
+    ("voronoi", Function::Voronoi, 5, 5),
+    ("ridgedmf", Function::RidgedMf, 6, 6),
+];
+
+const CONSTANTS: [(&str, f64); 4] = [
+    ("e", std::f64::consts::E),
+    ("pi", std::f64::consts::PI),
+    ("true", 1.0),
+    ("false", 0.0),
+];
+
+const KEYWORDS: [&str; 8] = [
+    "if", "else", "while", "do", "for", "break", "continue", "return",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Constant(f64),
+    Variable(usize),
+    Assign(usize, Option<BinaryOp>, Box<Node>),
+    Unary(UnaryOp, Box<Node>),
+    Binary(BinaryOp, Box<Node>, Box<Node>),
+    Conditional(Box<Node>, Box<Node>, Option<Box<Node>>),
+    While(Box<Node>, Box<Node>),
+    DoWhile(Box<Node>, Box<Node>),
+    For(Box<Node>, Box<Node>, Box<Node>, Box<Node>),
+    RangeFor(usize, Box<Node>, Box<Node>, Box<Node>),
+    Sequence(Vec<Node>),
+    Break,
+    Continue,
+    Return(Box<Node>),
+    Call(Function, Vec<Node>),
+}
+
+/// Non-local control flow, propagated through `Err` so `?` unwinds loops and sequences.
+enum Flow {
+    Break,
+    Continue,
+    Return(f64),
+    Error(String),
+}
+
+struct Runtime {
+    slots: Vec<f64>,
+    random: Random,
+    perlin: HashMap<i64, Perlin>,
+}
+
+fn bool_value(b: bool) -> f64 {
+    if b { 1.0 } else { 0.0 }
+}
+
+impl Node {
+    fn eval(&self, rt: &mut Runtime) -> Result<f64, Flow> {
+        Ok(match self {
+            Self::Constant(value) => *value,
+            Self::Variable(slot) => rt.slots[*slot],
+            Self::Assign(slot, op, value) => {
+                let value = value.eval(rt)?;
+                let value = match op {
+                    Some(op) => op.apply(rt.slots[*slot], value),
+                    None => value,
+                };
+                rt.slots[*slot] = value;
+                value
+            }
+            Self::Unary(op, value) => {
+                let value = value.eval(rt)?;
+                match op {
+                    UnaryOp::Neg => -value,
+                    UnaryOp::Not => bool_value(value <= 0.0),
+                    UnaryOp::Complement => !(value as i64) as f64,
+                }
+            }
+            Self::Binary(BinaryOp::And, a, b) => bool_value(a.eval(rt)? > 0.0 && b.eval(rt)? > 0.0),
+            Self::Binary(BinaryOp::Or, a, b) => bool_value(a.eval(rt)? > 0.0 || b.eval(rt)? > 0.0),
+            Self::Binary(op, a, b) => {
+                let a = a.eval(rt)?;
+                op.apply(a, b.eval(rt)?)
+            }
+            Self::Conditional(condition, then, otherwise) => {
+                if condition.eval(rt)? > 0.0 {
+                    then.eval(rt)?
+                } else if let Some(otherwise) = otherwise {
+                    otherwise.eval(rt)?
+                } else {
+                    0.0
+                }
+            }
+            Self::While(condition, body) => {
+                let mut last = 0.0;
+                let mut iterations = 0;
+                while condition.eval(rt)? > 0.0 {
+                    check_iterations(&mut iterations)?;
+                    match body.eval(rt) {
+                        Ok(value) => last = value,
+                        Err(Flow::Break) => break,
+                        Err(Flow::Continue) => {}
+                        Err(flow) => return Err(flow),
+                    }
+                }
+                last
+            }
+            Self::DoWhile(body, condition) => {
+                let mut last = 0.0;
+                let mut iterations = 0;
+                loop {
+                    check_iterations(&mut iterations)?;
+                    match body.eval(rt) {
+                        Ok(value) => last = value,
+                        Err(Flow::Break) => break,
+                        Err(Flow::Continue) => {}
+                        Err(flow) => return Err(flow),
+                    }
+                    if condition.eval(rt)? <= 0.0 {
+                        break;
+                    }
+                }
+                last
+            }
+            Self::For(init, condition, increment, body) => {
+                let mut last = 0.0;
+                let mut iterations = 0;
+                init.eval(rt)?;
+                while condition.eval(rt)? > 0.0 {
+                    check_iterations(&mut iterations)?;
+                    match body.eval(rt) {
+                        Ok(value) => last = value,
+                        Err(Flow::Break) => break,
+                        Err(Flow::Continue) => {}
+                        Err(flow) => return Err(flow),
+                    }
+                    increment.eval(rt)?;
+                }
+                last
+            }
+            Self::RangeFor(slot, first, last_value, body) => {
+                let mut last = 0.0;
+                let mut iterations = 0;
+                let first = first.eval(rt)?;
+                let last_value = last_value.eval(rt)?;
+                let mut i = first;
+                while i <= last_value {
+                    check_iterations(&mut iterations)?;
+                    rt.slots[*slot] = i;
+                    match body.eval(rt) {
+                        Ok(value) => last = value,
+                        Err(Flow::Break) => break,
+                        Err(Flow::Continue) => {}
+                        Err(flow) => return Err(flow),
+                    }
+                    i += 1.0;
+                }
+                last
+            }
+            Self::Sequence(nodes) => {
+                let mut last = 0.0;
+                for node in nodes {
+                    last = node.eval(rt)?;
+                }
+                last
+            }
+            Self::Break => return Err(Flow::Break),
+            Self::Continue => return Err(Flow::Continue),
+            Self::Return(value) => return Err(Flow::Return(value.eval(rt)?)),
+            Self::Call(function, args) => {
+                let mut values = [0.0; 7];
+                for (value, arg) in values.iter_mut().zip(args) {
+                    *value = arg.eval(rt)?;
+                }
+                call(*function, &values[..args.len()], rt)
+            }
+        })
+    }
+}
+
+fn check_iterations(iterations: &mut usize) -> Result<(), Flow> {
+    *iterations += 1;
+    if *iterations > MAX_LOOP_ITERATIONS {
+        Err(Flow::Error(format!(
+            "Loop exceeded {MAX_LOOP_ITERATIONS} iterations"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn call(function: Function, args: &[f64], rt: &mut Runtime) -> f64 {
+    match function {
+        Function::Abs => args[0].abs(),
+        Function::Acos => args[0].acos(),
+        Function::Asin => args[0].asin(),
+        Function::Atan => args[0].atan(),
+        Function::Atan2 => args[0].atan2(args[1]),
+        Function::Cbrt => args[0].cbrt(),
+        Function::Ceil => args[0].ceil(),
+        Function::Cos => args[0].cos(),
+        Function::Cosh => args[0].cosh(),
+        Function::Exp => args[0].exp(),
+        Function::Floor => args[0].floor(),
+        Function::Ln => args[0].ln(),
+        Function::Log => args[0].ln(),
+        Function::Log10 => args[0].log10(),
+        Function::Max => args.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        Function::Min => args.iter().copied().fold(f64::INFINITY, f64::min),
+        Function::Rint => args[0].round_ties_even(),
+        Function::Round => args[0].round(),
+        Function::Sin => args[0].sin(),
+        Function::Sinh => args[0].sinh(),
+        Function::Sqrt => args[0].sqrt(),
+        Function::Tan => args[0].tan(),
+        Function::Tanh => args[0].tanh(),
+        Function::Rand => rt.random.next_f64(),
+        Function::RandInt => {
+            let bound = args[0].floor();
+            if bound < 1.0 {
+                0.0
+            } else {
+                rt.random.next_below(bound as u64) as f64
+            }
+        }
+        Function::Perlin => {
+            let perlin = rt
+                .perlin
+                .entry(args[0] as i64)
+                .or_insert_with(|| Perlin::new(args[0] as i64));
+            perlin.fractal(
+                args[1],
+                args[2],
+                args[3],
+                args[4],
+                args[5].clamp(1.0, 30.0) as u32,
+                args[6],
+            )
+        }
+        Function::Voronoi => voronoi(args[0] as i64, args[1], args[2], args[3], args[4]),
+        Function::RidgedMf => {
+            let perlin = rt
+                .perlin
+                .entry(args[0] as i64)
+                .or_insert_with(|| Perlin::new(args[0] as i64));
+            perlin.ridged_multifractal(
+                args[1],
+                args[2],
+                args[3],
+                args[4],
+                args[5].clamp(1.0, 30.0) as u32,
+            )
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    index: usize,
+    end: usize,
+    variables: Vec<String>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(token, _)| token)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.index + offset).map(|(token, _)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.index)
+            .map_or(self.end, |(_, pos)| *pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.index).map(|(token, _)| token.clone());
+        self.index += 1;
+        token
+    }
+
+    fn error(&self, message: impl Into<String>) -> ExpressionError {
+        ExpressionError::new(message, self.position())
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), ExpressionError> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected '{symbol}'")))
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Ident(ident)) if ident == keyword) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn variable(&mut self, name: &str) -> usize {
+        if let Some(slot) = self.variables.iter().position(|v| v == name) {
+            slot
+        } else {
+            self.variables.push(name.to_string());
+            self.variables.len() - 1
+        }
+    }
+
+    fn parse_program(&mut self) -> Result<Node, ExpressionError> {
+        let node = self.parse_statements()?;
+        if self.peek().is_some() {
+            return Err(self.error("Unexpected token"));
+        }
+        Ok(node)
+    }
+
+    fn parse_statements(&mut self) -> Result<Node, ExpressionError> {
+        let mut statements = Vec::new();
+        loop {
+            while self.eat_symbol(";") {}
+            if matches!(self.peek(), None | Some(Token::Symbol("}"))) {
+                break;
+            }
+            let (statement, needs_separator) = self.parse_statement()?;
+            statements.push(statement);
+            if needs_separator
+                && !self.eat_symbol(";")
+                && !matches!(self.peek(), None | Some(Token::Symbol("}")))
+            {
+                return Err(self.error("Expected ';'"));
+            }
+        }
+        Ok(if statements.len() == 1 {
+            statements.pop().unwrap()
+        } else {
+            Node::Sequence(statements)
+        })
+    }
+
+    /// Returns the statement and whether it must be followed by a `;` separator.
+    fn parse_statement(&mut self) -> Result<(Node, bool), ExpressionError> {
+        if self.eat_symbol("{") {
+            let body = self.parse_statements()?;
+            self.expect_symbol("}")?;
+            return Ok((body, false));
+        }
+        if self.eat_keyword("if") {
+            let condition = self.parse_condition()?;
+            let (then, _) = self.parse_statement()?;
+            while self.eat_symbol(";") {}
+            let otherwise = if self.eat_keyword("else") {
+                Some(Box::new(self.parse_statement()?.0))
+            } else {
+                None
+            };
+            return Ok((
+                Node::Conditional(Box::new(condition), Box::new(then), otherwise),
+                false,
+            ));
+        }
+        if self.eat_keyword("while") {
+            let condition = self.parse_condition()?;
+            let (body, _) = self.parse_statement()?;
+            return Ok((Node::While(Box::new(condition), Box::new(body)), false));
+        }
+        if self.eat_keyword("do") {
+            let (body, _) = self.parse_statement()?;
+            while self.eat_symbol(";") {}
+            if !self.eat_keyword("while") {
+                return Err(self.error("Expected 'while'"));
+            }
+            let condition = self.parse_condition()?;
+            return Ok((Node::DoWhile(Box::new(body), Box::new(condition)), true));
+        }
+        if self.eat_keyword("for") {
+            return self.parse_for().map(|node| (node, false));
+        }
+        if self.eat_keyword("break") {
+            return Ok((Node::Break, true));
+        }
+        if self.eat_keyword("continue") {
+            return Ok((Node::Continue, true));
+        }
+        if self.eat_keyword("return") {
+            let value = if matches!(self.peek(), None | Some(Token::Symbol(";" | "}"))) {
+                Node::Constant(0.0)
+            } else {
+                self.parse_expression()?
+            };
+            return Ok((Node::Return(Box::new(value)), true));
+        }
+        Ok((self.parse_expression()?, true))
+    }
+
+    fn parse_condition(&mut self) -> Result<Node, ExpressionError> {
+        self.expect_symbol("(")?;
+        let condition = self.parse_expression()?;
+        self.expect_symbol(")")?;
+        Ok(condition)
+    }
+
+    fn parse_for(&mut self) -> Result<Node, ExpressionError> {
+        self.expect_symbol("(")?;
+        let init = match (self.peek(), self.peek_at(1)) {
+            (Some(Token::Ident(name)), Some(Token::Symbol("="))) => {
+                let name = name.clone();
+                self.check_assignable(&name)?;
+                self.index += 2;
+                let slot = self.variable(&name);
+                let first = self.parse_expression()?;
+                if self.eat_symbol(",") {
+                    let last = self.parse_expression()?;
+                    self.expect_symbol(")")?;
+                    let (body, _) = self.parse_statement()?;
+                    return Ok(Node::RangeFor(
+                        slot,
+                        Box::new(first),
+                        Box::new(last),
+                        Box::new(body),
+                    ));
+                }
+                Node::Assign(slot, None, Box::new(first))
+            }
+            (Some(Token::Symbol(";")), _) => Node::Constant(0.0),
+            _ => self.parse_expression()?,
+        };
+        self.expect_symbol(";")?;
+        let condition = if matches!(self.peek(), Some(Token::Symbol(";"))) {
+            Node::Constant(1.0)
+        } else {
+            self.parse_expression()?
+        };
+        self.expect_symbol(";")?;
+        let increment = if matches!(self.peek(), Some(Token::Symbol(")"))) {
+            Node::Constant(0.0)
+        } else {
+            self.parse_expression()?
+        };
+        self.expect_symbol(")")?;
+        let (body, _) = self.parse_statement()?;
+        Ok(Node::For(
+            Box::new(init),
+            Box::new(condition),
+            Box::new(increment),
+            Box::new(body),
+        ))
+    }
+
+    fn check_assignable(&self, name: &str) -> Result<(), ExpressionError> {
+        if CONSTANTS.iter().any(|(constant, _)| *constant == name)
+            || KEYWORDS.contains(&name)
+            || FUNCTIONS.iter().any(|(function, ..)| *function == name)
+        {
+            Err(self.error(format!("Cannot assign to '{name}'")))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn parse_expression(&mut self) -> Result<Node, ExpressionError> {
+        if let (Some(Token::Ident(name)), Some(Token::Symbol(symbol))) =
+            (self.peek(), self.peek_at(1))
+        {
+            let op = match *symbol {
+                "=" => Some(None),
+                "+=" => Some(Some(BinaryOp::Add)),
+                "-=" => Some(Some(BinaryOp::Sub)),
+                "*=" => Some(Some(BinaryOp::Mul)),
+                "/=" => Some(Some(BinaryOp::Div)),
+                "%=" => Some(Some(BinaryOp::Rem)),
+                "^=" => Some(Some(BinaryOp::Pow)),
+                _ => None,
+            };
+            if let Some(op) = op {
+                let name = name.clone();
+                self.check_assignable(&name)?;
+                self.index += 2;
+                let slot = self.variable(&name);
+                let value = self.parse_expression()?;
+                return Ok(Node::Assign(slot, op, Box::new(value)));
+            }
+        }
+        self.parse_ternary()
+    }
+
+    fn parse_ternary(&mut self) -> Result<Node, ExpressionError> {
+        let condition = self.parse_binary(0)?;
+        if !self.eat_symbol("?") {
+            return Ok(condition);
+        }
+        let then = self.parse_expression()?;
+        self.expect_symbol(":")?;
+        let otherwise = self.parse_expression()?;
+        Ok(Node::Conditional(
+            Box::new(condition),
+            Box::new(then),
+            Some(Box::new(otherwise)),
+        ))
+    }
+
+    /// Precedence climbing over the left-associative binary operators.
+    fn parse_binary(&mut self, level: usize) -> Result<Node, ExpressionError> {
+        const LEVELS: [&[(&str, BinaryOp)]; 6] = [
+            &[("||", BinaryOp::Or)],
+            &[("&&", BinaryOp::And)],
+            &[
+                ("==", BinaryOp::Eq),
+                ("!=", BinaryOp::Ne),
+                ("~=", BinaryOp::Near),
+            ],
+            &[
+                ("<", BinaryOp::Lt),
+                ("<=", BinaryOp::Le),
+                (">", BinaryOp::Gt),
+                (">=", BinaryOp::Ge),
+            ],
+            &[("+", BinaryOp::Add), ("-", BinaryOp::Sub)],
+            &[
+                ("*", BinaryOp::Mul),
+                ("/", BinaryOp::Div),
+                ("%", BinaryOp::Rem),
+            ],
+        ];
+
+        let Some(operators) = LEVELS.get(level) else {
+            return self.parse_unary();
+        };
+        let mut lhs = self.parse_binary(level + 1)?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Symbol(symbol)) => operators
+                    .iter()
+                    .find(|(s, _)| s == symbol)
+                    .map(|(_, op)| *op),
+                _ => None,
+            };
+            let Some(op) = op else {
+                return Ok(lhs);
+            };
+            self.index += 1;
+            let rhs = self.parse_binary(level + 1)?;
+            lhs = Node::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Node, ExpressionError> {
+        let op = match self.peek() {
+            Some(Token::Symbol("-")) => Some(UnaryOp::Neg),
+            Some(Token::Symbol("!")) => Some(UnaryOp::Not),
+            Some(Token::Symbol("~")) => Some(UnaryOp::Complement),
+            Some(Token::Symbol("+")) => {
+                self.index += 1;
+                return self.parse_unary();
+            }
+            _ => None,
+        };
+        if let Some(op) = op {
+            self.index += 1;
+            let value = self.parse_unary()?;
+            return Ok(Node::Unary(op, Box::new(value)));
+        }
+        self.parse_power()
+    }
+
+    fn parse_power(&mut self) -> Result<Node, ExpressionError> {
+        let base = self.parse_primary()?;
+        if self.eat_symbol("^") {
+            let exponent = self.parse_unary()?;
+            return Ok(Node::Binary(
+                BinaryOp::Pow,
+                Box::new(base),
+                Box::new(exponent),
+            ));
+        }
+        Ok(base)
+    }
+
+    fn parse_primary(&mut self) -> Result<Node, ExpressionError> {
+        let position = self.position();
+        match self.next() {
+            Some(Token::Number(value)) => Ok(Node::Constant(value)),
+            Some(Token::Symbol("(")) => {
+                let node = self.parse_expression()?;
+                self.expect_symbol(")")?;
+                Ok(node)
+            }
+            Some(Token::Ident(name)) => {
+                if self.eat_symbol("(") {
+                    return self.parse_call(&name, position);
+                }
+                if let Some((_, value)) = CONSTANTS.iter().find(|(constant, _)| *constant == name) {
+                    return Ok(Node::Constant(*value));
+                }
+                if KEYWORDS.contains(&name.as_str()) {
+                    return Err(ExpressionError::new(
+                        format!("Unexpected keyword '{name}'"),
+                        position,
+                    ));
+                }
+                Ok(Node::Variable(self.variable(&name)))
+            }
+            Some(_) => Err(ExpressionError::new("Unexpected token", position)),
+            None => Err(ExpressionError::new(
+                "Unexpected end of expression",
+                position,
+            )),
+        }
+    }
+
+    fn parse_call(&mut self, name: &str, position: usize) -> Result<Node, ExpressionError> {
+        let Some(&(_, function, min_args, max_args)) =
+            FUNCTIONS.iter().find(|(function, ..)| *function == name)
+        else {
+            return Err(ExpressionError::new(
+                format!("Unknown function '{name}'"),
+                position,
+            ));
+        };
+        let mut args = Vec::new();
+        if !self.eat_symbol(")") {
+            loop {
+                args.push(self.parse_expression()?);
+                if self.eat_symbol(")") {
+                    break;
+                }
+                self.expect_symbol(",")?;
+            }
+        }
+        if !(min_args..=max_args).contains(&args.len()) {
+            return Err(ExpressionError::new(
+                format!("Wrong number of arguments for '{name}'"),
+                position,
+            ));
+        }
+        Ok(Node::Call(function, args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Expression;
+
+    fn eval(source: &str, inputs: &[f64]) -> f64 {
+        Expression::compile(source, &["x", "y", "z"])
+            .unwrap()
+            .evaluate(inputs)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_expression_arithmetic() {
+        assert_eq!(eval("1 + 2 * 3", &[0.0, 0.0, 0.0]), 7.0);
+        assert_eq!(eval("(1 + 2) * 3", &[0.0, 0.0, 0.0]), 9.0);
+        assert_eq!(eval("-2 ^ 2", &[0.0, 0.0, 0.0]), -4.0);
+        assert_eq!(eval("2 ^ 3 ^ 2", &[0.0, 0.0, 0.0]), 512.0);
+        assert_eq!(eval("x * x + y * y + z * z < 1", &[0.5, 0.5, 0.5]), 1.0);
+        assert_eq!(eval("max(x, y, z)", &[1.0, 3.0, 2.0]), 3.0);
+    }
+
+    #[test]
+    fn test_expression_control_flow() {
+        assert_eq!(eval("a = 0; for (i = 1, 10) { a += i } a", &[0.0; 3]), 55.0);
+        assert_eq!(
+            eval(
+                "a = 0; i = 0; while (i < 5) { i += 1; if (i == 3) continue; a += i; } a",
+                &[0.0; 3]
+            ),
+            12.0
+        );
+        assert_eq!(eval("x > 0 ? 1 : -1", &[-3.0, 0.0, 0.0]), -1.0);
+        assert_eq!(eval("if (x > 0) return 5; 7", &[1.0, 0.0, 0.0]), 5.0);
+    }
+
+    #[test]
+    fn test_expression_errors() {
+        assert!(Expression::compile("1 +", &[]).is_err());
+        assert!(Expression::compile("unknown(1)", &[]).is_err());
+        assert!(Expression::compile("pi = 3", &[]).is_err());
+        let mut infinite = Expression::compile("while (1) { }", &[]).unwrap();
+        assert!(infinite.evaluate(&[]).is_err());
+    }
+}
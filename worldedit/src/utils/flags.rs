@@ -0,0 +1,116 @@
+use pumpkin::command::CommandExecutor;
+use pumpkin::command::tree::CommandTree;
+use pumpkin::command::tree::builder::{NonLeafNodeBuilder, literal};
+
+/// The set of flags (such as `-s`) a command was invoked with.
+#[derive(Debug, Clone, Copy)]
+pub struct Flags {
+    names: &'static [&'static str],
+    bits: u32,
+}
+
+impl Flags {
+    /// Whether the flag `name` (including the leading `-`) was given.
+    #[must_use]
+    pub fn has(&self, name: &str) -> bool {
+        self.names
+            .iter()
+            .position(|flag| *flag == name)
+            .is_some_and(|i| self.bits & (1 << i) != 0)
+    }
+}
+
+/// Adds the flag literals in `names` to `tree`, in every order, each followed by
+/// `arguments`. Every flag literal and the tree itself are made executable with
+/// `executor`. Both closures receive the flags of the path they are added to.
+///
+/// The flag literals are added before the bare arguments so an argument is never
+/// mistaken for a flag.
+pub fn with_flags<A, X, E>(
+    tree: CommandTree,
+    names: &'static [&'static str],
+    arguments: A,
+    executor: X,
+) -> CommandTree
+where
+    A: Fn(Flags) -> NonLeafNodeBuilder,
+    X: Fn(Flags) -> E,
+    E: CommandExecutor + 'static,
+{
+    let flags = Flags { names, bits: 0 };
+    let mut tree = tree;
+    for node in flag_nodes(flags, &|flags| Some(arguments(flags)), &executor) {
+        tree = tree.then(node);
+    }
+    tree.then(arguments(flags)).execute(executor(flags))
+}
+
+/// Like [`with_flags`], for commands that take no arguments besides the flags.
+pub fn with_only_flags<X, E>(
+    tree: CommandTree,
+    names: &'static [&'static str],
+    executor: X,
+) -> CommandTree
+where
+    X: Fn(Flags) -> E,
+    E: CommandExecutor + 'static,
+{
+    let flags = Flags { names, bits: 0 };
+    let mut tree = tree;
+    for node in flag_nodes(flags, &|_: Flags| None, &executor) {
+        tree = tree.then(node);
+    }
+    tree.execute(executor(flags))
+}
+
+/// Like [`with_flags`], for a literal subcommand such as `//brush sphere`. Literals
+/// that take no arguments besides the flags return `None` from `arguments`.
+pub fn literal_with_flags<A, X, E>(
+    name: &'static str,
+    names: &'static [&'static str],
+    arguments: A,
+    executor: X,
+) -> NonLeafNodeBuilder
+where
+    A: Fn(Flags) -> Option<NonLeafNodeBuilder>,
+    X: Fn(Flags) -> E,
+    E: CommandExecutor + 'static,
+{
+    let flags = Flags { names, bits: 0 };
+    let mut node = literal(name);
+    for child in flag_nodes(flags, &arguments, &executor) {
+        node = node.then(child);
+    }
+    if let Some(arguments) = arguments(flags) {
+        node = node.then(arguments);
+    }
+    node.execute(executor(flags))
+}
+
+fn flag_nodes<A, X, E>(flags: Flags, arguments: &A, executor: &X) -> Vec<NonLeafNodeBuilder>
+where
+    A: Fn(Flags) -> Option<NonLeafNodeBuilder>,
+    X: Fn(Flags) -> E,
+    E: CommandExecutor + 'static,
+{
+    flags
+        .names
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| flags.bits & (1 << i) == 0)
+        .map(|(i, name)| {
+            let flags = Flags {
+                bits: flags.bits | (1 << i),
+                ..flags
+            };
+            let mut node = literal(name);
+            for child in flag_nodes(flags, arguments, executor) {
+                node = node.then(child);
+            }
+            if let Some(arguments) = arguments(flags) {
+                node = node.then(arguments);
+            }
+            node.execute(executor(flags))
+        })
+        .collect()
+}
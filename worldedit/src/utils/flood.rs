@@ -0,0 +1,122 @@
+use std::collections::{HashSet, VecDeque};
+
+use pumpkin::world::World;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::region::{BlockBuffer, max_y, min_y, read_blocks};
+
+/// The maximum number of blocks a single flood fill may collect before it is aborted.
+pub const MAX_VISITED: usize = 1_000_000;
+
+/// Offsets of the four horizontal neighbours and the one below a block.
+pub const HORIZONTAL_AND_DOWN: [(i32, i32, i32); 5] =
+    [(1, 0, 0), (-1, 0, 0), (0, -1, 0), (0, 0, 1), (0, 0, -1)];
+
+/// Offset of the neighbour below a block.
+pub const DOWN: [(i32, i32, i32); 1] = [(0, -1, 0)];
+
+/// Collects every block reachable from `seeds` for which `accept` returns `true`,
+/// breadth first. `neighbours` returns the offsets to continue with from a block,
+/// so a fill can for example be kept from climbing upwards.
+///
+/// `accept` is called at most once per position. Fails once more than
+/// [`MAX_VISITED`] blocks were collected.
+pub fn flood_fill<S, N, F>(seeds: S, neighbours: N, mut accept: F) -> Result<Vec<BlockPos>, String>
+where
+    S: IntoIterator<Item = BlockPos>,
+    N: Fn(BlockPos) -> &'static [(i32, i32, i32)],
+    F: FnMut(BlockPos) -> bool,
+{
+    let mut seen = HashSet::new();
+    let mut queue: VecDeque<_> = seeds.into_iter().collect();
+    let mut result = Vec::new();
+
+    while let Some(pos) = queue.pop_front() {
+        if !seen.insert(pos) || !accept(pos) {
+            continue;
+        }
+        result.push(pos);
+        if result.len() > MAX_VISITED {
+            return Err(format!(
+                "The fill would visit more than {MAX_VISITED} blocks, use a smaller radius."
+            ));
+        }
+        for (dx, dy, dz) in neighbours(pos) {
+            let next = BlockPos(pos.0 + Vector3::new(*dx, *dy, *dz));
+            if !seen.contains(&next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Whether `pos` lies within the ball of the given radius around `origin`.
+#[must_use]
+pub fn within_radius(origin: BlockPos, pos: BlockPos, radius: i32) -> bool {
+    let (dx, dy, dz) = (
+        i64::from(pos.0.x - origin.0.x),
+        i64::from(pos.0.y - origin.0.y),
+        i64::from(pos.0.z - origin.0.z),
+    );
+    let radius = i64::from(radius);
+    dx * dx + dy * dy + dz * dz <= radius * radius
+}
+
+/// Reads the blocks of the cube of the given radius around `origin`, clamped to the
+/// world's height.
+pub async fn read_around(world: &World, origin: BlockPos, radius: i32) -> BlockBuffer {
+    let pos1 = Vector3::new(
+        origin.0.x - radius,
+        (origin.0.y - radius).max(min_y(world)),
+        origin.0.z - radius,
+    );
+    let pos2 = Vector3::new(
+        origin.0.x + radius,
+        (origin.0.y + radius).min(max_y(world)),
+        origin.0.z + radius,
+    );
+    read_blocks(world, BlockPos(pos1), BlockPos(pos2)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+    use super::{DOWN, HORIZONTAL_AND_DOWN, flood_fill};
+    use crate::utils::region::NEIGHBOURS;
+
+    fn pos(x: i32, y: i32, z: i32) -> BlockPos {
+        BlockPos(Vector3::new(x, y, z))
+    }
+
+    #[test]
+    fn test_flood_fill_stays_inside() {
+        let inside = |p: BlockPos| (0..3).contains(&p.0.x) && (0..3).contains(&p.0.y) && p.0.z == 0;
+        let filled = flood_fill([pos(1, 1, 0)], |_| &NEIGHBOURS, inside).unwrap();
+        assert_eq!(filled.len(), 9);
+        assert!(filled.iter().all(|p| inside(*p)));
+    }
+
+    #[test]
+    fn test_flood_fill_downward() {
+        // An L-shaped hole with a side pocket at the bottom, which is only reachable
+        // sideways and must not be filled.
+        let hole = |p: BlockPos| {
+            p.0.z == 0
+                && ((0..3).contains(&p.0.x) && p.0.y == 0
+                    || p.0.x == 0 && p.0.y > -3
+                    || p.0.x == 1 && p.0.y == -2)
+        };
+        let neighbours = |p: BlockPos| -> &'static [(i32, i32, i32)] {
+            if p.0.y == 0 {
+                &HORIZONTAL_AND_DOWN
+            } else {
+                &DOWN
+            }
+        };
+        let filled = flood_fill([pos(2, 0, 0)], neighbours, hole).unwrap();
+        assert_eq!(filled.len(), 5);
+    }
+}
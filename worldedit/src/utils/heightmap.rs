@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+
+use pumpkin::world::World;
+use pumpkin_util::math::{position::BlockPos, vector2::Vector2, vector3::Vector3};
+
+use super::block::is_air;
+use super::chunked_range::ChunkedRange;
+use super::mask::{Mask, masked};
+use super::region::{edit_positions, max_y, min_y, read_blocks};
+
+/// The height and state of the topmost matching block of every column of a cuboid.
+pub struct HeightMap {
+    min_x: i32,
+    min_z: i32,
+    width: usize,
+    length: usize,
+    heights: Vec<Option<(i32, u16)>>,
+}
+
+impl HeightMap {
+    /// Number of columns along the x axis.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of columns along the z axis.
+    #[must_use]
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    fn index(&self, x: i32, z: i32) -> Option<usize> {
+        let (dx, dz) = (x - self.min_x, z - self.min_z);
+        if dx < 0 || dz < 0 || dx as usize >= self.width || dz as usize >= self.length {
+            return None;
+        }
+        Some(dx as usize * self.length + dz as usize)
+    }
+
+    /// Returns the Y coordinate of the topmost matching block of the column at `x`, `z`,
+    /// or `None` if the column has no matching block or lies outside the map.
+    #[must_use]
+    pub fn get(&self, x: i32, z: i32) -> Option<i32> {
+        self.index(x, z)
+            .and_then(|index| self.heights[index])
+            .map(|(y, _)| y)
+    }
+
+    /// Returns the state id of the topmost matching block of the column at `x`, `z`.
+    #[must_use]
+    pub fn state(&self, x: i32, z: i32) -> Option<u16> {
+        self.index(x, z)
+            .and_then(|index| self.heights[index])
+            .map(|(_, state_id)| state_id)
+    }
+
+    /// Iterates over `(x, z, height)` of all columns.
+    pub fn columns(&self) -> impl Iterator<Item = (i32, i32, Option<i32>)> + '_ {
+        self.heights.iter().enumerate().map(|(index, height)| {
+            (
+                self.min_x + (index / self.length) as i32,
+                self.min_z + (index % self.length) as i32,
+                height.map(|(y, _)| y),
+            )
+        })
+    }
+}
+
+/// Scans every column of the cuboid spanned by `pos1` and `pos2` (which must be
+/// normalized) from the top down, directly over the chunk sections, and records the
+/// first block for which `matches` returns `true`.
+pub async fn read_heightmap<F>(
+    world: &World,
+    pos1: BlockPos,
+    pos2: BlockPos,
+    matches: F,
+) -> HeightMap
+where
+    F: Fn(BlockPos, u16) -> bool + Send + Sync,
+{
+    let min_y = min_y(world);
+
+    let width = (pos2.0.x - pos1.0.x + 1) as usize;
+    let length = (pos2.0.z - pos1.0.z + 1) as usize;
+    let mut heightmap = HeightMap {
+        min_x: pos1.0.x,
+        min_z: pos1.0.z,
+        width,
+        length,
+        heights: vec![None; width * length],
+    };
+
+    let (x1, x2) = (pos1.0.x, pos2.0.x);
+    let (z1, z2) = (pos1.0.z, pos2.0.z);
+    let (y1, y2) = (pos1.0.y - min_y, pos2.0.y - min_y);
+    let y_ranges: Vec<_> = ChunkedRange::new(y1..=y2).collect();
+
+    for (chunk_x, x_range) in ChunkedRange::new(x1..=x2) {
+        for (chunk_z, z_range) in ChunkedRange::new(z1..=z2) {
+            let chunk = world.level.get_chunk(Vector2::new(chunk_x, chunk_z)).await;
+            let chunk = chunk.read().await;
+            for x in x_range.clone() {
+                for z in z_range.clone() {
+                    let (world_x, world_z) = ((chunk_x << 4) + x, (chunk_z << 4) + z);
+                    'column: for (chunk_y, y_range) in y_ranges.iter().rev() {
+                        let Some(section) = chunk.section.sections.get(*chunk_y as usize) else {
+                            continue;
+                        };
+                        for y in y_range.clone().rev() {
+                            let world_y = (chunk_y << 4) + y + min_y;
+                            let block_id =
+                                section.block_states.get(x as usize, y as usize, z as usize);
+                            if matches(BlockPos(Vector3::new(world_x, world_y, world_z)), block_id)
+                            {
+                                if let Some(index) = heightmap.index(world_x, world_z) {
+                                    heightmap.heights[index] = Some((world_y, block_id));
+                                }
+                                break 'column;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    heightmap
+}
+
+/// Returns the position and state of the topmost non-air block of every column within
+/// `radius` of `origin`, over the full height of the world.
+pub async fn read_surface_around(
+    world: &World,
+    origin: BlockPos,
+    radius: i32,
+) -> Vec<(BlockPos, u16)> {
+    let pos1 = BlockPos(Vector3::new(
+        origin.0.x - radius,
+        min_y(world),
+        origin.0.z - radius,
+    ));
+    let pos2 = BlockPos(Vector3::new(
+        origin.0.x + radius,
+        max_y(world),
+        origin.0.z + radius,
+    ));
+    let heightmap = read_heightmap(world, pos1, pos2, |_, block_id| !is_air(block_id)).await;
+    heightmap
+        .columns()
+        .filter(|(x, z, _)| {
+            let (dx, dz) = (x - origin.0.x, z - origin.0.z);
+            dx * dx + dz * dz <= radius * radius
+        })
+        .filter_map(|(x, z, height)| {
+            Some((
+                BlockPos(Vector3::new(x, height?, z)),
+                heightmap.state(x, z)?,
+            ))
+        })
+        .collect()
+}
+
+/// The same kernel WorldEdit uses for `//smooth`.
+const KERNEL_RADIUS: usize = 5;
+const KERNEL_SIGMA: f64 = 1.0;
+
+/// The most smoothing passes a single edit may run.
+pub const MAX_ITERATIONS: i32 = 100;
+
+/// Smooths the elevation of the cuboid spanned by `pos1` and `pos2` (which must be
+/// normalized), using the topmost block for which `matches` returns `true` as the
+/// height of each column. Columns without such a block are left out of the smoothing.
+/// Only blocks `mask` matches are changed. Returns the number of changed blocks.
+pub async fn smooth_terrain<F>(
+    world: &World,
+    pos1: BlockPos,
+    pos2: BlockPos,
+    iterations: i32,
+    matches: F,
+    mask: Option<&Mask>,
+) -> usize
+where
+    F: Fn(BlockPos, u16) -> bool + Send + Sync,
+{
+    let heightmap = read_heightmap(world, pos1, pos2, matches).await;
+    let buffer = read_blocks(world, pos1, pos2).await;
+
+    let (width, length) = (heightmap.width(), heightmap.length());
+    let mut heights: Vec<Option<f64>> = heightmap
+        .columns()
+        .map(|(_, _, height)| height.map(f64::from))
+        .collect();
+    for _ in 0..iterations {
+        heights = gaussian_smooth(&heights, width, length, KERNEL_RADIUS, KERNEL_SIGMA);
+    }
+
+    // Raise a column by stacking its surface block on top of itself, and lower it by
+    // moving the surface block down and clearing everything above.
+    let mut changes = HashMap::new();
+    for ((x, z, height), smoothed) in heightmap.columns().zip(heights) {
+        let (Some(old), Some(smoothed)) = (height, smoothed) else {
+            continue;
+        };
+        let new = (smoothed.round() as i32).clamp(pos1.0.y, pos2.0.y);
+        let at = |y| BlockPos(Vector3::new(x, y, z));
+        let Some(surface) = buffer.get(at(old)) else {
+            continue;
+        };
+        if new < old {
+            for y in new + 1..=old {
+                changes.insert(at(y), 0);
+            }
+            changes.insert(at(new), surface);
+        } else if new > old {
+            for y in old + 1..=new {
+                changes.insert(at(y), surface);
+            }
+            if let Some(below) = buffer.get(at(old - 1)) {
+                changes.insert(at(old), below);
+            }
+        }
+    }
+
+    edit_positions(
+        world,
+        changes.keys().copied().collect::<Vec<_>>(),
+        masked(mask, |block_pos, _| changes.get(&block_pos).copied()),
+    )
+    .await
+}
+
+/// Blurs a `width` x `length` grid of heights with a Gaussian kernel of the given
+/// radius and standard deviation. Samples beyond the edges are clamped to the edge.
+///
+/// Missing heights are left out of the kernel, which is renormalized over the
+/// remaining ones, and stay missing.
+#[must_use]
+pub fn gaussian_smooth(
+    heights: &[Option<f64>],
+    width: usize,
+    length: usize,
+    radius: usize,
+    sigma: f64,
+) -> Vec<Option<f64>> {
+    let radius = radius as isize;
+    let kernel: Vec<f64> = (-radius..=radius)
+        .map(|i| (-((i * i) as f64) / (2.0 * sigma * sigma)).exp())
+        .collect();
+    let sum: f64 = kernel.iter().sum();
+    let kernel: Vec<f64> = kernel.iter().map(|weight| weight / sum).collect();
+
+    // The 2D Gaussian is separable: blur along z, then along x. Weighted sums and the
+    // weights of the present samples are blurred alike, so missing ones drop out.
+    let clamp = |i: isize, len: usize| i.clamp(0, len as isize - 1) as usize;
+    let mut blurred_z = vec![(0.0, 0.0); heights.len()];
+    for x in 0..width {
+        for z in 0..length {
+            blurred_z[x * length + z] = (-radius..=radius)
+                .filter_map(|i| {
+                    let height = heights[x * length + clamp(z as isize + i, length)]?;
+                    let weight = kernel[(i + radius) as usize];
+                    Some((weight * height, weight))
+                })
+                .fold((0.0, 0.0), |(sum, total), (value, weight)| {
+                    (sum + value, total + weight)
+                });
+        }
+    }
+    let mut blurred = vec![None; heights.len()];
+    for x in 0..width {
+        for z in 0..length {
+            if heights[x * length + z].is_none() {
+                continue;
+            }
+            let (sum, total) = (-radius..=radius)
+                .map(|i| {
+                    let (value, weight) = blurred_z[clamp(x as isize + i, width) * length + z];
+                    let factor = kernel[(i + radius) as usize];
+                    (factor * value, factor * weight)
+                })
+                .fold((0.0, 0.0), |(sum, total), (value, weight)| {
+                    (sum + value, total + weight)
+                });
+            blurred[x * length + z] = Some(sum / total);
+        }
+    }
+    blurred
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gaussian_smooth;
+
+    #[test]
+    fn test_gaussian_smooth_keeps_flat_terrain() {
+        let heights = vec![Some(64.0); 6 * 4];
+        for height in gaussian_smooth(&heights, 6, 4, 5, 1.0) {
+            assert!((height.unwrap() - 64.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_gaussian_smooth_lowers_spike() {
+        let mut heights = vec![Some(0.0); 5 * 5];
+        heights[2 * 5 + 2] = Some(10.0);
+        let smoothed: Vec<f64> = gaussian_smooth(&heights, 5, 5, 5, 1.0)
+            .into_iter()
+            .map(Option::unwrap)
+            .collect();
+        assert!(smoothed[2 * 5 + 2] < 10.0);
+        assert!(smoothed[2 * 5 + 3] > 0.0);
+        assert!((smoothed.iter().sum::<f64>() - 10.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_gaussian_smooth_skips_missing_columns() {
+        let mut heights = vec![Some(64.0); 5 * 5];
+        heights[2 * 5 + 2] = None;
+        let smoothed = gaussian_smooth(&heights, 5, 5, 5, 1.0);
+        assert_eq!(smoothed[2 * 5 + 2], None);
+        for height in smoothed.into_iter().flatten() {
+            assert!((height - 64.0).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use pumpkin::command::args::{Arg, ConsumedArgs};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin::world::World;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::biome::parse_biome;
+use super::block::{block_of, is_air, parse_block};
+use super::expression::Expression;
+use super::random::{Random, hash_position};
+use super::region::{BlockBuffer, NEIGHBOURS, read_biomes, read_blocks};
+
+/// A mask decides which blocks an edit is allowed to touch.
+///
+/// Masks are written as a comma separated list of entries, any of which has to
+/// match, e.g. `stone,dirt`. Lists joined by `&` all have to match, e.g.
+/// `stone,dirt&>grass_block`. A leading `!` negates a list or a single entry.
+///
+/// An entry is one of:
+/// - a block, which matches regardless of its state,
+/// - `=expression`, which matches where the expression of `x`, `y` and `z` is
+///   positive; it takes the rest of the input,
+/// - `>entry` and `<entry`, which match above and below blocks matching `entry`,
+/// - `%chance`, which matches that percentage of the blocks at random,
+/// - `$biome`, which matches blocks in the biome,
+/// - `#surface`, which matches blocks next to air, and `#region` (or `#sel`), which
+///   matches blocks inside the player's selection.
+///
+/// Masks that look at the world around a block have to be [prepared](Mask::prepare)
+/// before they are tested.
+#[derive(Debug, Clone)]
+pub enum Mask {
+    Blocks(Vec<u16>),
+    Not(Box<Mask>),
+    /// Matches if every mask matches.
+    All(Vec<Mask>),
+    /// Matches if any mask matches.
+    Any(Vec<Mask>),
+    Expression(MaskExpression),
+    /// Matches if the block `dy` above matches `mask`.
+    Offset {
+        mask: Box<Mask>,
+        dy: i32,
+        blocks: Option<Arc<BlockBuffer>>,
+    },
+    Random {
+        seed: u64,
+        chance: f64,
+    },
+    Biomes {
+        biomes: Vec<u8>,
+        /// The biome of each 4x4x4 cell, keyed by the cell's coordinates.
+        cells: Option<Arc<HashMap<(i32, i32, i32), u8>>>,
+    },
+    Surface(Option<Arc<BlockBuffer>>),
+    /// The selection, normalized.
+    Region(Option<(BlockPos, BlockPos)>),
+}
+
+/// An expression tested by a mask, shared between clones of the mask.
+#[derive(Clone)]
+pub struct MaskExpression {
+    source: String,
+    expression: Arc<Mutex<Expression>>,
+}
+
+impl fmt::Debug for MaskExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "={}", self.source)
+    }
+}
+
+/// Splits `input` at every `separator`, except that an entry starting with `=` (an
+/// expression) runs to the end.
+fn split_entries(input: &str, separator: char) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut rest = input;
+    loop {
+        let entry_start = rest.trim_start_matches(['!', '>', '<']);
+        if entry_start.starts_with('=') {
+            entries.push(rest);
+            return entries;
+        }
+        match rest.split_once(separator) {
+            Some((entry, next)) => {
+                entries.push(entry);
+                rest = next;
+            }
+            None => {
+                entries.push(rest);
+                return entries;
+            }
+        }
+    }
+}
+
+impl Mask {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut masks = split_entries(input, '&')
+            .into_iter()
+            .map(Self::parse_list)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(if masks.len() == 1 {
+            masks.remove(0)
+        } else {
+            Self::All(masks)
+        })
+    }
+
+    fn parse_list(input: &str) -> Result<Self, String> {
+        if let Some(inner) = input.strip_prefix('!') {
+            return Ok(Self::Not(Box::new(Self::parse_list(inner)?)));
+        }
+        // Plain blocks are merged into a single block mask.
+        let mut blocks = Vec::new();
+        let mut masks = Vec::new();
+        for entry in split_entries(input, ',') {
+            match Self::parse_entry(entry)? {
+                Self::Blocks(ids) => blocks.extend(ids),
+                mask => masks.push(mask),
+            }
+        }
+        if !blocks.is_empty() {
+            masks.insert(0, Self::Blocks(blocks));
+        }
+        Ok(if masks.len() == 1 {
+            masks.remove(0)
+        } else {
+            Self::Any(masks)
+        })
+    }
+
+    fn parse_entry(input: &str) -> Result<Self, String> {
+        if let Some(inner) = input.strip_prefix('!') {
+            return Ok(Self::Not(Box::new(Self::parse_entry(inner)?)));
+        }
+        if let Some(source) = input.strip_prefix('=') {
+            let expression =
+                Expression::compile(source, &["x", "y", "z"]).map_err(|err| err.to_string())?;
+            return Ok(Self::Expression(MaskExpression {
+                source: source.to_string(),
+                expression: Arc::new(Mutex::new(expression)),
+            }));
+        }
+        if let Some(inner) = input.strip_prefix('>') {
+            return Ok(Self::Offset {
+                mask: Box::new(Self::parse_entry(inner)?),
+                dy: -1,
+                blocks: None,
+            });
+        }
+        if let Some(inner) = input.strip_prefix('<') {
+            return Ok(Self::Offset {
+                mask: Box::new(Self::parse_entry(inner)?),
+                dy: 1,
+                blocks: None,
+            });
+        }
+        if let Some(chance) = input.strip_prefix('%') {
+            let chance: f64 = chance
+                .parse()
+                .ok()
+                .filter(|chance| (0.0..=100.0).contains(chance))
+                .ok_or_else(|| format!("Invalid chance '{chance}'."))?;
+            return Ok(Self::Random {
+                seed: Random::new().next_u64(),
+                chance: chance / 100.0,
+            });
+        }
+        if let Some(name) = input.strip_prefix('$') {
+            return Ok(Self::Biomes {
+                biomes: vec![parse_biome(name)?.id],
+                cells: None,
+            });
+        }
+        if let Some(name) = input.strip_prefix('#') {
+            return match name.to_ascii_lowercase().as_str() {
+                "surface" => Ok(Self::Surface(None)),
+                "region" | "selection" | "sel" => Ok(Self::Region(None)),
+                _ => Err(format!("Unknown mask '#{name}'.")),
+            };
+        }
+        Ok(Self::Blocks(vec![parse_block(input)?.id]))
+    }
+
+    /// Reads what the mask needs to know about the world to test the blocks between
+    /// `pos1` and `pos2` (which must be normalized), and remembers the player's
+    /// `selection` for `#region`. Masks that only look at the tested block need
+    /// nothing, so preparing them is free.
+    pub fn prepare<'a>(
+        &'a mut self,
+        world: &'a World,
+        selection: Option<(BlockPos, BlockPos)>,
+        pos1: BlockPos,
+        pos2: BlockPos,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            match self {
+                Self::Not(mask) => mask.prepare(world, selection, pos1, pos2).await,
+                Self::All(masks) | Self::Any(masks) => {
+                    for mask in masks {
+                        mask.prepare(world, selection, pos1, pos2).await;
+                    }
+                }
+                Self::Offset { mask, dy, blocks } => {
+                    let offset = Vector3::new(0, *dy, 0);
+                    let (pos1, pos2) = (BlockPos(pos1.0 + offset), BlockPos(pos2.0 + offset));
+                    mask.prepare(world, selection, pos1, pos2).await;
+                    *blocks = Some(Arc::new(read_blocks(world, pos1, pos2).await));
+                }
+                Self::Biomes { cells, .. } => {
+                    let mut biomes = HashMap::new();
+                    read_biomes(world, pos1, pos2, |pos, biome_id| {
+                        biomes.insert((pos.0.x >> 2, pos.0.y >> 2, pos.0.z >> 2), biome_id);
+                    })
+                    .await;
+                    *cells = Some(Arc::new(biomes));
+                }
+                Self::Surface(blocks) => {
+                    let one = Vector3::new(1, 1, 1);
+                    let buffer =
+                        read_blocks(world, BlockPos(pos1.0 - one), BlockPos(pos2.0 + one)).await;
+                    *blocks = Some(Arc::new(buffer));
+                }
+                Self::Region(region) => {
+                    *region = selection.map(|(pos1, pos2)| {
+                        let (a, b) = (pos1.0, pos2.0);
+                        (
+                            BlockPos(Vector3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z))),
+                            BlockPos(Vector3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z))),
+                        )
+                    });
+                }
+                Self::Blocks(_) | Self::Expression(_) | Self::Random { .. } => {}
+            }
+        })
+    }
+
+    /// Whether the block at `pos`, currently in state `state_id`, matches the mask.
+    #[must_use]
+    pub fn test(&self, pos: BlockPos, state_id: u16) -> bool {
+        match self {
+            Self::Blocks(blocks) => blocks.contains(&block_of(state_id).id),
+            Self::Not(mask) => !mask.test(pos, state_id),
+            Self::All(masks) => masks.iter().all(|mask| mask.test(pos, state_id)),
+            Self::Any(masks) => masks.iter().any(|mask| mask.test(pos, state_id)),
+            Self::Expression(MaskExpression { expression, .. }) => {
+                let inputs = [pos.0.x, pos.0.y, pos.0.z].map(f64::from);
+                expression
+                    .lock()
+                    .is_ok_and(|mut expression| expression.evaluate(&inputs).is_ok_and(|v| v > 0.0))
+            }
+            Self::Offset { mask, dy, blocks } => {
+                let offset = BlockPos(pos.0 + Vector3::new(0, *dy, 0));
+                blocks
+                    .as_ref()
+                    .and_then(|blocks| blocks.get(offset))
+                    .is_some_and(|block_id| mask.test(offset, block_id))
+            }
+            Self::Random { seed, chance } => {
+                hash_position(*seed, pos.0.x, pos.0.y, pos.0.z) < *chance
+            }
+            Self::Biomes { biomes, cells } => cells
+                .as_ref()
+                .and_then(|cells| cells.get(&(pos.0.x >> 2, pos.0.y >> 2, pos.0.z >> 2)))
+                .is_some_and(|biome_id| biomes.contains(biome_id)),
+            Self::Surface(blocks) => {
+                !is_air(state_id)
+                    && blocks.as_ref().is_some_and(|blocks| {
+                        NEIGHBOURS.iter().any(|(dx, dy, dz)| {
+                            blocks
+                                .get(BlockPos(pos.0 + Vector3::new(*dx, *dy, *dz)))
+                                .is_some_and(is_air)
+                        })
+                    })
+            }
+            Self::Region(region) => region.is_some_and(|(min, max)| {
+                (min.0.x..=max.0.x).contains(&pos.0.x)
+                    && (min.0.y..=max.0.y).contains(&pos.0.y)
+                    && (min.0.z..=max.0.z).contains(&pos.0.z)
+            }),
+        }
+    }
+
+    /// Combines two optional masks into one that matches where both do.
+    #[must_use]
+    pub fn intersect(first: Option<Self>, second: Option<Self>) -> Option<Self> {
+        match (first, second) {
+            (Some(first), Some(second)) => Some(Self::All(vec![first, second])),
+            (first, second) => first.or(second),
+        }
+    }
+}
+
+/// Wraps the closure of an edit, as given to
+/// [`edit_blocks`](super::region::edit_blocks), so blocks `mask` doesn't match are
+/// left untouched.
+pub fn masked<F>(mask: Option<&Mask>, mut f: F) -> impl FnMut(BlockPos, u16) -> Option<u16> + Send
+where
+    F: FnMut(BlockPos, u16) -> Option<u16> + Send,
+{
+    move |pos, state_id| {
+        if mask.is_some_and(|mask| !mask.test(pos, state_id)) {
+            return None;
+        }
+        f(pos, state_id)
+    }
+}
+
+/// Parses the mask given to the (simple) argument `name`.
+pub fn find_mask(args: &ConsumedArgs<'_>, name: &str) -> Result<Mask, CommandError> {
+    let Some(Arg::Simple(mask)) = args.get(name) else {
+        return Err(CommandError::InvalidConsumption(Some(name.to_string())));
+    };
+    Mask::parse(mask).map_err(CommandError::GeneralCommandIssue)
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_data::Block;
+    use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+    use super::{Mask, split_entries};
+
+    fn pos(x: i32, y: i32, z: i32) -> BlockPos {
+        BlockPos(Vector3::new(x, y, z))
+    }
+
+    #[test]
+    fn test_split_entries() {
+        assert_eq!(split_entries("stone,dirt", ','), ["stone", "dirt"]);
+        assert_eq!(split_entries("stone&>dirt", '&'), ["stone", ">dirt"]);
+        assert_eq!(
+            split_entries("stone&=y<64&&x>0", '&'),
+            ["stone", "=y<64&&x>0"]
+        );
+        assert_eq!(split_entries("!=x,y", ','), ["!=x,y"]);
+    }
+
+    #[test]
+    fn test_parse() {
+        let (stone, dirt) = (Block::STONE.id, Block::DIRT.id);
+        let Ok(Mask::Blocks(ids)) = Mask::parse("stone,dirt") else {
+            panic!("expected a block mask");
+        };
+        assert_eq!(ids, [stone, dirt]);
+
+        // `&` joins lists, each of which may mix blocks with other entries.
+        let Ok(Mask::All(masks)) = Mask::parse("stone,%50&>dirt") else {
+            panic!("expected an intersection");
+        };
+        let [Mask::Any(any), Mask::Offset { dy: -1, .. }] = &masks[..] else {
+            panic!("expected a list and an offset, got {masks:?}");
+        };
+        assert!(matches!(&any[..], [Mask::Blocks(_), Mask::Random { .. }]));
+
+        // A leading `!` negates the whole list, one after `,` only its entry.
+        let Ok(Mask::Not(mask)) = Mask::parse("!stone,dirt") else {
+            panic!("expected a negation");
+        };
+        assert!(matches!(mask.as_ref(), Mask::Blocks(ids) if ids.len() == 2));
+        let Ok(Mask::Any(masks)) = Mask::parse("stone,!dirt") else {
+            panic!("expected a union");
+        };
+        assert!(matches!(&masks[..], [Mask::Blocks(_), Mask::Not(_)]));
+
+        assert!(matches!(
+            Mask::parse("<stone"),
+            Ok(Mask::Offset { dy: 1, .. })
+        ));
+        let Ok(Mask::Random { chance, .. }) = Mask::parse("%25") else {
+            panic!("expected a random mask");
+        };
+        assert!((chance - 0.25).abs() < f64::EPSILON);
+        assert!(Mask::parse("%101").is_err());
+        assert!(Mask::parse("%-1").is_err());
+        assert!(Mask::parse("#nothing").is_err());
+    }
+
+    #[test]
+    fn test_region() {
+        let region = Mask::Region(Some((pos(-2, 0, 0), pos(2, 5, 3))));
+        assert!(region.test(pos(-2, 0, 0), 0));
+        assert!(region.test(pos(2, 5, 3), 0));
+        assert!(!region.test(pos(3, 5, 3), 0));
+        assert!(!region.test(pos(0, -1, 1), 0));
+        // Without a selection, nothing is inside.
+        assert!(!Mask::Region(None).test(pos(0, 0, 0), 0));
+    }
+
+    #[test]
+    fn test_random_bounds() {
+        let never = Mask::Random {
+            seed: 7,
+            chance: 0.0,
+        };
+        let always = Mask::Random {
+            seed: 7,
+            chance: 1.0,
+        };
+        for x in -50..50 {
+            assert!(!never.test(pos(x, x * 3, -x), 0));
+            assert!(always.test(pos(x, x * 3, -x), 0));
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use pumpkin::entity::EntityBase;
+use pumpkin::entity::player::Player;
+use pumpkin::world::World;
+use pumpkin_data::Block;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::block::{is_air, is_solid};
+use super::direction::facing;
+use super::region::{edit_positions, max_y, min_y, read_blocks};
+use super::trace::target_block;
+
+/// How far `/jumpto` and the compass reach.
+pub const JUMP_RANGE: f64 = 300.0;
+
+/// How far `/thru` looks for free space behind a wall.
+const THRU_RANGE: i32 = 32;
+
+// The column helpers work on the solid flags of a column from the bottom up, where
+// the index of a block is its height above the world's bottom.
+
+/// Whether a player fits at index `i`, with room for their feet and head. Everything
+/// above the column counts as free.
+fn fits(solid: &[bool], i: usize) -> bool {
+    !solid.get(i).copied().unwrap_or(false) && !solid.get(i + 1).copied().unwrap_or(false)
+}
+
+/// Whether a player can stand at index `i`: they fit and there is ground below.
+fn can_stand(solid: &[bool], i: usize) -> bool {
+    i > 0 && solid.get(i - 1).copied().unwrap_or(false) && fits(solid, i)
+}
+
+/// The first spot above `from` a player can stand on.
+#[must_use]
+pub fn floor_above(solid: &[bool], from: usize) -> Option<usize> {
+    (from + 1..solid.len()).find(|i| can_stand(solid, *i))
+}
+
+/// The first spot below `from` a player can stand on.
+#[must_use]
+pub fn floor_below(solid: &[bool], from: usize) -> Option<usize> {
+    (1..from.min(solid.len()))
+        .rev()
+        .find(|i| can_stand(solid, *i))
+}
+
+/// The first spot at or above `from` a player fits in, regardless of ground.
+#[must_use]
+pub fn free_above(solid: &[bool], from: usize) -> Option<usize> {
+    (from..solid.len()).find(|i| fits(solid, *i))
+}
+
+/// The first solid block above the head of a player at `from`.
+#[must_use]
+pub fn ceiling_above(solid: &[bool], from: usize) -> Option<usize> {
+    (from + 2..solid.len()).find(|i| solid[*i])
+}
+
+/// The solid flags of a block column, from the world's bottom up.
+pub struct Column {
+    x: i32,
+    z: i32,
+    min_y: i32,
+    pub solid: Vec<bool>,
+}
+
+impl Column {
+    /// Reads the column at `x`, `z`.
+    pub async fn read(world: &World, x: i32, z: i32) -> Self {
+        let pos1 = BlockPos(Vector3::new(x, min_y(world), z));
+        let pos2 = BlockPos(Vector3::new(x, max_y(world), z));
+        let buffer = read_blocks(world, pos1, pos2).await;
+        let solid = (pos1.0.y..=pos2.0.y)
+            .map(|y| {
+                buffer
+                    .get(BlockPos(Vector3::new(x, y, z)))
+                    .is_some_and(is_solid)
+            })
+            .collect();
+        Self {
+            x,
+            z,
+            min_y: pos1.0.y,
+            solid,
+        }
+    }
+
+    /// The index of height `y`, clamped to the column.
+    #[must_use]
+    pub fn index(&self, y: i32) -> usize {
+        (y - self.min_y).clamp(0, self.solid.len() as i32) as usize
+    }
+
+    /// The position of index `i`.
+    #[must_use]
+    pub fn pos(&self, i: usize) -> BlockPos {
+        BlockPos(Vector3::new(self.x, self.min_y + i as i32, self.z))
+    }
+}
+
+/// Reads the column the player stands in, along with the index of their feet.
+pub async fn player_column(player: &Player) -> (Column, usize) {
+    let feet = BlockPos(player.position().to_i32());
+    let world = player.world().await;
+    let column = Column::read(&world, feet.0.x, feet.0.z).await;
+    let from = column.index(feet.0.y);
+    (column, from)
+}
+
+/// Moves the player to the centre of the block at `pos`, keeping where they look.
+pub async fn teleport(player: &Player, pos: BlockPos) {
+    let entity = player.get_entity();
+    let position = Vector3::new(
+        f64::from(pos.0.x) + 0.5,
+        f64::from(pos.0.y),
+        f64::from(pos.0.z) + 0.5,
+    );
+    player
+        .request_teleport(position, entity.yaw.load(), entity.pitch.load())
+        .await;
+}
+
+/// Places a glass block at `pos` if it is air, so the player has something to stand on.
+pub async fn place_platform(world: &World, pos: BlockPos) {
+    edit_positions(world, [pos], |_, block_id| {
+        is_air(block_id).then_some(Block::GLASS.default_state.id)
+    })
+    .await;
+}
+
+/// Moves the player on top of the block they are looking at.
+pub async fn jump_to(player: &Arc<Player>) -> Result<(), String> {
+    let Some(target) = target_block(player, JUMP_RANGE).await else {
+        return Err("No block in sight (or too far away)!".to_string());
+    };
+    let world = player.world().await;
+    let column = Column::read(&world, target.0.x, target.0.z).await;
+    let Some(i) = free_above(&column.solid, column.index(target.0.y + 1)) else {
+        return Err("There is no free spot above that block.".to_string());
+    };
+    teleport(player, column.pos(i)).await;
+    Ok(())
+}
+
+/// Moves the player through the wall in front of them, in the direction they face.
+pub async fn pass_through(player: &Arc<Player>) -> Result<(), String> {
+    let entity = player.get_entity();
+    let step = facing(entity.yaw.load(), entity.pitch.load());
+
+    let world = player.world().await;
+    let origin = BlockPos(player.position().to_i32());
+    let solid_at = async |pos: BlockPos| is_solid(world.get_block_state_id(&pos).await);
+
+    // Skip the free space in front of the player, then the wall, and stop at the first
+    // spot on the other side the player fits in.
+    let mut passed_wall = false;
+    let mut pos = origin;
+    for _ in 0..THRU_RANGE {
+        pos = BlockPos(pos.0 + step);
+        let head = BlockPos(pos.0 + Vector3::new(0, 1, 0));
+        if solid_at(pos).await || solid_at(head).await {
+            passed_wall = true;
+        } else if passed_wall {
+            teleport(player, pos).await;
+            return Ok(());
+        }
+    }
+    Err("No free spot ahead of you found.".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ceiling_above, floor_above, floor_below, free_above};
+
+    /// Ground at 0, a floor at 4 and a roof at 8, with the player standing at 1.
+    fn column() -> Vec<bool> {
+        let mut solid = vec![false; 12];
+        for i in [0, 4, 8] {
+            solid[i] = true;
+        }
+        solid
+    }
+
+    #[test]
+    fn test_column() {
+        let solid = column();
+        assert_eq!(floor_above(&solid, 1), Some(5));
+        assert_eq!(floor_above(&solid, 5), Some(9));
+        assert_eq!(floor_above(&solid, 9), None);
+        assert_eq!(floor_below(&solid, 9), Some(5));
+        assert_eq!(floor_below(&solid, 5), Some(1));
+        assert_eq!(floor_below(&solid, 1), None);
+        assert_eq!(free_above(&solid, 4), Some(5));
+        assert_eq!(free_above(&solid, 3), Some(5));
+        assert_eq!(ceiling_above(&solid, 1), Some(4));
+        assert_eq!(ceiling_above(&solid, 9), None);
+    }
+}
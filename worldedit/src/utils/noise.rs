@@ -0,0 +1,190 @@
+use super::random::{Random, mix64};
+
+/// Improved Perlin gradient noise with a seeded permutation table.
+///
+/// The fractal helpers ([`Perlin::fractal`], [`Perlin::ridged_multifractal`]) follow
+/// the libnoise modules WorldEdit exposes through its expression functions, so
+/// expressions written for WorldEdit produce similar shapes here.
+#[derive(Debug, Clone)]
+pub struct Perlin {
+    permutation: [u8; 512],
+}
+
+impl Perlin {
+    #[must_use]
+    pub fn new(seed: i64) -> Self {
+        let mut table: [u8; 256] = std::array::from_fn(|i| i as u8);
+        let mut random = Random::with_seed(seed as u64);
+        for i in (1..256).rev() {
+            let j = random.next_below(i as u64 + 1) as usize;
+            table.swap(i, j);
+        }
+        Self {
+            permutation: std::array::from_fn(|i| table[i & 255]),
+        }
+    }
+
+    /// Samples a single octave of noise. The result lies roughly in `[-1, 1]`.
+    #[must_use]
+    pub fn noise(&self, x: f64, y: f64, z: f64) -> f64 {
+        let p = &self.permutation;
+
+        let (xf, yf, zf) = (x.floor(), y.floor(), z.floor());
+        let (xi, yi, zi) = (
+            (xf as i64 & 255) as usize,
+            (yf as i64 & 255) as usize,
+            (zf as i64 & 255) as usize,
+        );
+        let (x, y, z) = (x - xf, y - yf, z - zf);
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let a = p[xi] as usize + yi;
+        let aa = p[a] as usize + zi;
+        let ab = p[a + 1] as usize + zi;
+        let b = p[xi + 1] as usize + yi;
+        let ba = p[b] as usize + zi;
+        let bb = p[b + 1] as usize + zi;
+
+        lerp(
+            w,
+            lerp(
+                v,
+                lerp(u, grad(p[aa], x, y, z), grad(p[ba], x - 1.0, y, z)),
+                lerp(
+                    u,
+                    grad(p[ab], x, y - 1.0, z),
+                    grad(p[bb], x - 1.0, y - 1.0, z),
+                ),
+            ),
+            lerp(
+                v,
+                lerp(
+                    u,
+                    grad(p[aa + 1], x, y, z - 1.0),
+                    grad(p[ba + 1], x - 1.0, y, z - 1.0),
+                ),
+                lerp(
+                    u,
+                    grad(p[ab + 1], x, y - 1.0, z - 1.0),
+                    grad(p[bb + 1], x - 1.0, y - 1.0, z - 1.0),
+                ),
+            ),
+        )
+    }
+
+    /// Sums `octaves` layers of noise, each at double the frequency and `persistence`
+    /// times the amplitude of the previous one. The result is normalized to `[-1, 1]`.
+    #[must_use]
+    pub fn fractal(
+        &self,
+        x: f64,
+        y: f64,
+        z: f64,
+        frequency: f64,
+        octaves: u32,
+        persistence: f64,
+    ) -> f64 {
+        let (mut x, mut y, mut z) = (x * frequency, y * frequency, z * frequency);
+        let mut amplitude = 1.0;
+        let mut total = 0.0;
+        let mut max = 0.0;
+        for _ in 0..octaves.max(1) {
+            total += self.noise(x, y, z) * amplitude;
+            max += amplitude;
+            amplitude *= persistence;
+            x *= 2.0;
+            y *= 2.0;
+            z *= 2.0;
+        }
+        (total / max).clamp(-1.0, 1.0)
+    }
+
+    /// Ridged multifractal noise, which produces sharp crests useful for mountain ranges.
+    /// The result is normalized to `[-1, 1]`.
+    #[must_use]
+    pub fn ridged_multifractal(&self, x: f64, y: f64, z: f64, frequency: f64, octaves: u32) -> f64 {
+        const LACUNARITY: f64 = 2.0;
+        const OFFSET: f64 = 1.0;
+        const GAIN: f64 = 2.0;
+
+        let (mut x, mut y, mut z) = (x * frequency, y * frequency, z * frequency);
+        let mut total = 0.0;
+        let mut weight = 1.0;
+        let mut max = 0.0;
+        let mut spectral = 1.0;
+        for _ in 0..octaves.max(1) {
+            let mut signal = OFFSET - self.noise(x, y, z).abs();
+            signal *= signal * weight;
+            weight = (signal * GAIN).clamp(0.0, 1.0);
+
+            total += signal * spectral;
+            max += spectral;
+            spectral /= LACUNARITY;
+            x *= LACUNARITY;
+            y *= LACUNARITY;
+            z *= LACUNARITY;
+        }
+        (total / max * 2.0 - 1.0).clamp(-1.0, 1.0)
+    }
+}
+
+/// Cellular noise: space is split into cells each holding one jittered feature point,
+/// and every position takes a constant value of its nearest feature point.
+/// The result lies in `[-1, 1]`.
+#[must_use]
+pub fn voronoi(seed: i64, x: f64, y: f64, z: f64, frequency: f64) -> f64 {
+    let (x, y, z) = (x * frequency, y * frequency, z * frequency);
+    let (cx, cy, cz) = (x.floor() as i64, y.floor() as i64, z.floor() as i64);
+
+    let mut nearest = f64::MAX;
+    let mut value = 0.0;
+    for dx in -1..=1 {
+        for dy in -1..=1 {
+            for dz in -1..=1 {
+                let (ix, iy, iz) = (cx + dx, cy + dy, cz + dz);
+                let h = cell_hash(seed, ix, iy, iz);
+                let px = ix as f64 + unit(h);
+                let py = iy as f64 + unit(mix64(h ^ 1));
+                let pz = iz as f64 + unit(mix64(h ^ 2));
+                let distance = (px - x).powi(2) + (py - y).powi(2) + (pz - z).powi(2);
+                if distance < nearest {
+                    nearest = distance;
+                    value = unit(mix64(h ^ 3)) * 2.0 - 1.0;
+                }
+            }
+        }
+    }
+    value
+}
+
+fn cell_hash(seed: i64, x: i64, y: i64, z: i64) -> u64 {
+    let mut h = mix64(seed as u64);
+    h = mix64(h ^ x as u64);
+    h = mix64(h ^ y as u64);
+    mix64(h ^ z as u64)
+}
+
+fn unit(h: u64) -> f64 {
+    (h >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+fn grad(hash: u8, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
@@ -0,0 +1,400 @@
+use std::fmt;
+use std::sync::Arc;
+
+use pumpkin::command::args::{Arg, ConsumedArgs};
+use pumpkin::command::dispatcher::CommandError;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::{
+    block::{block_of, parse_block, state_from_properties, with_property},
+    clipboard::Clipboard,
+    noise::Perlin,
+    random::{Random, hash_position},
+};
+
+/// How many octaves of noise `#perlin` patterns sum up.
+const NOISE_OCTAVES: u32 = 4;
+
+/// A pattern decides which block state is placed at each position of an edit.
+///
+/// Patterns are written as a comma separated list of entries, each optionally
+/// prefixed by a weight, e.g. `stone` or `70%stone,30%cobblestone`. Entries without a
+/// weight count as `1`. An entry is one of:
+/// - a block, placed in its default state,
+/// - `^block[prop=value,...]`, which changes the type and the given properties of the
+///   existing block but keeps its other properties; either part may be left out,
+///   e.g. `^[waterlogged=true]`,
+/// - `#clipboard@[x,y,z]` (or `#copy`), which tiles the clipboard, shifted by the
+///   optional offset.
+///
+/// A whole list may also be prefixed by `#perlin[scale]`, which picks the entries
+/// by smooth noise so they form patches about `scale` blocks across, or by
+/// `#gradient[axis]`, which blends from the first entry to the last across the
+/// selection along `x`, `y` or `z`, or outwards from its centre with `radial`.
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    Block(u16),
+    Random {
+        seed: u64,
+        total: f64,
+        entries: Vec<(f64, Pattern)>,
+    },
+    Noise {
+        noise: Arc<Perlin>,
+        scale: f64,
+        total: f64,
+        entries: Vec<(f64, Pattern)>,
+    },
+    Gradient {
+        shape: GradientShape,
+        seed: u64,
+        /// The selection the gradient spans, normalized.
+        min: Vector3<f64>,
+        max: Vector3<f64>,
+        stops: Vec<Pattern>,
+    },
+    /// Changes the existing block to the default state of `block` if given, keeping
+    /// the properties both blocks have, then sets `properties`.
+    TypeApply {
+        block: Option<u16>,
+        properties: Vec<(String, String)>,
+    },
+    Clipboard(ClipboardPattern),
+}
+
+/// Which way a gradient runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientShape {
+    X,
+    Y,
+    Z,
+    Radial,
+}
+
+/// The clipboard, repeated in every direction.
+#[derive(Clone)]
+pub struct ClipboardPattern {
+    clipboard: Arc<Clipboard>,
+    offset: Vector3<i32>,
+}
+
+impl fmt::Debug for ClipboardPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#clipboard@[{},{},{}]",
+            self.offset.x, self.offset.y, self.offset.z
+        )
+    }
+}
+
+/// What patterns may refer to besides blocks.
+#[derive(Default)]
+pub struct PatternContext {
+    pub clipboard: Option<Arc<Clipboard>>,
+    pub selection: Option<(BlockPos, BlockPos)>,
+}
+
+/// Splits `input` at every comma outside of square brackets.
+fn split_entries(input: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ',' if depth == 0 => {
+                entries.push(&input[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push(&input[start..]);
+    entries
+}
+
+/// Splits `[argument]rest` into the argument and the rest.
+fn split_argument(input: &str) -> Option<(&str, &str)> {
+    input.strip_prefix('[')?.split_once(']')
+}
+
+fn to_f64(v: Vector3<i32>) -> Vector3<f64> {
+    Vector3::new(f64::from(v.x), f64::from(v.y), f64::from(v.z))
+}
+
+/// The entry of a weighted list a roll in `[0, 1)` lands on.
+fn pick(entries: &[(f64, Pattern)], total: f64, roll: f64) -> &Pattern {
+    let mut roll = roll * total;
+    for (weight, pattern) in entries {
+        if roll < *weight {
+            return pattern;
+        }
+        roll -= weight;
+    }
+    &entries[entries.len() - 1].1
+}
+
+/// The stop of a gradient with `stops` stops at `t` in `[0, 1]`. Between two stops,
+/// a roll in `[0, 1)` decides, so the stops blend into each other.
+fn gradient_stop(t: f64, stops: usize, roll: f64) -> usize {
+    let t = t.clamp(0.0, 1.0) * (stops - 1) as f64;
+    let below = t.floor();
+    if roll < t - below {
+        below as usize + 1
+    } else {
+        below as usize
+    }
+}
+
+impl Pattern {
+    pub fn parse(input: &str, context: &PatternContext) -> Result<Self, String> {
+        if let Some(rest) = input.strip_prefix("#perlin") {
+            let (scale, list) =
+                split_argument(rest).ok_or("Usage: #perlin[scale]<pattern>".to_string())?;
+            let scale: f64 = scale
+                .parse()
+                .ok()
+                .filter(|scale| *scale > 0.0)
+                .ok_or_else(|| format!("Invalid scale '{scale}'."))?;
+            let entries = Self::parse_weighted(list, context)?;
+            return Ok(Self::Noise {
+                noise: Arc::new(Perlin::new(Random::new().next_u64() as i64)),
+                scale,
+                total: entries.iter().map(|(weight, _)| weight).sum(),
+                entries,
+            });
+        }
+        if let Some(rest) = input.strip_prefix("#gradient") {
+            let (shape, list) = split_argument(rest)
+                .ok_or("Usage: #gradient[x|y|z|radial]<patterns>".to_string())?;
+            let shape = match shape.to_ascii_lowercase().as_str() {
+                "x" => GradientShape::X,
+                "y" => GradientShape::Y,
+                "z" => GradientShape::Z,
+                "radial" => GradientShape::Radial,
+                _ => return Err(format!("Unknown gradient '{shape}'.")),
+            };
+            let Some((pos1, pos2)) = context.selection else {
+                return Err("Gradients span your selection, make one first.".to_string());
+            };
+            let stops = split_entries(list)
+                .into_iter()
+                .map(|entry| Self::parse_entry(entry, context))
+                .collect::<Result<Vec<_>, _>>()?;
+            if stops.len() < 2 {
+                return Err("A gradient needs at least two patterns.".to_string());
+            }
+            let (a, b) = (to_f64(pos1.0), to_f64(pos2.0));
+            return Ok(Self::Gradient {
+                shape,
+                seed: Random::new().next_u64(),
+                min: Vector3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+                max: Vector3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+                stops,
+            });
+        }
+
+        let mut entries = Self::parse_weighted(input, context)?;
+        if entries.len() == 1 {
+            Ok(entries.remove(0).1)
+        } else {
+            Ok(Self::Random {
+                seed: Random::new().next_u64(),
+                total: entries.iter().map(|(weight, _)| weight).sum(),
+                entries,
+            })
+        }
+    }
+
+    fn parse_weighted(input: &str, context: &PatternContext) -> Result<Vec<(f64, Self)>, String> {
+        let mut entries = Vec::new();
+        for entry in split_entries(input) {
+            let (weight, entry) = match entry.split_once('%') {
+                Some((weight, entry)) => {
+                    let weight: f64 = weight
+                        .parse()
+                        .map_err(|_| format!("Invalid weight '{weight}'."))?;
+                    if weight <= 0.0 {
+                        return Err(format!("Invalid weight '{weight}'."));
+                    }
+                    (weight, entry)
+                }
+                None => (1.0, entry),
+            };
+            entries.push((weight, Self::parse_entry(entry, context)?));
+        }
+        Ok(entries)
+    }
+
+    fn parse_entry(input: &str, context: &PatternContext) -> Result<Self, String> {
+        if let Some(rest) = input.strip_prefix('^') {
+            let (name, properties) = match rest.split_once('[') {
+                Some((name, properties)) => (
+                    name,
+                    properties
+                        .strip_suffix(']')
+                        .ok_or_else(|| format!("Missing ']' in '{input}'."))?,
+                ),
+                None => (rest, ""),
+            };
+            let block = (!name.is_empty())
+                .then(|| parse_block(name))
+                .transpose()?
+                .map(|block| block.default_state.id);
+            let properties = properties
+                .split(',')
+                .filter(|property| !property.is_empty())
+                .map(|property| {
+                    let (key, value) = property
+                        .split_once('=')
+                        .ok_or_else(|| format!("Invalid property '{property}'."))?;
+                    if block.is_some_and(|state_id| with_property(state_id, key, value).is_none()) {
+                        return Err(format!("Invalid property '{property}'."));
+                    }
+                    Ok((key.to_string(), value.to_string()))
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            return Ok(Self::TypeApply { block, properties });
+        }
+        if let Some(rest) = input.strip_prefix('#') {
+            let (name, offset) = rest.split_once('@').unwrap_or((rest, ""));
+            if !matches!(name.to_ascii_lowercase().as_str(), "clipboard" | "copy") {
+                return Err(format!("Unknown pattern '#{name}'."));
+            }
+            let offset = if offset.is_empty() {
+                Vector3::new(0, 0, 0)
+            } else {
+                let coords = split_argument(offset)
+                    .filter(|(_, rest)| rest.is_empty())
+                    .map(|(coords, _)| coords.split(',').map(str::parse).collect::<Vec<_>>());
+                match coords.as_deref() {
+                    Some([Ok(x), Ok(y), Ok(z)]) => Vector3::new(*x, *y, *z),
+                    _ => return Err(format!("Invalid offset '{offset}'.")),
+                }
+            };
+            let clipboard = context
+                .clipboard
+                .clone()
+                .ok_or("Your clipboard is empty. Use //copy first.".to_string())?;
+            return Ok(Self::Clipboard(ClipboardPattern { clipboard, offset }));
+        }
+        Ok(Self::Block(parse_block(input)?.default_state.id))
+    }
+
+    /// Returns the block state to place at `pos`, where the block is currently in
+    /// state `state_id`.
+    #[must_use]
+    pub fn apply(&self, pos: BlockPos, state_id: u16) -> u16 {
+        match self {
+            Self::Block(state_id) => *state_id,
+            Self::Random {
+                seed,
+                total,
+                entries,
+            } => {
+                let roll = hash_position(*seed, pos.0.x, pos.0.y, pos.0.z);
+                pick(entries, *total, roll).apply(pos, state_id)
+            }
+            Self::Noise {
+                noise,
+                scale,
+                total,
+                entries,
+            } => {
+                let value = noise.fractal(
+                    f64::from(pos.0.x),
+                    f64::from(pos.0.y),
+                    f64::from(pos.0.z),
+                    1.0 / scale,
+                    NOISE_OCTAVES,
+                    0.5,
+                );
+                let roll = ((value + 1.0) / 2.0).clamp(0.0, 1.0 - f64::EPSILON);
+                pick(entries, *total, roll).apply(pos, state_id)
+            }
+            Self::Gradient {
+                shape,
+                seed,
+                min,
+                max,
+                stops,
+            } => {
+                let at = to_f64(pos.0);
+                let t = match shape {
+                    GradientShape::X => (at.x - min.x) / (max.x - min.x).max(1.0),
+                    GradientShape::Y => (at.y - min.y) / (max.y - min.y).max(1.0),
+                    GradientShape::Z => (at.z - min.z) / (max.z - min.z).max(1.0),
+                    GradientShape::Radial => {
+                        let center = (*min + *max) * 0.5;
+                        (at - center).length() / (*max - center).length().max(1.0)
+                    }
+                };
+                let roll = hash_position(*seed, pos.0.x, pos.0.y, pos.0.z);
+                stops[gradient_stop(t, stops.len(), roll)].apply(pos, state_id)
+            }
+            Self::TypeApply { block, properties } => {
+                let mut new = match block {
+                    Some(default) => {
+                        let current = block_of(state_id)
+                            .properties(state_id)
+                            .map(|props| props.to_props())
+                            .unwrap_or_default();
+                        state_from_properties(block_of(*default).name, &current).unwrap_or(*default)
+                    }
+                    None => state_id,
+                };
+                for (key, value) in properties {
+                    new = with_property(new, key, value).unwrap_or(new);
+                }
+                new
+            }
+            Self::Clipboard(ClipboardPattern { clipboard, offset }) => {
+                let size = clipboard.size();
+                let at = pos.0 + *offset;
+                let relative = Vector3::new(
+                    at.x.rem_euclid(size.x),
+                    at.y.rem_euclid(size.y),
+                    at.z.rem_euclid(size.z),
+                );
+                clipboard.get(relative).unwrap_or(state_id)
+            }
+        }
+    }
+}
+
+/// Parses the pattern given to the (simple) argument `name`.
+pub fn find_pattern(
+    args: &ConsumedArgs<'_>,
+    name: &str,
+    context: &PatternContext,
+) -> Result<Pattern, CommandError> {
+    let Some(Arg::Simple(pattern)) = args.get(name) else {
+        return Err(CommandError::InvalidConsumption(Some(name.to_string())));
+    };
+    Pattern::parse(pattern, context).map_err(CommandError::GeneralCommandIssue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gradient_stop, split_entries};
+
+    #[test]
+    fn test_split_entries() {
+        assert_eq!(split_entries("stone,dirt"), ["stone", "dirt"]);
+        assert_eq!(
+            split_entries("^[axis=x,waterlogged=true],#copy@[1,0,-2]"),
+            ["^[axis=x,waterlogged=true]", "#copy@[1,0,-2]"]
+        );
+    }
+
+    #[test]
+    fn test_gradient_stop() {
+        assert_eq!(gradient_stop(0.0, 3, 0.0), 0);
+        assert_eq!(gradient_stop(1.0, 3, 0.99), 2);
+        assert_eq!(gradient_stop(0.25, 3, 0.4), 1);
+        assert_eq!(gradient_stop(0.25, 3, 0.6), 0);
+        assert_eq!(gradient_stop(-1.0, 2, 0.5), 0);
+    }
+}
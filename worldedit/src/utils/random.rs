@@ -0,0 +1,65 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A small, fast pseudo random number generator (SplitMix64).
+///
+/// This is not cryptographically secure; it only has to be good enough to
+/// scatter blocks in patterns and to back the `rand()` expression function.
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    /// Creates a generator with a fixed seed, yielding a reproducible sequence.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Creates a generator seeded from the system clock.
+    #[must_use]
+    pub fn new() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        Self::with_seed(nanos)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        mix64(self.state)
+    }
+
+    /// Returns a value in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a value in `[0, bound)`. `bound` must be non-zero.
+    pub fn next_below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The SplitMix64 finalizer, also handy as a stateless integer hash.
+#[must_use]
+pub fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Hashes a block position together with a seed into a value in `[0, 1)`.
+#[must_use]
+pub fn hash_position(seed: u64, x: i32, y: i32, z: i32) -> f64 {
+    let mut h = mix64(seed ^ (x as u32 as u64));
+    h = mix64(h ^ ((y as u32 as u64) << 21));
+    h = mix64(h ^ ((z as u32 as u64) << 42));
+    (h >> 11) as f64 / (1u64 << 53) as f64
+}
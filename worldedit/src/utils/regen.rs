@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use pumpkin::block::registry::BlockRegistry;
+use pumpkin::world::World;
+use pumpkin_util::math::{position::BlockPos, vector2::Vector2};
+use pumpkin_world::chunk::ChunkData;
+use pumpkin_world::generation::generator::WorldGenerator;
+use pumpkin_world::generation::{Seed, get_world_gen};
+use pumpkin_world::level::Level;
+use tokio::task::JoinError;
+
+use super::region::min_y;
+
+/// The world's generator, or a generator for the same dimension with a different
+/// seed, built once and shared by every chunk of an edit.
+#[derive(Clone)]
+pub struct Generator {
+    world_gen: Arc<dyn WorldGenerator>,
+    level: Arc<Level>,
+    block_registry: Arc<BlockRegistry>,
+    min_y: i32,
+}
+
+impl Generator {
+    #[must_use]
+    pub fn new(world: &World, seed: Option<u64>) -> Self {
+        let world_gen = match seed {
+            Some(seed) => Arc::from(get_world_gen(Seed(seed), world.dimension_type)),
+            None => world.level.world_gen.clone(),
+        };
+        Self {
+            world_gen,
+            level: world.level.clone(),
+            block_registry: world.block_registry.clone(),
+            min_y: min_y(world),
+        }
+    }
+
+    /// Runs the generator for the chunk at `at` on the blocking thread pool. Nothing
+    /// is written to the world.
+    pub async fn generate(&self, at: Vector2<i32>) -> Result<GeneratedChunk, JoinError> {
+        let generator = self.clone();
+        let chunk = tokio::task::spawn_blocking(move || {
+            generator.world_gen.generate_chunk(
+                &generator.level,
+                generator.block_registry.as_ref(),
+                &at,
+            )
+        })
+        .await?;
+        Ok(GeneratedChunk {
+            chunk,
+            min_y: self.min_y,
+        })
+    }
+}
+
+/// A chunk freshly generated by the world generator, detached from the world.
+pub struct GeneratedChunk {
+    chunk: ChunkData,
+    min_y: i32,
+}
+
+impl GeneratedChunk {
+    /// Returns the generated state at `pos`, which must lie inside this chunk.
+    #[must_use]
+    pub fn block(&self, pos: BlockPos) -> Option<u16> {
+        let y = pos.0.y - self.min_y;
+        let section = self
+            .chunk
+            .section
+            .sections
+            .get(usize::try_from(y >> 4).ok()?)?;
+        Some(section.block_states.get(
+            (pos.0.x & 15) as usize,
+            (y & 15) as usize,
+            (pos.0.z & 15) as usize,
+        ))
+    }
+
+    /// Returns the generated biome of the cell containing `pos`, which must lie inside
+    /// this chunk.
+    #[must_use]
+    pub fn biome(&self, pos: BlockPos) -> Option<u8> {
+        let y = pos.0.y - self.min_y;
+        let section = self
+            .chunk
+            .section
+            .sections
+            .get(usize::try_from(y >> 4).ok()?)?;
+        Some(section.biomes.get(
+            ((pos.0.x & 15) >> 2) as usize,
+            ((y & 15) >> 2) as usize,
+            ((pos.0.z & 15) >> 2) as usize,
+        ))
+    }
+}
+
+/// Turns a seed argument into a seed: numbers are used as is, anything else is
+/// hashed like vanilla does for the world seed.
+#[must_use]
+pub fn parse_seed(input: &str) -> u64 {
+    input.parse::<i64>().map_or_else(
+        |_| {
+            // Java's String.hashCode.
+            input.encode_utf16().fold(0i32, |hash, c| {
+                hash.wrapping_mul(31).wrapping_add(i32::from(c))
+            }) as u64
+        },
+        |seed| seed as u64,
+    )
+}
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+pub use common::region::{
+    BlockBuffer, NEIGHBOURS, bounds, edit_blocks, edit_positions, max_y, min_y, read_blocks,
+};
+use pumpkin::block::entities::{BlockEntity, block_entity_from_nbt};
+use pumpkin::entity::player::Player;
+use pumpkin::world::World;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_protocol::client::play::CChunkData;
+use pumpkin_util::math::{position::BlockPos, vector2::Vector2, vector3::Vector3};
+use pumpkin_world::world::BlockFlags;
+
+use super::chunked_range::ChunkedRange;
+
+/// Visits every 4x4x4 biome cell intersecting the cuboid spanned by `pos1` and `pos2`
+/// (which must be normalized) and replaces its biome with the id returned by `f`, if any.
+///
+/// `f` receives the cell's lowest corner clamped into the cuboid, and the current biome
+/// id. Chunks with changed biomes are resent to the clients, as there is no packet
+/// to update a single biome cell.
+///
+/// Returns the number of biome cells that were changed.
+pub async fn edit_biomes<F>(world: &World, pos1: BlockPos, pos2: BlockPos, mut f: F) -> usize
+where
+    F: FnMut(BlockPos, u8) -> Option<u8> + Send,
+{
+    let min_y = min_y(world);
+
+    let (x1, x2) = (pos1.0.x, pos2.0.x);
+    let (z1, z2) = (pos1.0.z, pos2.0.z);
+    let (y1, y2) = (pos1.0.y - min_y, pos2.0.y - min_y);
+
+    let mut total_cnt = 0;
+
+    for (chunk_x, x_range) in ChunkedRange::new(x1..=x2) {
+        for (chunk_z, z_range) in ChunkedRange::new(z1..=z2) {
+            let chunk = world.level.get_chunk(Vector2::new(chunk_x, chunk_z)).await;
+            let mut chunk = chunk.write().await;
+            let mut cnt = 0;
+            for (chunk_y, y_range) in ChunkedRange::new(y1..=y2) {
+                let Some(section) = chunk.section.sections.get_mut(chunk_y as usize) else {
+                    continue;
+                };
+                for cell_x in (x_range.start() >> 2)..=(x_range.end() >> 2) {
+                    for cell_z in (z_range.start() >> 2)..=(z_range.end() >> 2) {
+                        for cell_y in (y_range.start() >> 2)..=(y_range.end() >> 2) {
+                            let block_pos = BlockPos(Vector3::new(
+                                (chunk_x << 4) + (cell_x << 2).max(*x_range.start()),
+                                (chunk_y << 4) + (cell_y << 2).max(*y_range.start()) + min_y,
+                                (chunk_z << 4) + (cell_z << 2).max(*z_range.start()),
+                            ));
+                            let (x, y, z) = (cell_x as usize, cell_y as usize, cell_z as usize);
+                            let cur_biome_id = section.biomes.get(x, y, z);
+                            if let Some(biome_id) = f(block_pos, cur_biome_id) {
+                                if biome_id != cur_biome_id {
+                                    section.biomes.set(x, y, z, biome_id);
+                                    cnt += 1;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if cnt != 0 {
+                chunk.dirty = true;
+                total_cnt += cnt;
+                world.broadcast_packet_all(&CChunkData(&chunk)).await;
+            }
+            drop(chunk);
+        }
+    }
+
+    total_cnt
+}
+
+/// Visits every 4x4x4 biome cell intersecting the cuboid spanned by `pos1` and `pos2`
+/// (which must be normalized), calling `f` with the cell's lowest corner clamped into
+/// the cuboid and its biome id. Unlike [`edit_biomes`], the chunks are only read.
+pub async fn read_biomes<F>(world: &World, pos1: BlockPos, pos2: BlockPos, mut f: F)
+where
+    F: FnMut(BlockPos, u8) + Send,
+{
+    let min_y = min_y(world);
+
+    let (x1, x2) = (pos1.0.x, pos2.0.x);
+    let (z1, z2) = (pos1.0.z, pos2.0.z);
+    let (y1, y2) = (pos1.0.y - min_y, pos2.0.y - min_y);
+
+    for (chunk_x, x_range) in ChunkedRange::new(x1..=x2) {
+        for (chunk_z, z_range) in ChunkedRange::new(z1..=z2) {
+            let chunk = world.level.get_chunk(Vector2::new(chunk_x, chunk_z)).await;
+            let chunk = chunk.read().await;
+            for (chunk_y, y_range) in ChunkedRange::new(y1..=y2) {
+                let Some(section) = chunk.section.sections.get(chunk_y as usize) else {
+                    continue;
+                };
+                for cell_x in (x_range.start() >> 2)..=(x_range.end() >> 2) {
+                    for cell_z in (z_range.start() >> 2)..=(z_range.end() >> 2) {
+                        for cell_y in (y_range.start() >> 2)..=(y_range.end() >> 2) {
+                            let block_pos = BlockPos(Vector3::new(
+                                (chunk_x << 4) + (cell_x << 2).max(*x_range.start()),
+                                (chunk_y << 4) + (cell_y << 2).max(*y_range.start()) + min_y,
+                                (chunk_z << 4) + (cell_z << 2).max(*z_range.start()),
+                            ));
+                            let (x, y, z) = (cell_x as usize, cell_y as usize, cell_z as usize);
+                            f(block_pos, section.biomes.get(x, y, z));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Serializes the block entities (chests, signs, ...) inside the cuboid spanned by
+/// `pos1` and `pos2` (which must be normalized), together with their positions.
+pub async fn read_block_entities(
+    world: &World,
+    pos1: BlockPos,
+    pos2: BlockPos,
+) -> Vec<(BlockPos, NbtCompound)> {
+    let inside = |pos: &BlockPos| {
+        (pos1.0.x..=pos2.0.x).contains(&pos.0.x)
+            && (pos1.0.y..=pos2.0.y).contains(&pos.0.y)
+            && (pos1.0.z..=pos2.0.z).contains(&pos.0.z)
+    };
+
+    let mut result = Vec::new();
+    for (chunk_x, _) in ChunkedRange::new(pos1.0.x..=pos2.0.x) {
+        for (chunk_z, _) in ChunkedRange::new(pos1.0.z..=pos2.0.z) {
+            let chunk = world.level.get_chunk(Vector2::new(chunk_x, chunk_z)).await;
+            let block_entities: Vec<_> = chunk
+                .read()
+                .await
+                .block_entities
+                .iter()
+                .filter(|(pos, _)| inside(pos))
+                .map(|(pos, block_entity)| (*pos, block_entity.clone()))
+                .collect();
+            for (pos, block_entity) in block_entities {
+                result.push((pos, block_entity_nbt(block_entity.as_ref()).await));
+            }
+        }
+    }
+    result
+}
+
+/// Returns the positions of the block entities inside the cuboid spanned by `pos1`
+/// and `pos2` (which must be normalized), without serializing them like
+/// [`read_block_entities`].
+pub async fn read_block_entity_positions(
+    world: &World,
+    pos1: BlockPos,
+    pos2: BlockPos,
+) -> Vec<BlockPos> {
+    let inside = |pos: &BlockPos| {
+        (pos1.0.x..=pos2.0.x).contains(&pos.0.x)
+            && (pos1.0.y..=pos2.0.y).contains(&pos.0.y)
+            && (pos1.0.z..=pos2.0.z).contains(&pos.0.z)
+    };
+
+    let mut result = Vec::new();
+    for (chunk_x, _) in ChunkedRange::new(pos1.0.x..=pos2.0.x) {
+        for (chunk_z, _) in ChunkedRange::new(pos1.0.z..=pos2.0.z) {
+            let chunk = world.level.get_chunk(Vector2::new(chunk_x, chunk_z)).await;
+            result.extend(
+                chunk
+                    .read()
+                    .await
+                    .block_entities
+                    .keys()
+                    .filter(|pos| inside(pos)),
+            );
+        }
+    }
+    result
+}
+
+/// Serializes a block entity together with its id, so [`block_entity_at`] can
+/// rebuild it.
+async fn block_entity_nbt(block_entity: &dyn BlockEntity) -> NbtCompound {
+    let mut nbt = NbtCompound::new();
+    block_entity.write_nbt(&mut nbt).await;
+    nbt
+}
+
+/// Rebuilds a block entity serialized by [`block_entity_nbt`], moved to `pos`.
+fn block_entity_at(mut nbt: NbtCompound, pos: BlockPos) -> Option<Arc<dyn BlockEntity>> {
+    nbt.put_int("x", pos.0.x);
+    nbt.put_int("y", pos.0.y);
+    nbt.put_int("z", pos.0.z);
+    block_entity_from_nbt(&nbt)
+}
+
+/// Places a block entity read by [`read_block_entities`] at `pos`.
+pub async fn place_block_entity(world: &World, pos: BlockPos, nbt: NbtCompound) {
+    if let Some(block_entity) = block_entity_at(nbt, pos) {
+        world.add_block_entity(block_entity).await;
+    }
+}
+
+/// Returns the block light and sky light levels at `pos`, or `None` if it lies
+/// outside the world's height.
+pub async fn read_light(world: &World, pos: BlockPos) -> Option<(u8, u8)> {
+    let y = pos.0.y - min_y(world);
+    if y < 0 || pos.0.y > max_y(world) {
+        return None;
+    }
+    let (x, z) = (pos.0.x & 15, pos.0.z & 15);
+    let chunk = world
+        .level
+        .get_chunk(Vector2::new(pos.0.x >> 4, pos.0.z >> 4))
+        .await;
+    let chunk = chunk.read().await;
+
+    // The light arrays may carry an extra section below and above the world.
+    let sections = chunk.section.sections.len();
+    let light = &chunk.light_engine;
+    let index = |len: usize| (y >> 4) as usize + len.saturating_sub(sections) / 2;
+    let (x, y, z) = (x as usize, (y & 15) as usize, z as usize);
+    let block = light
+        .block_light
+        .get(index(light.block_light.len()))
+        .map_or(0, |section| section.get(x, y, z));
+    let sky = light
+        .sky_light
+        .get(index(light.sky_light.len()))
+        .map_or(0, |section| section.get(x, y, z));
+    Some((block, sky))
+}
+
+/// Breaks the blocks at `positions` as if `player` mined them, so they drop their
+/// items. Slower than [`edit_positions`], as every block is broken on its own.
+pub async fn break_blocks<I>(world: &Arc<World>, positions: I, player: &Arc<Player>) -> usize
+where
+    I: IntoIterator<Item = BlockPos>,
+{
+    let mut cnt = 0;
+    for pos in positions {
+        world
+            .break_block(&pos, Some(player.clone()), BlockFlags::NOTIFY_NEIGHBORS)
+            .await;
+        cnt += 1;
+    }
+    cnt
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin::block::entities::chest::ChestBlockEntity;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_block_entity_round_trip() {
+        let chest = ChestBlockEntity::new(BlockPos(Vector3::new(1, 64, 2)));
+        let nbt = block_entity_nbt(&chest).await;
+
+        let pos = BlockPos(Vector3::new(-5, 10, 7));
+        let block_entity = block_entity_at(nbt, pos).expect("the id is serialized");
+        assert_eq!(block_entity.resource_location(), chest.resource_location());
+        assert_eq!(block_entity.get_position(), pos);
+    }
+}
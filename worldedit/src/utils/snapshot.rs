@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use flate2::read::GzDecoder;
+use pumpkin::world::World;
+use pumpkin_registry::VanillaDimensionType;
+
+/// How a backup is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotKind {
+    Folder,
+    Zip,
+    TarGz,
+}
+
+/// A world backup in the snapshot directory.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// The file or folder name, which is what players refer to it by.
+    pub name: String,
+    pub path: PathBuf,
+    pub kind: SnapshotKind,
+    /// Seconds since the epoch, taken from a date in the name or else the
+    /// modification time.
+    pub time: u64,
+}
+
+/// Lists the snapshots in `dir`, oldest first.
+pub fn list_snapshots(dir: &Path) -> Result<Vec<Snapshot>, String> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|err| format!("Failed to read snapshot directory {}: {err}", dir.display()))?;
+
+    let mut snapshots = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let kind = if metadata.is_dir() {
+            SnapshotKind::Folder
+        } else if name.ends_with(".zip") {
+            SnapshotKind::Zip
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            SnapshotKind::TarGz
+        } else {
+            continue;
+        };
+        let time = parse_date(&name).unwrap_or_else(|| {
+            metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |duration| duration.as_secs())
+        });
+        snapshots.push(Snapshot {
+            name,
+            path: entry.path(),
+            kind,
+            time,
+        });
+    }
+    snapshots.sort_by(|a, b| a.time.cmp(&b.time).then_with(|| a.name.cmp(&b.name)));
+    Ok(snapshots)
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Finds a date like `2024-01-31`, optionally followed by a time like `12-30-00` or
+/// `12:30`, and returns it as seconds since the epoch (in UTC).
+///
+/// Any separators are accepted, so backup names such as `2024-01-31_12-30-00.zip`
+/// and `world 2024.01.31.tar.gz` are understood as well.
+#[must_use]
+pub fn parse_date(input: &str) -> Option<u64> {
+    let groups: Vec<&str> = input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|group| !group.is_empty())
+        .collect();
+    let start = groups.iter().position(|group| group.len() == 4)?;
+    let numbers: Vec<i64> = groups[start..]
+        .iter()
+        .take(6)
+        .map_while(|group| group.parse().ok())
+        .collect();
+    if numbers.len() < 3 {
+        return None;
+    }
+
+    let (year, month, day) = (numbers[0], numbers[1], numbers[2]);
+    let hour = numbers.get(3).copied().unwrap_or(0);
+    let minute = numbers.get(4).copied().unwrap_or(0);
+    let second = numbers.get(5).copied().unwrap_or(0);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    let seconds = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second;
+    u64::try_from(seconds).ok()
+}
+
+/// The folder of the world's dimension that holds its region files.
+#[must_use]
+pub fn region_dir(world: &World) -> &'static str {
+    match world.dimension_type {
+        VanillaDimensionType::Overworld | VanillaDimensionType::OverworldCaves => "region",
+        VanillaDimensionType::TheNether => "DIM-1/region",
+        VanillaDimensionType::TheEnd => "DIM1/region",
+    }
+}
+
+/// Whether the archive entry `entry` is the region file `file` of `region_dir`. The
+/// world may be nested in a folder, but the Overworld must not match another
+/// dimension's region folder.
+fn matches_entry(entry: &str, region_dir: &str, file: &str) -> bool {
+    let entry = entry.replace('\\', "/");
+    let entry = entry.trim_start_matches("./");
+    let suffix = format!("{region_dir}/{file}");
+    if entry == suffix {
+        return true;
+    }
+    entry.strip_suffix(&suffix).is_some_and(|prefix| {
+        prefix.ends_with('/') && !prefix.ends_with("DIM-1/") && !prefix.ends_with("DIM1/")
+    })
+}
+
+impl Snapshot {
+    /// Reads the region files named in `files` from the snapshot's `region_dir`.
+    /// Missing files are left out of the result.
+    ///
+    /// This does blocking IO and should be run off the async runtime.
+    pub fn read_regions(
+        &self,
+        region_dir: &str,
+        files: &[String],
+    ) -> Result<HashMap<String, Vec<u8>>, String> {
+        let mut regions = HashMap::new();
+        match self.kind {
+            SnapshotKind::Folder => {
+                // The backup is either the world folder itself or a folder holding it.
+                let mut roots = vec![self.path.clone()];
+                if let Ok(entries) = std::fs::read_dir(&self.path) {
+                    roots.extend(entries.flatten().map(|entry| entry.path()));
+                }
+                for file in files {
+                    let found = roots
+                        .iter()
+                        .map(|root| root.join(region_dir).join(file))
+                        .find(|path| path.is_file());
+                    if let Some(path) = found {
+                        let bytes = std::fs::read(&path)
+                            .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+                        regions.insert(file.clone(), bytes);
+                    }
+                }
+            }
+            SnapshotKind::Zip => {
+                let archive = File::open(&self.path).map_err(|err| err.to_string())?;
+                let mut archive = zip::ZipArchive::new(archive).map_err(|err| err.to_string())?;
+                let names: Vec<String> = archive.file_names().map(str::to_string).collect();
+                for file in files {
+                    let Some(name) = names
+                        .iter()
+                        .find(|name| matches_entry(name, region_dir, file))
+                    else {
+                        continue;
+                    };
+                    let mut entry = archive.by_name(name).map_err(|err| err.to_string())?;
+                    let mut bytes = Vec::new();
+                    entry
+                        .read_to_end(&mut bytes)
+                        .map_err(|err| err.to_string())?;
+                    regions.insert(file.clone(), bytes);
+                }
+            }
+            SnapshotKind::TarGz => {
+                // A tarball can only be read front to back, so all regions are
+                // collected in a single pass.
+                let archive = File::open(&self.path).map_err(|err| err.to_string())?;
+                let mut archive = tar::Archive::new(GzDecoder::new(archive));
+                for entry in archive.entries().map_err(|err| err.to_string())? {
+                    let mut entry = entry.map_err(|err| err.to_string())?;
+                    let name = entry
+                        .path()
+                        .map_err(|err| err.to_string())?
+                        .to_string_lossy()
+                        .into_owned();
+                    let Some(file) = files
+                        .iter()
+                        .find(|file| matches_entry(&name, region_dir, file))
+                    else {
+                        continue;
+                    };
+                    let mut bytes = Vec::new();
+                    entry
+                        .read_to_end(&mut bytes)
+                        .map_err(|err| err.to_string())?;
+                    regions.insert(file.clone(), bytes);
+                    if regions.len() == files.len() {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(regions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matches_entry, parse_date};
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2000-03-01"), Some(951_868_800));
+        assert_eq!(parse_date("2024-01-31_12-30-15.zip"), Some(1_706_704_215));
+        assert_eq!(
+            parse_date("world 2024.01.31 12:30.tar.gz"),
+            Some(1_706_704_200)
+        );
+        assert_eq!(parse_date("2024-13-01"), None);
+        assert_eq!(parse_date("backup"), None);
+    }
+
+    #[test]
+    fn test_matches_entry() {
+        assert!(matches_entry("region/r.0.0.mca", "region", "r.0.0.mca"));
+        assert!(matches_entry(
+            "world/region/r.0.0.mca",
+            "region",
+            "r.0.0.mca"
+        ));
+        assert!(!matches_entry(
+            "world/DIM-1/region/r.0.0.mca",
+            "region",
+            "r.0.0.mca"
+        ));
+        assert!(!matches_entry(
+            "world/myregion/r.0.0.mca",
+            "region",
+            "r.0.0.mca"
+        ));
+        assert!(matches_entry(
+            "./world/DIM1/region/r.-1.2.mca",
+            "DIM1/region",
+            "r.-1.2.mca"
+        ));
+    }
+}
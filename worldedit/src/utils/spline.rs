@@ -0,0 +1,88 @@
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::draw::line;
+
+/// A Kochanek–Bartels spline through a list of nodes.
+///
+/// With `tension`, `bias` and `continuity` all zero this is a Catmull–Rom spline,
+/// which is what WorldEdit's `//curve` uses.
+pub struct KochanekBartels {
+    nodes: Vec<Vector3<f64>>,
+    tension: f64,
+    bias: f64,
+    continuity: f64,
+}
+
+impl KochanekBartels {
+    #[must_use]
+    pub fn new(nodes: Vec<Vector3<f64>>, tension: f64, bias: f64, continuity: f64) -> Self {
+        Self {
+            nodes,
+            tension,
+            bias,
+            continuity,
+        }
+    }
+
+    /// Returns node `i`, repeating the first and last node beyond the ends.
+    fn node(&self, i: isize) -> Vector3<f64> {
+        self.nodes[i.clamp(0, self.nodes.len() as isize - 1) as usize]
+    }
+
+    /// Returns the point at `s` in `[0, 1]` on the segment between nodes `i` and `i + 1`.
+    #[must_use]
+    pub fn point(&self, i: usize, s: f64) -> Vector3<f64> {
+        let i = i as isize;
+        let (p0, p1, p2, p3) = (
+            self.node(i - 1),
+            self.node(i),
+            self.node(i + 1),
+            self.node(i + 2),
+        );
+        let (t, b, c) = (self.tension, self.bias, self.continuity);
+
+        // Outgoing tangent at p1 and incoming tangent at p2.
+        let d1 = (p1 - p0) * ((1.0 - t) * (1.0 + b) * (1.0 + c) / 2.0)
+            + (p2 - p1) * ((1.0 - t) * (1.0 - b) * (1.0 - c) / 2.0);
+        let d2 = (p2 - p1) * ((1.0 - t) * (1.0 + b) * (1.0 - c) / 2.0)
+            + (p3 - p2) * ((1.0 - t) * (1.0 - b) * (1.0 + c) / 2.0);
+
+        let (s2, s3) = (s * s, s * s * s);
+        let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+        let h10 = s3 - 2.0 * s2 + s;
+        let h01 = -2.0 * s3 + 3.0 * s2;
+        let h11 = s3 - s2;
+
+        p1 * h00 + d1 * h10 + p2 * h01 + d2 * h11
+    }
+
+    /// Samples the whole spline densely and returns the connected blocks it passes through.
+    #[must_use]
+    pub fn rasterize(&self) -> Vec<BlockPos> {
+        let to_block = |p: Vector3<f64>| {
+            BlockPos(Vector3::new(
+                p.x.floor() as i32,
+                p.y.floor() as i32,
+                p.z.floor() as i32,
+            ))
+        };
+
+        let Some(&first) = self.nodes.first() else {
+            return Vec::new();
+        };
+        let mut points = vec![to_block(first)];
+        for i in 0..self.nodes.len() - 1 {
+            let length = (self.nodes[i + 1] - self.nodes[i]).length();
+            let steps = (length * 4.0).ceil().max(1.0) as usize;
+            for step in 1..=steps {
+                let next = to_block(self.point(i, step as f64 / steps as f64));
+                let last = *points.last().unwrap();
+                if next != last {
+                    // Bridge any gap so the curve stays connected.
+                    points.extend(line(last, next).into_iter().skip(1));
+                }
+            }
+        }
+        points
+    }
+}
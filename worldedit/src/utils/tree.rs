@@ -0,0 +1,312 @@
+use std::collections::{HashMap, VecDeque};
+
+use pumpkin::world::World;
+use pumpkin_data::Block;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+use super::block::{block_of, is_air, with_property};
+use super::mask::{Mask, masked};
+use super::random::Random;
+use super::region::{NEIGHBOURS, edit_positions};
+
+/// A block of a generated tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeBlock {
+    Log,
+    /// Leaves with their distance to the nearest log, as stored in the `distance`
+    /// property (1 to 7).
+    Leaves(u8),
+}
+
+/// The trees `//forest` can plant.
+///
+/// Pumpkin's tree features only generate into proto chunks during world generation,
+/// so trees are built here instead, roughly following the vanilla shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeType {
+    Oak,
+    Birch,
+    Spruce,
+    Jungle,
+    Acacia,
+    DarkOak,
+}
+
+impl TreeType {
+    pub const ALL: [Self; 6] = [
+        Self::Oak,
+        Self::Birch,
+        Self::Spruce,
+        Self::Jungle,
+        Self::Acacia,
+        Self::DarkOak,
+    ];
+
+    /// Parses a tree type, accepting WorldEdit's names as well. `random` yields `None`.
+    pub fn parse(input: &str) -> Result<Option<Self>, String> {
+        Ok(Some(match input.to_lowercase().as_str() {
+            "oak" | "tree" => Self::Oak,
+            "birch" => Self::Birch,
+            "spruce" | "redwood" | "pine" => Self::Spruce,
+            "jungle" | "smalljungle" => Self::Jungle,
+            "acacia" => Self::Acacia,
+            "dark_oak" | "darkoak" => Self::DarkOak,
+            "random" => return Ok(None),
+            _ => return Err(format!("Unknown tree type '{input}'.")),
+        }))
+    }
+
+    #[must_use]
+    pub fn log(self) -> &'static Block {
+        match self {
+            Self::Oak => &Block::OAK_LOG,
+            Self::Birch => &Block::BIRCH_LOG,
+            Self::Spruce => &Block::SPRUCE_LOG,
+            Self::Jungle => &Block::JUNGLE_LOG,
+            Self::Acacia => &Block::ACACIA_LOG,
+            Self::DarkOak => &Block::DARK_OAK_LOG,
+        }
+    }
+
+    #[must_use]
+    pub fn leaves(self) -> &'static Block {
+        match self {
+            Self::Oak => &Block::OAK_LEAVES,
+            Self::Birch => &Block::BIRCH_LEAVES,
+            Self::Spruce => &Block::SPRUCE_LEAVES,
+            Self::Jungle => &Block::JUNGLE_LEAVES,
+            Self::Acacia => &Block::ACACIA_LEAVES,
+            Self::DarkOak => &Block::DARK_OAK_LEAVES,
+        }
+    }
+
+    /// Generates a tree whose trunk starts at `base`.
+    #[must_use]
+    pub fn generate(self, base: BlockPos, random: &mut Random) -> Vec<(BlockPos, TreeBlock)> {
+        let mut tree = TreeBuilder::default();
+        let base = base.0;
+        let mut height = |min: u64, max: u64| (min + random.next_below(max - min + 1)) as i32;
+        match self {
+            Self::Oak | Self::Birch | Self::Jungle => {
+                let trunk = match self {
+                    Self::Oak => height(4, 6),
+                    Self::Birch => height(5, 7),
+                    _ => height(8, 12),
+                };
+                tree.trunk(base, trunk, 1);
+                tree.blob(Vector3::new(base.x, base.y + trunk, base.z), random);
+            }
+            Self::Spruce => {
+                let trunk = height(6, 9);
+                tree.trunk(base, trunk, 1);
+                // Layers alternate between a narrow and a wide ring, the wide ones
+                // growing towards the bottom; the tip is a single leaf above the trunk.
+                tree.leaf(Vector3::new(base.x, base.y + trunk, base.z));
+                for dy in (2..trunk).rev() {
+                    let layer = trunk - 1 - dy;
+                    let radius = if layer % 2 == 0 {
+                        1
+                    } else {
+                        (2 + layer / 4).min(3)
+                    };
+                    tree.disc(
+                        Vector3::new(base.x, base.y + dy, base.z),
+                        radius,
+                        true,
+                        random,
+                    );
+                }
+            }
+            Self::Acacia => {
+                let trunk = height(5, 7);
+                let (dx, dz) = [(1, 0), (-1, 0), (0, 1), (0, -1)][random.next_below(4) as usize];
+                let bend = trunk - 1 - random.next_below(3) as i32;
+                let mut top = base;
+                for dy in 0..trunk {
+                    if dy >= bend {
+                        top.x += dx;
+                        top.z += dz;
+                    }
+                    top.y = base.y + dy;
+                    tree.log(top);
+                }
+                tree.disc(top, 3, true, random);
+                tree.disc(Vector3::new(top.x, top.y + 1, top.z), 1, false, random);
+            }
+            Self::DarkOak => {
+                let trunk = height(6, 8);
+                tree.trunk(base, trunk, 2);
+                let center = Vector3::new(base.x, base.y + trunk - 1, base.z);
+                for dy in -1..=1 {
+                    let radius = if dy == 1 { 2 } else { 3 };
+                    tree.disc(
+                        Vector3::new(center.x, center.y + dy, center.z),
+                        radius,
+                        true,
+                        random,
+                    );
+                }
+            }
+        }
+        tree.finish()
+    }
+}
+
+#[derive(Default)]
+struct TreeBuilder {
+    blocks: HashMap<BlockPos, TreeBlock>,
+}
+
+impl TreeBuilder {
+    fn log(&mut self, pos: Vector3<i32>) {
+        self.blocks.insert(BlockPos(pos), TreeBlock::Log);
+    }
+
+    fn leaf(&mut self, pos: Vector3<i32>) {
+        self.blocks
+            .entry(BlockPos(pos))
+            .or_insert(TreeBlock::Leaves(7));
+    }
+
+    /// A `width` x `width` trunk of the given height.
+    fn trunk(&mut self, base: Vector3<i32>, height: i32, width: i32) {
+        for dy in 0..height {
+            for dx in 0..width {
+                for dz in 0..width {
+                    self.log(Vector3::new(base.x + dx, base.y + dy, base.z + dz));
+                }
+            }
+        }
+    }
+
+    /// A square layer of leaves. With `ragged`, corners are left out at random.
+    fn disc(&mut self, center: Vector3<i32>, radius: i32, ragged: bool, random: &mut Random) {
+        for dx in -radius..=radius {
+            for dz in -radius..=radius {
+                let corner = dx.abs() == radius && dz.abs() == radius && radius > 0;
+                if corner && (!ragged || random.next_below(2) == 0) {
+                    continue;
+                }
+                self.leaf(Vector3::new(center.x + dx, center.y, center.z + dz));
+            }
+        }
+    }
+
+    /// The vanilla blob foliage: two wide layers below the top of the trunk and two
+    /// narrow ones at and above it.
+    fn blob(&mut self, top: Vector3<i32>, random: &mut Random) {
+        for dy in -3..=0 {
+            let radius = if dy < -1 { 2 } else { 1 };
+            self.disc(
+                Vector3::new(top.x, top.y + dy, top.z),
+                radius,
+                dy != 0,
+                random,
+            );
+        }
+    }
+
+    /// Computes the distance of every leaf to the nearest log, like vanilla does.
+    fn finish(mut self) -> Vec<(BlockPos, TreeBlock)> {
+        let mut queue: VecDeque<_> = self
+            .blocks
+            .iter()
+            .filter(|(_, block)| **block == TreeBlock::Log)
+            .map(|(pos, _)| (*pos, 0u8))
+            .collect();
+        while let Some((pos, distance)) = queue.pop_front() {
+            if distance >= 6 {
+                continue;
+            }
+            for (dx, dy, dz) in NEIGHBOURS {
+                let next = BlockPos(pos.0 + Vector3::new(dx, dy, dz));
+                if let Some(TreeBlock::Leaves(current)) = self.blocks.get_mut(&next) {
+                    if *current > distance + 1 {
+                        *current = distance + 1;
+                        queue.push_back((next, distance + 1));
+                    }
+                }
+            }
+        }
+        self.blocks.into_iter().collect()
+    }
+}
+
+/// Trees are only planted on these blocks.
+#[must_use]
+pub fn is_soil(block_id: u16) -> bool {
+    let block = block_of(block_id);
+    [
+        &Block::GRASS_BLOCK,
+        &Block::DIRT,
+        &Block::COARSE_DIRT,
+        &Block::PODZOL,
+    ]
+    .iter()
+    .any(|soil| soil.id == block.id)
+}
+
+/// The blocks of trees about to be placed, with the state each will be set to.
+pub type TreeBlocks = HashMap<BlockPos, (TreeBlock, u16)>;
+
+/// Adds the blocks of a tree of `tree_type` growing from `base` to `blocks`. Logs win
+/// over the leaves of neighbouring trees.
+pub fn add_tree(blocks: &mut TreeBlocks, tree_type: TreeType, base: BlockPos, random: &mut Random) {
+    for (pos, block) in tree_type.generate(base, random) {
+        let state_id = match block {
+            TreeBlock::Log => tree_type.log().default_state.id,
+            TreeBlock::Leaves(distance) => {
+                let leaves = tree_type.leaves().default_state.id;
+                with_property(leaves, "distance", &distance.to_string()).unwrap_or(leaves)
+            }
+        };
+        if block == TreeBlock::Log || !blocks.contains_key(&pos) {
+            blocks.insert(pos, (block, state_id));
+        }
+    }
+}
+
+/// Places the trees collected with [`add_tree`] and returns the number of changed
+/// blocks. Trees only grow into air, or replace leaves with logs, and only where
+/// `mask` matches.
+pub async fn place_trees(world: &World, blocks: &TreeBlocks, mask: Option<&Mask>) -> usize {
+    edit_positions(
+        world,
+        blocks.keys().copied().collect::<Vec<_>>(),
+        masked(mask, |pos, block_id| {
+            let (block, state_id) = blocks.get(&pos)?;
+            let leaves = block_of(block_id).name.ends_with("_leaves");
+            (is_air(block_id) || *block == TreeBlock::Log && leaves).then_some(*state_id)
+        }),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+    use super::{TreeBlock, TreeType};
+    use crate::utils::random::Random;
+
+    #[test]
+    fn test_trees_have_trunk_and_reachable_leaves() {
+        let base = BlockPos(Vector3::new(10, 64, -3));
+        for tree_type in TreeType::ALL {
+            let mut random = Random::with_seed(42);
+            let blocks = tree_type.generate(base, &mut random);
+            assert!(blocks.contains(&(base, TreeBlock::Log)));
+            assert!(blocks.iter().all(|(pos, _)| pos.0.y >= base.0.y));
+            assert!(
+                blocks
+                    .iter()
+                    .any(|(_, block)| matches!(block, TreeBlock::Leaves(_)))
+            );
+            assert!(
+                blocks
+                    .iter()
+                    .all(|(_, block)| !matches!(block, TreeBlock::Leaves(7)))
+            );
+        }
+    }
+}